@@ -0,0 +1,32 @@
+-- CreateTable
+CREATE TABLE "Category" (
+    "id" SERIAL NOT NULL,
+    "name" VARCHAR(100) NOT NULL,
+    "position" INTEGER NOT NULL DEFAULT 0,
+    "archived" BOOLEAN NOT NULL DEFAULT false,
+
+    CONSTRAINT "Category_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Category_name_key" ON "Category"("name");
+
+-- The categories that used to be hardcoded in the menu, in the same order
+INSERT INTO "Category" ("name", "position") VALUES
+    ('Meat', 0),
+    ('Vegetable', 1),
+    ('Fruit', 2),
+    ('Side', 3),
+    ('Bread', 4),
+    ('Pasta', 5),
+    ('Ingredient', 6);
+
+-- Anything else that was already committed gets a category too,
+-- otherwise the foreign key below can't be added
+INSERT INTO "Category" ("name", "position")
+SELECT "food_type", 6 + ROW_NUMBER() OVER (ORDER BY "food_type")
+FROM (SELECT DISTINCT "food_type" FROM "Food") AS "existing"
+WHERE "food_type" NOT IN (SELECT "name" FROM "Category");
+
+-- AddForeignKey
+ALTER TABLE "Food" ADD CONSTRAINT "Food_food_type_fkey" FOREIGN KEY ("food_type") REFERENCES "Category"("name") ON DELETE RESTRICT ON UPDATE CASCADE;
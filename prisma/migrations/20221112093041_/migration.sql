@@ -0,0 +1,4 @@
+-- Store prices and totals in cents. Totals are recalculated from the
+-- rounded price, both round half away from zero like `Money::times`
+ALTER TABLE "Food" ALTER COLUMN "price" SET DATA TYPE INTEGER USING ROUND("price"::NUMERIC * 100)::INTEGER,
+ALTER COLUMN "total" SET DATA TYPE INTEGER USING ROUND(ROUND("price"::NUMERIC * 100) * "amount"::NUMERIC)::INTEGER;
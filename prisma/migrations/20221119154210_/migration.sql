@@ -0,0 +1,2 @@
+-- AlterTable
+ALTER TABLE "Food" ADD COLUMN     "unit" VARCHAR(10) NOT NULL DEFAULT 'pcs';
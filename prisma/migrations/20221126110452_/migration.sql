@@ -0,0 +1,16 @@
+-- AlterTable
+ALTER TABLE "Food" ADD COLUMN     "store_id" INTEGER;
+
+-- CreateTable
+CREATE TABLE "Store" (
+    "id" SERIAL NOT NULL,
+    "name" VARCHAR(100) NOT NULL,
+
+    CONSTRAINT "Store_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Store_name_key" ON "Store"("name");
+
+-- AddForeignKey
+ALTER TABLE "Food" ADD CONSTRAINT "Food_store_id_fkey" FOREIGN KEY ("store_id") REFERENCES "Store"("id") ON DELETE SET NULL ON UPDATE CASCADE;
@@ -0,0 +1,19 @@
+-- AlterTable
+ALTER TABLE "Food" ADD COLUMN     "receipt_id" INTEGER;
+
+-- CreateTable
+CREATE TABLE "Receipt" (
+    "id" SERIAL NOT NULL,
+    "date" DATE NOT NULL,
+    "store_id" INTEGER,
+    "total" INTEGER,
+    "notes" TEXT NOT NULL DEFAULT '',
+
+    CONSTRAINT "Receipt_pkey" PRIMARY KEY ("id")
+);
+
+-- AddForeignKey
+ALTER TABLE "Receipt" ADD CONSTRAINT "Receipt_store_id_fkey" FOREIGN KEY ("store_id") REFERENCES "Store"("id") ON DELETE SET NULL ON UPDATE CASCADE;
+
+-- AddForeignKey
+ALTER TABLE "Food" ADD CONSTRAINT "Food_receipt_id_fkey" FOREIGN KEY ("receipt_id") REFERENCES "Receipt"("id") ON DELETE SET NULL ON UPDATE CASCADE;
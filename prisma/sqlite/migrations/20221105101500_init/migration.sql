@@ -0,0 +1,32 @@
+-- CreateTable
+CREATE TABLE "Category" (
+    "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    "name" TEXT NOT NULL,
+    "position" INTEGER NOT NULL DEFAULT 0,
+    "archived" BOOLEAN NOT NULL DEFAULT false
+);
+
+-- CreateTable
+CREATE TABLE "Food" (
+    "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    "food_type" TEXT NOT NULL,
+    "name" TEXT NOT NULL,
+    "price" REAL NOT NULL,
+    "amount" REAL NOT NULL,
+    "total" REAL NOT NULL,
+    "date" DATETIME NOT NULL,
+    CONSTRAINT "Food_food_type_fkey" FOREIGN KEY ("food_type") REFERENCES "Category" ("name") ON DELETE RESTRICT ON UPDATE CASCADE
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Category_name_key" ON "Category"("name");
+
+-- The categories the Postgres database starts with
+INSERT INTO "Category" ("name", "position") VALUES
+    ('Meat', 0),
+    ('Vegetable', 1),
+    ('Fruit', 2),
+    ('Side', 3),
+    ('Bread', 4),
+    ('Pasta', 5),
+    ('Ingredient', 6);
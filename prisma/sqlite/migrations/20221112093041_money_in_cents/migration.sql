@@ -0,0 +1,21 @@
+-- Store prices and totals in cents. Totals are recalculated from the
+-- rounded price, both round half away from zero like `Money::times`
+
+-- RedefineTables
+PRAGMA foreign_keys=OFF;
+CREATE TABLE "new_Food" (
+    "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    "food_type" TEXT NOT NULL,
+    "name" TEXT NOT NULL,
+    "price" INTEGER NOT NULL,
+    "amount" REAL NOT NULL,
+    "total" INTEGER NOT NULL,
+    "date" DATETIME NOT NULL,
+    CONSTRAINT "Food_food_type_fkey" FOREIGN KEY ("food_type") REFERENCES "Category" ("name") ON DELETE RESTRICT ON UPDATE CASCADE
+);
+INSERT INTO "new_Food" ("id", "food_type", "name", "price", "amount", "total", "date")
+SELECT "id", "food_type", "name", CAST(ROUND("price" * 100) AS INTEGER), "amount", CAST(ROUND(ROUND("price" * 100) * "amount") AS INTEGER), "date" FROM "Food";
+DROP TABLE "Food";
+ALTER TABLE "new_Food" RENAME TO "Food";
+PRAGMA foreign_key_check;
+PRAGMA foreign_keys=ON;
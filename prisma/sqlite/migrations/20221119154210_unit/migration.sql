@@ -0,0 +1,2 @@
+-- AlterTable
+ALTER TABLE "Food" ADD COLUMN "unit" TEXT NOT NULL DEFAULT 'pcs';
@@ -0,0 +1,11 @@
+-- CreateTable
+CREATE TABLE "Store" (
+    "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    "name" TEXT NOT NULL
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Store_name_key" ON "Store"("name");
+
+-- AlterTable
+ALTER TABLE "Food" ADD COLUMN "store_id" INTEGER REFERENCES "Store" ("id") ON DELETE SET NULL ON UPDATE CASCADE;
@@ -0,0 +1,12 @@
+-- CreateTable
+CREATE TABLE "Receipt" (
+    "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    "date" DATETIME NOT NULL,
+    "store_id" INTEGER,
+    "total" INTEGER,
+    "notes" TEXT NOT NULL DEFAULT '',
+    CONSTRAINT "Receipt_store_id_fkey" FOREIGN KEY ("store_id") REFERENCES "Store" ("id") ON DELETE SET NULL ON UPDATE CASCADE
+);
+
+-- AlterTable
+ALTER TABLE "Food" ADD COLUMN "receipt_id" INTEGER REFERENCES "Receipt" ("id") ON DELETE SET NULL ON UPDATE CASCADE;
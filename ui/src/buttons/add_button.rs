@@ -3,7 +3,11 @@ use cursive::{
   Cursive
 };
 
-use crate::item::Item;
+use super::{basket_changed, pending_row, selected_category};
+use crate::{
+  item::{Item, Unit},
+  validation::Rules
+};
 
 /// Get the content of a `NamedView<EditView>`
 fn get_edit_view_content(s: &mut Cursive, name: &str) -> Option<String> {
@@ -12,110 +16,100 @@ fn get_edit_view_content(s: &mut Cursive, name: &str) -> Option<String> {
   })
 }
 
-/// Functionality for the `Add` button
-pub fn add_button(s: &mut Cursive, select_view_name: &str) {
-  let mut select_view = match s.find_name::<SelectView<Item>>(select_view_name)
-  {
-    Some(view) => view,
-    None => {
-      return s.add_layer(Dialog::info("Couldn't find a view with that name"));
-    }
-  };
+/// Collect the `product_*` fields of a menu item into an `Item` and run it
+/// through `rules`. Every field that breaks one of them is listed in the error
+fn validate_menu_item(s: &mut Cursive, rules: &Rules) -> Result<Item, String> {
+  let mut contents = Vec::with_capacity(4);
 
-  let product_name = match get_edit_view_content(s, "product_name") {
-    Some(content) => {
-      if content.is_empty() {
-        return s.add_layer(Dialog::info("Name cannot be empty"));
-      } else {
-        content
+  for name in [
+    "product_name",
+    "product_price",
+    "product_amount",
+    "product_date_of_purchase"
+  ] {
+    match get_edit_view_content(s, name) {
+      Some(content) => contents.push(content.trim().to_string()),
+      None => {
+        return Err(String::from("Couldn't find content with the given name"))
       }
     }
-    None => {
-      return s
-        .add_layer(Dialog::info("Couldn't find content with the given name"));
-    }
-  };
+  }
 
-  let product_price = match get_edit_view_content(s, "product_price") {
-    Some(content) => {
-      if content.is_empty() {
-        return s.add_layer(Dialog::info("Price cannot be empty"));
-      } else {
-        content
-      }
-    }
-    None => {
-      return s
-        .add_layer(Dialog::info("Couldn't find content with the given name"));
-    }
-  };
+  let unit = s
+    .call_on_name("product_unit", |select_view: &mut SelectView<Unit>| {
+      select_view.selection()
+    })
+    .flatten()
+    .map(|unit| *unit)
+    .unwrap_or_default();
 
-  let product_amount = match get_edit_view_content(s, "product_amount") {
-    Some(content) => {
-      if content.is_empty() {
-        return s.add_layer(Dialog::info("Amount cannot be empty"));
-      } else {
-        content
-      }
-    }
-    None => {
-      return s
-        .add_layer(Dialog::info("Couldn't find content with the given name"));
-    }
-  };
+  let mut item = Item::new(
+    contents[0].as_str(),
+    contents[1].as_str(),
+    contents[2].as_str(),
+    unit.as_str(),
+    contents[3].as_str()
+  );
 
-  let product_date_of_purchase =
-    match get_edit_view_content(s, "product_date_of_purchase") {
-      Some(content) => {
-        if content.is_empty() {
-          return s.add_layer(Dialog::info("Purchase Date cannot be empty"));
-        } else {
-          content
-        }
-      }
-      None => {
-        return s.add_layer(Dialog::info(
-          "Couldn't find content with the given name"
-        ));
-      }
-    };
+  if let Err(violations) = item.validate(rules) {
+    return Err(format!("Please fix the following:\n{}", violations));
+  }
 
-  let item = Item::new(
-    product_name,
-    product_price,
-    product_amount,
-    product_date_of_purchase
-  );
+  item
+    .resolve_purchase_date(rules.date_parser())
+    .map_err(|err| err.to_string())?;
 
-  if item.are_empty() {
-    return s.add_layer(Dialog::info("Every field must have a value"));
+  Ok(item)
+}
+
+/// Read and validate the `product_*` fields of a menu item.
+/// Any problem is shown to the user in a dialog, in which case `None` is returned
+pub fn read_menu_item(s: &mut Cursive, rules: &Rules) -> Option<Item> {
+  match validate_menu_item(s, rules) {
+    Ok(item) => Some(item),
+    Err(message) => {
+      s.add_layer(Dialog::info(message));
+      None
+    }
   }
+}
 
-  let product_price = match item.validate_price() {
-    Ok(price) => price,
-    Err(_) => {
-      return s
-        .add_layer(Dialog::info("Price field does not have a valid format"))
+/// Functionality for the `Add` button.
+/// The item is added under the category picked above the form,
+/// in place of the item that is being edited if there is one
+pub fn add_button(s: &mut Cursive, rules: &Rules, select_view_name: &str) {
+  let mut select_view = match s.find_name::<SelectView<Item>>(select_view_name)
+  {
+    Some(view) => view,
+    None => {
+      return s.add_layer(Dialog::info("Couldn't find a view with that name"));
     }
   };
 
-  let product_amount = match item.validate_amount() {
-    Ok(amount) => amount,
-    Err(_) => {
-      return s
-        .add_layer(Dialog::info("Amount field does not have a valid format"))
-    }
+  let category = match selected_category(s) {
+    Some(category) => category,
+    None => return s.add_layer(Dialog::info("Pick a category first"))
   };
 
-  let product_date_of_purchase = match item.validate_purchase_date() {
-    Ok(date) => date,
-    Err(err) => {
-      return s.add_layer(Dialog::info(format!(
-        "Purchase Date field doesn't not have a valid format:\n{}",
-        err
-      )))
-    }
+  let mut item = match read_menu_item(s, rules) {
+    Some(item) => item,
+    None => return
   };
 
-  select_view.add_item(item.to_string(), item);
+  item.set_category(category);
+
+  let editing = select_view.iter().position(|(_, item)| item.is_editing());
+
+  match editing.and_then(|idx| select_view.get_item_mut(idx)) {
+    Some((label, edited)) => {
+      *label = pending_row(&item).into();
+      *edited = item;
+    }
+    None => select_view.add_item(pending_row(&item), item)
+  }
+
+  // the list is read again, so let go of it first
+  drop(select_view);
+
+  basket_changed(s);
 }
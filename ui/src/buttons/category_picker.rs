@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use cursive::{
+  view::{Nameable, Resizable},
+  views::{Dialog, LinearLayout, SelectView, TextView},
+  Cursive
+};
+
+use crate::model::Model;
+
+/// Popup to pick the category of the next item added to the basket,
+/// so a single basket can hold a whole receipt
+pub fn category_picker() -> LinearLayout {
+  LinearLayout::horizontal()
+    .child(TextView::new("Category: "))
+    .child(
+      SelectView::<String>::new()
+        .popup()
+        .with_name("basket_category")
+        .min_width(20)
+    )
+}
+
+/// Fetch the categories of the `Food` menu and put them into the picker,
+/// `selected` is the name of the category to pick afterwards
+pub fn load_categories(model: Arc<Model>, selected: String) {
+  tokio::spawn(async move {
+    let categories = match model.store().list_categories(false).await {
+      Ok(categories) => categories,
+      Err(error) => {
+        let message = format!("Failed to load the categories:\n{}", error);
+
+        return model.use_cb_sink(move |s| {
+          s.add_layer(Dialog::info(message));
+        });
+      }
+    };
+
+    model.use_cb_sink(move |s| {
+      s.call_on_name("basket_category", |select_view: &mut SelectView| {
+        select_view.clear();
+
+        for category in categories {
+          select_view.add_item_str(category.name);
+        }
+      });
+
+      select_category(s, &selected);
+    });
+  });
+}
+
+/// Pick the category called `name`, if the picker has it
+pub fn select_category(s: &mut Cursive, name: &str) {
+  s.call_on_name("basket_category", |select_view: &mut SelectView| {
+    if let Some(idx) = select_view.iter().position(|(_, known)| known == name) {
+      select_view.set_selection(idx);
+    }
+  });
+}
+
+/// The category picked for the next item, `None` before they're loaded
+pub fn selected_category(s: &mut Cursive) -> Option<String> {
+  s.call_on_name("basket_category", |select_view: &mut SelectView| {
+    select_view
+      .selection()
+      .map(|category| String::clone(&category))
+  })
+  .flatten()
+}
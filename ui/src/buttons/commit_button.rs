@@ -1,108 +1,400 @@
 use std::sync::Arc;
 
 use cursive::{
-  views::{Dialog, SelectView, TextView},
+  view::{Nameable, Resizable},
+  views::{Dialog, DummyView, EditView, LinearLayout, SelectView, TextView},
   Cursive
 };
-use prisma_client_rust::QueryError;
+use prisma_client_rust::chrono::Local;
 
+use super::{basket_changed, pending_row, selected_shop_id};
 use crate::{
   item::{FoodItem, Item},
   model::Model,
-  prisma::food::Data
+  money::Money,
+  queue::{sync_status, update_sync_status},
+  store::{NewReceipt, StoreError}
 };
 
 /// The stuff that happens when you press the `Commit` button
 ///
 /// Get every item of the `SelectView` one by one, trim and lowercase them,
 /// then collect them into a `Vec<PrismaItem>`, which will be sent to the database
-/// in a Tokio thread so the operation doesn't block the main thread
-pub fn commit_button(s: &mut Cursive, model: Arc<Model>, dialog_name: String) {
-  let select_view_items_len = s
+/// in a Tokio thread so the operation doesn't block the main thread.
+/// A receipt total typed in above the form is checked against the items first
+pub fn commit_button(s: &mut Cursive, model: Arc<Model>) {
+  let select_view_items_len = match s
     .find_name::<SelectView<Item>>("select_item")
-    .unwrap()
-    .len();
-
-  match select_view_items_len {
-    // if nothing has been added, notify the user and short circuit
-    0 => {
-      return s.add_layer(Dialog::info("List is empty. Nothing to commit."));
+  {
+    Some(select_view) => select_view.len(),
+    None => {
+      return s.add_layer(Dialog::info("Couldn't find a view with that name"));
     }
+  };
+
+  // if nothing has been added, notify the user and short circuit
+  if select_view_items_len == 0 {
+    return s.add_layer(Dialog::info("List is empty. Nothing to commit."));
+  }
+
+  if commit_in_flight(s) {
+    return s.add_layer(Dialog::info(
+      "The last commit is still on its way, wait for the database to answer"
+    ));
+  }
+
+  let total = match read_receipt_total(s) {
+    Ok(total) => total,
+    Err(message) => return s.add_layer(Dialog::info(message))
+  };
+
+  let (ids, food_items) = match collect_food_items(s) {
+    Ok(collected) => collected,
+    Err(message) => return s.add_layer(Dialog::info(message))
+  };
+
+  // items typed in a few days late shouldn't move the trip back in time
+  let receipt = NewReceipt {
+    date: food_items
+      .iter()
+      .map(FoodItem::purchase_date)
+      .max()
+      .unwrap_or_else(|| Local::today().naive_local()),
+    // the store picked above the form goes for the whole basket
+    shop_id: selected_shop_id(s),
+    total,
+    notes: String::new()
+  };
+
+  let sum = food_items.iter().map(FoodItem::total).sum::<Money>();
+  let tolerance = model.rules().receipt_tolerance();
 
-    // otherwise send the contents off to the database
-    // then clear the elements from the view
-    n => {
-      s.add_layer(
-        Dialog::around(TextView::new(format!("Commit {} items?", n)))
-          .button("Commit", move |s| {
-            s.pop_layer();
-
-            // does the actual heavy lifting
-            handle_commit(s, dialog_name.clone(), model.clone());
-
-            s.call_on_name(
-              "select_item",
-              |select_view: &mut SelectView<Item>| {
-                select_view.clear();
-              }
-            );
-          })
-          .button("Cancel", |s| {
-            s.pop_layer();
-          })
+  let (title, question) = match reconcile(total, sum, tolerance) {
+    Some(warning) => (
+      "Receipt doesn't add up",
+      format!("{}\n\nCommit {} items anyway?", warning, food_items.len())
+    ),
+    None => (
+      "Receipt",
+      format!(
+        "The items add up to {}\n\nCommit {} items?",
+        sum,
+        food_items.len()
+      )
+    )
+  };
+
+  // otherwise send the contents off to the database,
+  // the elements are cleared from the view once the database accepted them
+  s.add_layer(
+    Dialog::around(
+      LinearLayout::vertical()
+        .child(TextView::new(question))
+        .child(DummyView)
+        .child(TextView::new("Notes:"))
+        .child(EditView::new().with_name("receipt_notes").fixed_width(30))
+    )
+    .title(title)
+    .button("Commit", move |s| {
+      let notes = s
+        .call_on_name("receipt_notes", |edit_view: &mut EditView| {
+          edit_view.get_content().trim().to_string()
+        })
+        .unwrap_or_default();
+
+      s.pop_layer();
+
+      let receipt = NewReceipt {
+        notes,
+        ..receipt.clone()
+      };
+
+      // the list may be reordered or edited until the database answers,
+      // so the items are found by their ids afterwards
+      update_items(s, &ids, |item| {
+        item.set_committing(true);
+      });
+
+      // does the actual heavy lifting
+      handle_commit(
+        Model::clone(&model),
+        receipt,
+        ids.clone(),
+        food_items.clone()
       );
+    })
+    .button("Cancel", |s| {
+      s.pop_layer();
+    })
+  );
+}
+
+/// Read the total printed on the receipt, typed in above the form.
+/// It's optional, but has to fit the `Int` column when it's there
+fn read_receipt_total(s: &mut Cursive) -> Result<Option<Money>, String> {
+  let total = s
+    .call_on_name("receipt_total", |edit_view: &mut EditView| {
+      edit_view.get_content().trim().to_string()
+    })
+    .ok_or_else(|| String::from("Couldn't find content with the given name"))?;
+
+  if total.is_empty() {
+    return Ok(None);
+  }
+
+  let total = Money::parse(&total)
+    .map_err(|reason| format!("Receipt total '{}' {}", total, reason))?;
+
+  if i32::try_from(total.cents()).is_err() {
+    return Err(format!(
+      "Receipt total '{}' is more than the database can store",
+      total
+    ));
+  }
+
+  Ok(Some(total))
+}
+
+/// Compare the printed receipt `total` with the `sum` of the items.
+/// Anything further off than `tolerance` is most likely a mistyped price
+/// or a missed item, so a warning with the difference is returned
+fn reconcile(
+  total: Option<Money>,
+  sum: Money,
+  tolerance: Money
+) -> Option<String> {
+  let total = total?;
+  let difference = total - sum;
+
+  if difference.abs() <= tolerance {
+    return None;
+  }
+
+  let hint = if difference > Money::default() {
+    "Was an item missed?"
+  } else {
+    "Was a price or an amount mistyped?"
+  };
+
+  Some(format!(
+    "The receipt says {}, but the items add up to {}.\n\
+     Difference: {} (tolerance {}). {}",
+    total, sum, difference, tolerance, hint
+  ))
+}
+
+/// Whether some of the pending items were sent off with a commit
+/// the database hasn't answered yet
+fn commit_in_flight(s: &mut Cursive) -> bool {
+  s.call_on_name("select_item", |select_view: &mut SelectView<Item>| {
+    select_view.iter().any(|(_, item)| item.is_committing())
+  })
+  .unwrap_or_default()
+}
+
+/// Call it before anything takes the basket off the screen. The items of
+/// a commit in flight can only leave it once the database answers,
+/// so the user is asked to wait and `false` is returned meanwhile
+pub fn can_leave_basket(s: &mut Cursive) -> bool {
+  if commit_in_flight(s) {
+    s.add_layer(Dialog::info(
+      "The commit is still on its way, wait for the database"
+    ));
+
+    return false;
+  }
+
+  true
+}
+
+/// Run `f` on every pending item with one of the `ids` and refresh its label
+fn update_items<F>(s: &mut Cursive, ids: &[u64], mut f: F)
+where
+  F: FnMut(&mut Item)
+{
+  s.call_on_name("select_item", |select_view: &mut SelectView<Item>| {
+    for (label, item) in select_view.iter_mut() {
+      if ids.contains(&item.id()) {
+        f(item);
+        *label = pending_row(item).into();
+      }
     }
+  });
+}
+
+/// Mark the item at `idx` as rejected (or clear the mark) and refresh its label
+fn set_rejection(
+  select_view: &mut SelectView<Item>,
+  idx: usize,
+  rejection: Option<String>
+) {
+  if let Some((label, item)) = select_view.get_item_mut(idx) {
+    item.set_rejection(rejection);
+    *label = pending_row(item).into();
   }
 }
 
 /// Gets every `Item` from the `SelectView`, then parse each into a `FoodItem`.
 /// I'm having a feeling that having both types around is redundant, since I can just store everyting in `Item`,
 /// but then the question arises how am I going to validate each of it's fields. Maybe later
-fn handle_commit(s: &mut Cursive, dialog_name: String, model: Arc<Model>) {
+///
+/// Fails if any of the items wouldn't fit the `Food` table,
+/// that item is marked with the error, so it can be fixed.
+/// The ids of the items come along in the same order
+fn collect_food_items(
+  s: &mut Cursive
+) -> Result<(Vec<u64>, Vec<FoodItem>), String> {
   s.call_on_name("select_item", |select_view: &mut SelectView<Item>| {
-    let mut food_items = Vec::<FoodItem>::with_capacity(10);
+    let mut ids = Vec::with_capacity(select_view.len());
+    let mut food_items = Vec::<FoodItem>::with_capacity(select_view.len());
 
     for idx in 0..select_view.len() {
-      let (_, item) = select_view.get_item(idx).unwrap();
+      // a new attempt, forget about the previous one
+      set_rejection(select_view, idx, None);
 
-      let mut food_item = FoodItem::from(item);
+      let item = match select_view.get_item(idx) {
+        Some((_, item)) => item.clone(),
+        None => continue
+      };
 
-      food_item.set_table_name(dialog_name.clone());
+      if item.is_editing() {
+        return Err(format!(
+          "Item {} ({}) is being edited, press Add to save it \
+           or pick Stop editing from its actions",
+          idx + 1,
+          item.name()
+        ));
+      }
+
+      let food_item = FoodItem::try_from(&item).and_then(|mut food_item| {
+        food_item.set_table_name(item.category());
+        food_item.check_columns()?;
+
+        Ok(food_item)
+      });
+
+      match food_item {
+        Ok(food_item) => {
+          ids.push(item.id());
+          food_items.push(food_item);
+        }
+        Err(error) => {
+          let message = format!(
+            "Item {} ({}) cannot be committed:\n{}",
+            idx + 1,
+            item,
+            error
+          );
 
-      food_items.push(food_item);
+          set_rejection(select_view, idx, Some(error.to_string()));
+
+          return Err(message);
+        }
+      }
     }
 
-    // send items to database with prisma in a tokio thread
-    tokio::spawn(async move {
-      let create = food_items
-        .into_iter()
-        .map(|item| model.create_item(item, vec![]).exec())
-        .collect::<Vec<_>>();
+    Ok((ids, food_items))
+  })
+  .unwrap_or_else(|| Err(String::from("Couldn't find a view with that name")))
+}
+
+/// Items only leave the `SelectView` once the database accepted them,
+/// otherwise they stay there marked with the error, so they can be fixed.
+/// Everything committed together is linked to a single receipt
+fn handle_commit(
+  model: Arc<Model>,
+  receipt: NewReceipt,
+  ids: Vec<u64>,
+  mut food_items: Vec<FoodItem>
+) {
+  for food_item in food_items.iter_mut() {
+    food_item.set_shop_id(receipt.shop_id);
+  }
+
+  // send items to database with prisma in a tokio thread
+  tokio::spawn(async move {
+    // every item goes in a single transaction, if any of them fails
+    // the whole basket is rolled back and nothing is left behind
+    let result = model
+      .store()
+      .create_receipt(receipt.clone(), food_items.clone())
+      .await;
 
-      // collect the result of the query and if any of them fail, let the user know about it
-      // i'm interested in handling the errors in a create_many so that if any fails, I can rewind the whole transaction
-      let mut results = Vec::<Result<Data, QueryError>>::with_capacity(10);
+    let error = match result {
+      Ok(_) => {
+        return model.use_cb_sink(move |s| {
+          remove_committed(s, &ids);
 
-      for result in create {
-        results.push(result.await);
+          s.add_layer(Dialog::info("All set!"));
+        });
       }
+      Err(error) => error
+    };
+
+    let mut reason = error.to_string();
+
+    // the database couldn't be reached, so the basket waits in the queue
+    // until it's back. Anything it refused is reported right away,
+    // it won't get any better by waiting
+    if let Some(queue) = model.queue().filter(|_| error.is_offline()) {
+      match queue.push(receipt, food_items) {
+        Ok(()) => {
+          let status = sync_status(queue);
+          let message = format!(
+            "The database can't be reached, the basket was queued \
+             and will be committed once it's back:\n{}",
+            reason
+          );
+
+          return model.use_cb_sink(move |s| {
+            remove_committed(s, &ids);
+            update_sync_status(s, status);
 
-      // check whether every `Result` holds an `Ok`
-      if results.into_iter().all(|item| item.is_ok()) {
-        // ! Unwrapping because I wanna catch any threading related errors
-        model
-          .use_cb_sink(|s| {
-            s.add_layer(Dialog::info("All set!"));
-          })
-          .unwrap();
-      } else {
-        // ! Unwrapping because I wanna catch any threading related errors
-        model
-          .use_cb_sink(|s| {
-            s.add_layer(Dialog::info("Error occured when setting records"));
-          })
-          .unwrap();
+            s.add_layer(Dialog::info(message));
+          });
+        }
+        Err(queue_error) => {
+          reason = format!("{} (couldn't be queued: {})", reason, queue_error);
+        }
       }
+    }
+
+    // if the store could tell which item it refused, only that one
+    // is marked, the message names it
+    let (blamed, rejection) = match &error {
+      StoreError::Item { index, error, .. } => {
+        (ids.get(*index).copied(), error.to_string())
+      }
+      _ => (None, reason.clone())
+    };
+
+    model.use_cb_sink(move |s| {
+      update_items(s, &ids, |item| {
+        let refused = blamed.map_or(true, |id| id == item.id());
+
+        item.set_committing(false);
+        item.set_rejection(refused.then(|| rejection.clone()));
+      });
+
+      s.add_layer(Dialog::info(format!(
+        "Error occured when setting records, nothing was saved:\n{}",
+        reason
+      )));
     });
   });
 }
+
+/// Take the items with the given `ids` off the list, once they're stored
+/// in the database or in the queue. Anything added meanwhile stays
+fn remove_committed(s: &mut Cursive, ids: &[u64]) {
+  s.call_on_name("select_item", |select_view: &mut SelectView<Item>| {
+    while let Some(idx) = select_view
+      .iter()
+      .position(|(_, item)| ids.contains(&item.id()))
+    {
+      select_view.remove_item(idx);
+    }
+  });
+
+  basket_changed(s);
+}
@@ -1,53 +1,81 @@
 mod dialog {
   use super::menu_item::create_menu_item;
   use crate::{
-    buttons::{add_button, commit_button::commit_button},
-    callbacks::delete_item,
+    buttons::{
+      add_button,
+      category_picker::category_picker,
+      commit_button::{can_leave_basket, commit_button},
+      pending_table::{pending_footer, pending_header, pending_list},
+      shop_picker::shop_picker
+    },
+    callbacks::item_actions,
     item::Item,
-    model::Model
+    model::Model,
+    queue::sync_status
   };
   use cursive::{
     view::{Nameable, Resizable},
-    views::{Dialog, DummyView, LinearLayout, NamedView, SelectView}
+    views::{
+      Dialog, DummyView, EditView, LinearLayout, NamedView, SelectView,
+      TextView
+    }
   };
   use std::sync::Arc;
 
-  /// Add a new named dialog to the menu tree.
-  /// Not necessary to configure with distinct names, because only one is active at a time
-  pub fn create_dialog(
-    model: Arc<Model>,
-    dialog_name: &'static str,
-    dialog_title: &'static str
-  ) -> NamedView<Dialog> {
+  /// The basket every category of the menu tree adds its items to,
+  /// so a receipt mixing several categories is committed in one go
+  pub fn create_dialog(model: Arc<Model>) -> NamedView<Dialog> {
+    let add_model = Model::clone(&model);
+    let shop_model = Model::clone(&model);
+    let status = model.queue().map(sync_status).unwrap_or_default();
+
     Dialog::around(
       LinearLayout::vertical()
         .child(DummyView)
+        .child(shop_picker(shop_model))
+        // typed in first, so the commit can check the items against it
+        .child(
+          LinearLayout::horizontal()
+            .child(TextView::new("Receipt total: "))
+            .child(EditView::new().with_name("receipt_total").fixed_width(12))
+        )
+        .child(DummyView)
+        .child(category_picker())
         .child(
           Dialog::around(create_menu_item())
-            .button("Add", |s| add_button(s, "select_item"))
-            .button("Commit", move |s| {
-              commit_button(s, Model::clone(&model), dialog_title.to_string())
+            .button("Add", move |s| {
+              add_button(s, add_model.rules(), "select_item")
             })
+            .button("Commit", move |s| commit_button(s, Model::clone(&model)))
             .button("Back", |s| {
-              s.pop_layer();
+              if can_leave_basket(s) {
+                s.pop_layer();
+              }
             })
         )
+        .child(pending_header())
         .child(
-          SelectView::<Item>::new()
-            .on_submit(|s, item| delete_item(s, item))
-            .with_name("select_item")
-            .full_screen()
+          pending_list(
+            SelectView::<Item>::new()
+              .on_submit(|s, item| item_actions(s, item))
+              .with_name("select_item")
+          )
+          .full_screen()
         )
+        .child(pending_footer())
+        .child(TextView::new(status).with_name("sync_status"))
     )
-    .title(dialog_title)
-    .with_name(dialog_name)
+    .title("Basket")
+    .with_name("basket_dialog")
   }
 }
 
-mod menu_item {
+pub mod menu_item {
+  use crate::item::{Item, Unit};
   use cursive::{
-    view::{Margins, Nameable},
-    views::{Dialog, DummyView, EditView, LinearLayout}
+    view::{Margins, Nameable, Resizable},
+    views::{Dialog, DummyView, EditView, LinearLayout, SelectView},
+    Cursive
   };
 
   /// Return a new named menu item
@@ -67,9 +95,19 @@ mod menu_item {
       )
       .child(DummyView)
       .child(
-        Dialog::around(EditView::new().with_name("product_amount"))
-          .title("Amount")
-          .padding(Margins::lrtb(1, 1, 1, 1))
+        Dialog::around(
+          LinearLayout::horizontal()
+            .child(EditView::new().with_name("product_amount").full_width())
+            .child(DummyView)
+            .child(
+              SelectView::<Unit>::new()
+                .popup()
+                .with_all(Unit::ALL.map(|unit| (unit.as_str(), unit)))
+                .with_name("product_unit")
+            )
+        )
+        .title("Amount")
+        .padding(Margins::lrtb(1, 1, 1, 1))
       )
       .child(DummyView)
       .child(
@@ -78,23 +116,72 @@ mod menu_item {
           .padding(Margins::lrtb(1, 1, 1, 1))
       )
   }
+
+  /// Load the fields of an `Item` into the `EditView`s of a menu item
+  pub fn fill_menu_item(s: &mut Cursive, item: &Item) {
+    let names = [
+      "product_name",
+      "product_price",
+      "product_amount",
+      "product_date_of_purchase"
+    ];
+
+    for (name, content) in names.into_iter().zip(item.as_vec()) {
+      s.call_on_name(name, |edit_view: &mut EditView| {
+        edit_view.set_content(content);
+      });
+    }
+
+    if let Ok(unit) = item.validate_unit() {
+      s.call_on_name("product_unit", |select_view: &mut SelectView<Unit>| {
+        if let Some(idx) = Unit::ALL.iter().position(|known| *known == unit) {
+          select_view.set_selection(idx);
+        }
+      });
+    }
+  }
+
+  /// Empty the `EditView`s of a menu item, the unit stays as it is
+  pub fn clear_menu_item(s: &mut Cursive) {
+    for name in [
+      "product_name",
+      "product_price",
+      "product_amount",
+      "product_date_of_purchase"
+    ] {
+      s.call_on_name(name, |edit_view: &mut EditView| {
+        edit_view.set_content("");
+      });
+    }
+  }
 }
 
 pub mod leaf {
   use super::dialog::create_dialog;
-  use crate::model::Model;
-  use cursive::Cursive;
+  use crate::{
+    buttons::{load_categories, load_shops, select_category},
+    draft::offer_restore,
+    model::Model
+  };
+  use cursive::{views::Dialog, Cursive};
   use std::sync::Arc;
 
-  pub fn create_leaf(
-    s: &mut Cursive,
-    model: Arc<Model>,
-    name: &'static str,
-    title: &'static str
-  ) {
+  /// Open the basket with `category` picked for the next item.
+  /// A basket that wasn't committed last time can be restored into it
+  pub fn create_leaf(s: &mut Cursive, model: Arc<Model>, category: String) {
+    // the basket is already open, keep its items and switch the category
+    if s.find_name::<Dialog>("basket_dialog").is_some() {
+      return select_category(s, &category);
+    }
+
     // Pop previous layer
     s.pop_layer();
 
-    s.add_layer(create_dialog(model, name, title));
+    s.add_layer(create_dialog(Model::clone(&model)));
+
+    load_shops(Model::clone(&model), None);
+    load_categories(model, category);
+
+    offer_restore(s);
   }
 }
@@ -1,7 +1,18 @@
 mod add_button;
+mod category_picker;
 mod commit_button;
 mod components;
+mod pending_table;
+mod shop_picker;
 
-pub use add_button::add_button;
-pub use commit_button::commit_button;
-pub use components::leaf::create_leaf;
+pub use add_button::{add_button, read_menu_item};
+pub use category_picker::{
+  load_categories, select_category, selected_category
+};
+pub use commit_button::{can_leave_basket, commit_button};
+pub use components::{
+  leaf::create_leaf,
+  menu_item::{clear_menu_item, create_menu_item, fill_menu_item}
+};
+pub use pending_table::{basket_changed, pending_position, pending_row};
+pub use shop_picker::{load_shops, selected_shop_id};
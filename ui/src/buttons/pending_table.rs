@@ -0,0 +1,243 @@
+use std::cmp::Ordering;
+
+use cursive::{
+  view::{Nameable, Resizable},
+  views::{
+    Button, DummyView, LinearLayout, NamedView, OnEventView, SelectView,
+    TextView
+  },
+  Cursive
+};
+
+use crate::{draft::save_draft, item::Item, money::Money};
+
+/// A column of the pending list, in the order they're shown
+#[derive(Debug, Clone, Copy)]
+enum Column {
+  Category,
+  Name,
+  Price,
+  Amount,
+  Total,
+  Date
+}
+
+impl Column {
+  const ALL: [Column; 6] = [
+    Self::Category,
+    Self::Name,
+    Self::Price,
+    Self::Amount,
+    Self::Total,
+    Self::Date
+  ];
+
+  fn title(self) -> &'static str {
+    match self {
+      Self::Category => "Category",
+      Self::Name => "Name",
+      Self::Price => "Price",
+      Self::Amount => "Amount",
+      Self::Total => "Total",
+      Self::Date => "Date"
+    }
+  }
+
+  fn width(self) -> usize {
+    match self {
+      Self::Category => 12,
+      Self::Name => 24,
+      Self::Price => 9,
+      Self::Amount => 11,
+      Self::Total => 9,
+      Self::Date => 10
+    }
+  }
+
+  /// Anything that doesn't parse goes first, so it's easy to spot
+  fn compare(self, a: &Item, b: &Item) -> Ordering {
+    match self {
+      Self::Category => a.category().cmp(b.category()),
+      Self::Name => a.name().to_lowercase().cmp(&b.name().to_lowercase()),
+      Self::Price => a.validate_price().ok().cmp(&b.validate_price().ok()),
+      Self::Amount => {
+        let a = a.validate_amount().unwrap_or(f64::NEG_INFINITY);
+        let b = b.validate_amount().unwrap_or(f64::NEG_INFINITY);
+
+        a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+      }
+      Self::Total => a.total().cmp(&b.total()),
+      // resolved to ISO on `Add`, so text order is date order
+      Self::Date => a.purchase_date().cmp(b.purchase_date())
+    }
+  }
+}
+
+/// Cut `text` down to `width` characters, so the columns after it stay put
+fn fit(text: &str, width: usize) -> String {
+  if text.chars().count() <= width {
+    return text.to_string();
+  }
+
+  let mut cut = text.chars().take(width - 1).collect::<String>();
+
+  cut.push('~');
+  cut
+}
+
+/// Where the item with `id` is in the pending list right now.
+/// Rows move while a commit removes its items, so they're found by id
+pub fn pending_position(
+  select_view: &SelectView<Item>,
+  id: u64
+) -> Option<usize> {
+  select_view.iter().position(|(_, item)| item.id() == id)
+}
+
+/// Format a pending item into a row lined up with the header
+pub fn pending_row(item: &Item) -> String {
+  let cell = |column: Column, text: &str| {
+    let text = fit(text, column.width());
+
+    match column {
+      Column::Price | Column::Total => {
+        format!("{:>width$}", text, width = column.width())
+      }
+      _ => format!("{:<width$}", text, width = column.width())
+    }
+  };
+
+  let total = item.total().map(|total| total.to_string());
+  let amount = format!("{} {}", item.amount(), item.unit());
+
+  let mut row = [
+    cell(Column::Category, item.category()),
+    cell(Column::Name, item.name()),
+    cell(Column::Price, item.price()),
+    cell(Column::Amount, &amount),
+    cell(Column::Total, total.as_deref().unwrap_or("?")),
+    cell(Column::Date, item.purchase_date())
+  ]
+  .join(" ");
+
+  if let Some((price, unit)) = item.unit_price() {
+    row.push_str(&format!(" {}/{}", price, unit));
+  }
+
+  if item.is_editing() {
+    row.push_str(" | editing");
+  }
+
+  if item.is_committing() {
+    row.push_str(" | committing");
+  }
+
+  if let Some(rejection) = item.rejection() {
+    row.push_str(&format!(" | rejected: {}", rejection));
+  }
+
+  row
+}
+
+/// Clickable column titles above the pending list, each one sorts by itself
+pub fn pending_header() -> LinearLayout {
+  let mut header = LinearLayout::horizontal();
+
+  for column in Column::ALL {
+    header.add_child(
+      Button::new_raw(column.title(), move |s| sort_pending(s, column))
+        .fixed_width(column.width() + 1)
+    );
+  }
+
+  header
+}
+
+/// The pending list itself, keys `1` to `6` sort by the matching column
+pub fn pending_list(
+  select_view: NamedView<SelectView<Item>>
+) -> OnEventView<NamedView<SelectView<Item>>> {
+  let mut list = OnEventView::new(select_view);
+
+  for (idx, column) in Column::ALL.into_iter().enumerate() {
+    let key = char::from_digit(idx as u32 + 1, 10).unwrap_or_default();
+
+    list.set_on_event(key, move |s| sort_pending(s, column));
+  }
+
+  list
+}
+
+/// Item count and the sum of the basket, below the pending list
+pub fn pending_footer() -> LinearLayout {
+  LinearLayout::horizontal()
+    .child(
+      TextView::new(footer_text(0, Money::default()))
+        .with_name("basket_footer")
+    )
+    .child(DummyView)
+    .child(TextView::new("(press 1-6 to sort)"))
+}
+
+fn footer_text(count: usize, sum: Money) -> String {
+  format!("{} items, {} in total", count, sum)
+}
+
+/// Call it whenever items are added, removed or reordered.
+/// Counts the pending items and adds up their totals again,
+/// then saves them as a draft, so they survive a restart
+pub fn basket_changed(s: &mut Cursive) {
+  let items =
+    s.call_on_name("select_item", |select_view: &mut SelectView<Item>| {
+      select_view
+        .iter()
+        .map(|(_, item)| item.clone())
+        .collect::<Vec<_>>()
+    });
+
+  let items = match items {
+    Some(items) => items,
+    None => return
+  };
+
+  let sum = items.iter().filter_map(Item::total).sum::<Money>();
+
+  s.call_on_name("basket_footer", |text_view: &mut TextView| {
+    text_view.set_content(footer_text(items.len(), sum));
+  });
+
+  // only a backup, typing goes on even if it can't be written
+  let _ = save_draft(&items);
+}
+
+/// Sort the pending list by `column`, the selected item stays selected.
+/// Sorting by the column it's already sorted by turns the order around
+fn sort_pending(s: &mut Cursive, column: Column) {
+  s.call_on_name("select_item", |select_view: &mut SelectView<Item>| {
+    let selected = select_view.selection().map(|item| item.id());
+
+    let items = select_view.iter().map(|(_, item)| item).collect::<Vec<_>>();
+
+    let ascending = items
+      .windows(2)
+      .all(|pair| column.compare(pair[0], pair[1]) != Ordering::Greater);
+
+    // in place, the rows are moved around instead of being made again
+    if ascending {
+      select_view.sort_by(|a, b| column.compare(b, a));
+    } else {
+      select_view.sort_by(|a, b| column.compare(a, b));
+    }
+
+    let idx = select_view
+      .iter()
+      .position(|(_, item)| Some(item.id()) == selected);
+
+    if let Some(idx) = idx {
+      // nothing happens on select in the pending list
+      let _ = select_view.set_selection(idx);
+    }
+  });
+
+  basket_changed(s);
+}
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use cursive::{
+  view::{Nameable, Resizable},
+  views::{
+    Button, Dialog, DummyView, EditView, LinearLayout, SelectView, TextView
+  },
+  Cursive
+};
+
+use crate::{item::MAX_TEXT_LENGTH, model::Model, store::Shop};
+
+/// Popup to pick the store the whole basket was bought in,
+/// with a button to add a store that isn't in the list yet
+pub fn shop_picker(model: Arc<Model>) -> LinearLayout {
+  LinearLayout::horizontal()
+    .child(TextView::new("Store: "))
+    .child(
+      SelectView::<Option<Shop>>::new()
+        .popup()
+        .item("No store", None)
+        .with_name("basket_store")
+        .min_width(20)
+    )
+    .child(DummyView)
+    .child(Button::new("New store", move |s| {
+      add_shop(s, Model::clone(&model))
+    }))
+}
+
+/// Fetch the stores and put them into the picker,
+/// `selected` is the id of the store to pick afterwards
+pub fn load_shops(model: Arc<Model>, selected: Option<i32>) {
+  tokio::spawn(async move {
+    let shops = match model.store().list_shops().await {
+      Ok(shops) => shops,
+      Err(error) => {
+        let message = format!("Failed to load the stores:\n{}", error);
+
+        return model.use_cb_sink(move |s| {
+          s.add_layer(Dialog::info(message));
+        });
+      }
+    };
+
+    model.use_cb_sink(move |s| {
+      s.call_on_name(
+        "basket_store",
+        |select_view: &mut SelectView<Option<Shop>>| {
+          select_view.clear();
+          select_view.add_item("No store", None);
+
+          for shop in shops {
+            select_view.add_item(shop.name.clone(), Some(shop));
+          }
+
+          let idx = select_view
+            .iter()
+            .position(|(_, shop)| shop.as_ref().map(|shop| shop.id) == selected)
+            .unwrap_or_default();
+
+          select_view.set_selection(idx);
+        }
+      );
+    });
+  });
+}
+
+/// The id of the store picked for the basket, `None` for no store
+pub fn selected_shop_id(s: &mut Cursive) -> Option<i32> {
+  s.call_on_name(
+    "basket_store",
+    |select_view: &mut SelectView<Option<Shop>>| {
+      select_view
+        .selection()
+        .and_then(|shop| shop.as_ref().as_ref().map(|shop| shop.id))
+    }
+  )
+  .flatten()
+}
+
+/// Ask for the name of a new store, then pick it for the basket
+fn add_shop(s: &mut Cursive, model: Arc<Model>) {
+  s.add_layer(
+    Dialog::around(EditView::new().with_name("store_name").fixed_width(30))
+      .title("New store")
+      .button("Save", move |s| {
+        let name = s
+          .call_on_name("store_name", |edit_view: &mut EditView| {
+            edit_view.get_content().trim().to_string()
+          })
+          .unwrap_or_default();
+
+        if name.is_empty() {
+          return s.add_layer(Dialog::info("Name cannot be empty"));
+        }
+
+        if name.chars().count() > MAX_TEXT_LENGTH {
+          return s.add_layer(Dialog::info(format!(
+            "Name is longer than {} characters",
+            MAX_TEXT_LENGTH
+          )));
+        }
+
+        s.pop_layer();
+
+        let model = Model::clone(&model);
+
+        tokio::spawn(async move {
+          match model.store().create_shop(name).await {
+            Ok(shop) => load_shops(model, Some(shop.id)),
+            Err(error) => {
+              let message = format!("Failed to add store:\n{}", error);
+
+              model.use_cb_sink(move |s| {
+                s.add_layer(Dialog::info(message));
+              });
+            }
+          }
+        });
+      })
+      .button("Cancel", |s| {
+        s.pop_layer();
+      })
+  );
+}
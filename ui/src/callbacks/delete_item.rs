@@ -3,36 +3,31 @@ use cursive::{
   Cursive
 };
 
-use crate::item::Item;
-
-/// Delete an item from the `select_item` list
-pub fn delete_item(s: &mut Cursive, item: &Item) {
-  let selected = s.find_name::<SelectView<Item>>("select_item").unwrap();
+use crate::{
+  buttons::{basket_changed, pending_position},
+  item::Item
+};
 
-  // match on the currently selected item in `SelectView`
-  match selected.selected_id() {
-    None => {
-      s.add_layer(Dialog::info("Nothing to remove!").button("Dismiss", |s| {
-        s.pop_layer();
-      }));
-    }
+/// Delete the item with `id` from the `select_item` list, after asking.
+/// If it's gone by then (e.g. a commit took it), nothing happens
+pub fn delete_item(s: &mut Cursive, id: u64) {
+  s.add_layer(
+    // Popup window that asks for confirmation
+    Dialog::around(TextView::new("Remove item from list?"))
+      .button("Confirm", move |s| {
+        s.call_on_name("select_item", |select_view: &mut SelectView<Item>| {
+          if let Some(idx) = pending_position(select_view, id) {
+            select_view.remove_item(idx);
+          }
+        });
 
-    Some(item) => {
-      s.add_layer(
-        // Popup window that asks for confirmation
-        Dialog::around(TextView::new("Remove item from list?"))
-          .button("Confirm", move |s| {
-            s.find_name::<SelectView<Item>>("select_item")
-              .unwrap()
-              .remove_item(item);
+        basket_changed(s);
 
-            // remove the window from the view upon action
-            s.pop_layer();
-          })
-          .button("Cancel", |s| {
-            s.pop_layer();
-          })
-      );
-    }
-  }
+        // remove the window from the view upon action
+        s.pop_layer();
+      })
+      .button("Cancel", |s| {
+        s.pop_layer();
+      })
+  );
 }
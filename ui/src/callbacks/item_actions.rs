@@ -0,0 +1,132 @@
+use cursive::{
+  views::{Dialog, SelectView, TextView},
+  Cursive
+};
+
+use super::delete_item;
+use crate::{
+  buttons::{
+    basket_changed, clear_menu_item, fill_menu_item, pending_position,
+    pending_row, select_category
+  },
+  item::Item
+};
+
+/// Ask what should happen with an item selected in the `select_item` list
+pub fn item_actions(s: &mut Cursive, item: &Item) {
+  // it's on its way to the database, it goes away or comes back flagged
+  if item.is_committing() {
+    return s.add_layer(Dialog::info(format!(
+      "'{}' is being committed, wait until that's done",
+      item.name()
+    )));
+  }
+
+  let id = item.id();
+
+  let mut dialog = Dialog::around(TextView::new(format!(
+    "What should happen with '{}'?",
+    item.name()
+  )))
+  .title("Item");
+
+  if item.is_editing() {
+    dialog.add_button("Stop editing", move |s| {
+      s.pop_layer();
+
+      stop_editing(s, id);
+    });
+  } else {
+    dialog.add_button("Edit", move |s| {
+      s.pop_layer();
+
+      edit_item(s, id);
+    });
+  }
+
+  s.add_layer(
+    dialog
+      .button("Duplicate", move |s| {
+        s.pop_layer();
+
+        duplicate_item(s, id);
+      })
+      .button("Delete", move |s| {
+        s.pop_layer();
+
+        delete_item(s, id);
+      })
+      .button("Cancel", |s| {
+        s.pop_layer();
+      })
+  );
+}
+
+/// Load the item with `id` back into the form. It stays in the list marked
+/// as being edited, until `Add` replaces it with whatever the form holds
+fn edit_item(s: &mut Cursive, id: u64) {
+  let item =
+    s.call_on_name("select_item", |select_view: &mut SelectView<Item>| {
+      // only a single item can be edited at a time
+      for (label, item) in select_view.iter_mut() {
+        if item.is_editing() {
+          item.set_editing(false);
+          *label = pending_row(item).into();
+        }
+      }
+
+      let idx = pending_position(select_view, id)?;
+      let (label, item) = select_view.get_item_mut(idx)?;
+
+      item.set_editing(true);
+      *label = pending_row(item).into();
+
+      Some(item.clone())
+    });
+
+  let item = match item.flatten() {
+    Some(item) => item,
+    None => return s.add_layer(Dialog::info("Couldn't find that item"))
+  };
+
+  fill_menu_item(s, &item);
+  select_category(s, item.category());
+}
+
+/// Leave the item with `id` as it was and empty the form,
+/// whatever was changed in the form is dropped
+fn stop_editing(s: &mut Cursive, id: u64) {
+  s.call_on_name("select_item", |select_view: &mut SelectView<Item>| {
+    let idx = pending_position(select_view, id);
+
+    if let Some((label, item)) =
+      idx.and_then(|idx| select_view.get_item_mut(idx))
+    {
+      item.set_editing(false);
+      *label = pending_row(item).into();
+    }
+  });
+
+  clear_menu_item(s);
+  basket_changed(s);
+}
+
+/// Add a copy of the item with `id` right below it,
+/// handy for the same product bought twice with a different price
+fn duplicate_item(s: &mut Cursive, id: u64) {
+  s.call_on_name("select_item", |select_view: &mut SelectView<Item>| {
+    let idx = match pending_position(select_view, id) {
+      Some(idx) => idx,
+      None => return
+    };
+
+    let item = match select_view.get_item(idx) {
+      Some((_, item)) => item.duplicate(),
+      None => return
+    };
+
+    select_view.insert_item(idx + 1, pending_row(&item), item);
+  });
+
+  basket_changed(s);
+}
@@ -1,3 +1,5 @@
 mod delete_item;
+mod item_actions;
 
 pub use delete_item::delete_item;
+pub use item_actions::item_actions;
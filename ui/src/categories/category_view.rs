@@ -0,0 +1,296 @@
+use std::sync::Arc;
+
+use cursive::{
+  view::{Nameable, Resizable, Scrollable},
+  views::{Dialog, EditView, SelectView, TextView},
+  Cursive
+};
+
+use super::food_menu::reload_food_menu;
+use crate::{
+  buttons::can_leave_basket, item::MAX_TEXT_LENGTH, model::Model,
+  store::Category
+};
+
+/// Format a category into a single line of the `SelectView`
+fn format_category(category: &Category) -> String {
+  if category.archived {
+    format!("{} (archived)", category.name)
+  } else {
+    category.name.clone()
+  }
+}
+
+/// Open the category manager in place of the currently open dialog.
+/// Categories are listed in the same order as in the `Food` menu
+pub fn create_categories(s: &mut Cursive, model: Arc<Model>) {
+  // the basket may be open below
+  if !can_leave_basket(s) {
+    return;
+  }
+
+  // Pop previous layer
+  s.pop_layer();
+
+  let add_model = Model::clone(&model);
+  let rename_model = Model::clone(&model);
+  let up_model = Model::clone(&model);
+  let down_model = Model::clone(&model);
+  let archive_model = Model::clone(&model);
+
+  s.add_layer(
+    Dialog::around(
+      SelectView::<Category>::new()
+        .with_name("category_items")
+        .scrollable()
+        .full_screen()
+    )
+    .title("Categories")
+    .button("Add", move |s| add_category(s, Model::clone(&add_model)))
+    .button("Rename", move |s| {
+      rename_category(s, Model::clone(&rename_model))
+    })
+    .button("Up", move |s| move_category(s, Model::clone(&up_model), -1))
+    .button("Down", move |s| {
+      move_category(s, Model::clone(&down_model), 1)
+    })
+    .button("Archive", move |s| {
+      archive_category(s, Model::clone(&archive_model))
+    })
+    .button("Back", |s| {
+      s.pop_layer();
+    })
+  );
+
+  load_categories(model, 0);
+}
+
+/// Fetch every category, archived ones included, and put them into the
+/// `SelectView`. The row at `selected` gets focused afterwards
+fn load_categories(model: Arc<Model>, selected: usize) {
+  tokio::spawn(async move {
+    let categories = match model.store().list_categories(true).await {
+      Ok(categories) => categories,
+      Err(error) => {
+        let message = format!("Failed to load the categories:\n{}", error);
+
+        return model.use_cb_sink(move |s| {
+          s.add_layer(Dialog::info(message));
+        });
+      }
+    };
+
+    model.use_cb_sink(move |s| {
+      s.call_on_name(
+        "category_items",
+        |select_view: &mut SelectView<Category>| {
+          select_view.clear();
+
+          for category in categories {
+            select_view.add_item(format_category(&category), category);
+          }
+
+          select_view.set_selection(selected);
+        }
+      );
+    });
+  });
+}
+
+/// Reload the list and the `Food` menu after a category was changed,
+/// or tell the user what went wrong
+fn after_change<T, E: ToString>(
+  model: Arc<Model>,
+  result: Result<T, E>,
+  selected: usize,
+  action: &str
+) {
+  match result {
+    Ok(_) => {
+      load_categories(Model::clone(&model), selected);
+      reload_food_menu(model);
+    }
+    Err(error) => {
+      let message =
+        format!("Failed to {} category:\n{}", action, error.to_string());
+
+      model.use_cb_sink(move |s| {
+        s.add_layer(Dialog::info(message));
+      });
+    }
+  }
+}
+
+/// The focused category and its row, if there is any
+fn selected_category(s: &mut Cursive) -> Option<(usize, Category)> {
+  let select_view = s.find_name::<SelectView<Category>>("category_items")?;
+  let idx = select_view.selected_id()?;
+
+  select_view
+    .selection()
+    .map(|category| (idx, Category::clone(&category)))
+}
+
+/// Read the name typed into the category dialog
+fn read_category_name(s: &mut Cursive) -> Result<String, String> {
+  let name = s
+    .call_on_name("category_name", |edit_view: &mut EditView| {
+      edit_view.get_content().trim().to_string()
+    })
+    .ok_or_else(|| String::from("Couldn't find content with the given name"))?;
+
+  if name.is_empty() {
+    return Err(String::from("Name cannot be empty"));
+  }
+
+  if name.chars().count() > MAX_TEXT_LENGTH {
+    return Err(format!(
+      "Name is longer than {} characters",
+      MAX_TEXT_LENGTH
+    ));
+  }
+
+  Ok(name)
+}
+
+/// Dialog with a single `EditView` for the name of a category,
+/// `on_save` receives the trimmed name once it passed the checks
+fn name_dialog<F>(title: &str, content: &str, on_save: F) -> Dialog
+where
+  F: Fn(&mut Cursive, String) + Send + Sync + 'static
+{
+  Dialog::around(
+    EditView::new()
+      .content(content)
+      .with_name("category_name")
+      .fixed_width(30)
+  )
+  .title(title)
+  .button("Save", move |s| match read_category_name(s) {
+    Ok(name) => {
+      s.pop_layer();
+
+      on_save(s, name);
+    }
+    Err(message) => s.add_layer(Dialog::info(message))
+  })
+  .button("Cancel", |s| {
+    s.pop_layer();
+  })
+}
+
+/// New categories go to the bottom of the menu
+fn add_category(s: &mut Cursive, model: Arc<Model>) {
+  s.add_layer(name_dialog("New category", "", move |s, name| {
+    let position = s
+      .find_name::<SelectView<Category>>("category_items")
+      .map(|select_view| select_view.len())
+      .unwrap_or_default();
+
+    let model = Model::clone(&model);
+
+    tokio::spawn(async move {
+      let result = model.store().create_category(name, position as i32).await;
+
+      after_change(model, result, position, "add");
+    });
+  }));
+}
+
+/// Committed records keep pointing at the renamed category
+fn rename_category(s: &mut Cursive, model: Arc<Model>) {
+  let (idx, category) = match selected_category(s) {
+    Some(selected) => selected,
+    None => return s.add_layer(Dialog::info("No category is selected"))
+  };
+
+  s.add_layer(name_dialog(
+    &format!("Rename {}", category.name),
+    &category.name,
+    move |s, name| {
+      let model = Model::clone(&model);
+      let id = category.id;
+
+      tokio::spawn(async move {
+        let result = model.store().rename_category(id, name).await;
+
+        after_change(model, result, idx, "rename");
+      });
+    }
+  ));
+}
+
+/// Swap the focused category with the one above (`offset` of -1)
+/// or below it (`offset` of 1), then store the order of every category
+fn move_category(s: &mut Cursive, model: Arc<Model>, offset: isize) {
+  let ids = s.call_on_name(
+    "category_items",
+    |select_view: &mut SelectView<Category>| {
+      let idx = select_view.selected_id()?;
+      let target = idx as isize + offset;
+
+      if target < 0 || target as usize >= select_view.len() {
+        return None;
+      }
+
+      let mut ids = select_view
+        .iter()
+        .map(|(_, category)| category.id)
+        .collect::<Vec<_>>();
+
+      ids.swap(idx, target as usize);
+
+      Some((ids, target as usize))
+    }
+  );
+
+  // already at the top or the bottom, nothing to do
+  let (ids, target) = match ids.flatten() {
+    Some(ids) => ids,
+    None => return
+  };
+
+  tokio::spawn(async move {
+    let result = model.store().reorder_categories(ids).await;
+
+    after_change(model, result, target, "move");
+  });
+}
+
+/// Archived categories disappear from the `Food` menu, but the records
+/// committed with them stay. Archiving an archived category restores it
+fn archive_category(s: &mut Cursive, model: Arc<Model>) {
+  let (idx, category) = match selected_category(s) {
+    Some(selected) => selected,
+    None => return s.add_layer(Dialog::info("No category is selected"))
+  };
+
+  let question = if category.archived {
+    format!("Restore '{}' to the Food menu?", category.name)
+  } else {
+    format!(
+      "Archive '{}'? It won't show up in the Food menu",
+      category.name
+    )
+  };
+
+  s.add_layer(
+    Dialog::around(TextView::new(question))
+      .button("Confirm", move |s| {
+        s.pop_layer();
+
+        let model = Model::clone(&model);
+        let id = category.id;
+        let archived = !category.archived;
+
+        tokio::spawn(async move {
+          let result = model.store().set_category_archived(id, archived).await;
+
+          after_change(model, result, idx, "archive");
+        });
+      })
+      .button("Cancel", |s| {
+        s.pop_layer();
+      })
+  );
+}
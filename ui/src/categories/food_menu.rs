@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use cursive::{menu::Tree, views::Dialog};
+
+use crate::{buttons::create_leaf, model::Model, store::Category};
+
+/// Build the `Food` menu, a leaf for every category in the given order
+pub fn food_menu(model: Arc<Model>, categories: &[Category]) -> Tree {
+  let mut tree = Tree::new();
+
+  for category in categories {
+    // separate, cloned `Model` for every `leaf`
+    let leaf_model = Model::clone(&model);
+    let name = category.name.clone();
+
+    tree.add_leaf(category.name.clone(), move |s| {
+      create_leaf(s, Model::clone(&leaf_model), name.clone())
+    });
+  }
+
+  tree
+}
+
+/// Fetch the categories again and swap out the `Food` menu,
+/// so changes show up without restarting
+pub fn reload_food_menu(model: Arc<Model>) {
+  tokio::spawn(async move {
+    let categories = match model.store().list_categories(false).await {
+      Ok(categories) => categories,
+      Err(error) => {
+        let message = format!("Failed to reload the Food menu:\n{}", error);
+
+        return model.use_cb_sink(move |s| {
+          s.add_layer(Dialog::info(message));
+        });
+      }
+    };
+
+    let menu_model = Model::clone(&model);
+
+    model.use_cb_sink(move |s| {
+      if let Some(tree) = s.menubar().find_subtree("Food") {
+        *tree = food_menu(menu_model, &categories);
+      }
+    });
+  });
+}
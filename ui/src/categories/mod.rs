@@ -0,0 +1,5 @@
+mod category_view;
+mod food_menu;
+
+pub use category_view::create_categories;
+pub use food_menu::reload_food_menu;
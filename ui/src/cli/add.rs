@@ -0,0 +1,169 @@
+use super::flags::Flags;
+use crate::{
+  item::{FoodItem, Item, Unit},
+  model::Model
+};
+
+pub const USAGE: &str = "add --category NAME --name NAME --price PRICE \
+                         --amount AMOUNT [--unit UNIT] --date DATE";
+
+/// Get a flag every record needs
+fn required<'a>(flags: &'a Flags, flag: &str) -> Result<&'a str, String> {
+  flags
+    .get(flag)
+    .ok_or_else(|| format!("Missing `--{}`\nUsage: {}", flag, USAGE))
+}
+
+/// Explain why `category` can't be used, it's either archived or unknown
+async fn unknown_category(model: &Model, category: &str) -> String {
+  let archived = match model.store().list_categories(true).await {
+    Ok(categories) => categories
+      .iter()
+      .any(|known| known.archived && known.name == category),
+    Err(error) => return error.to_string()
+  };
+
+  if archived {
+    format!(
+      "Category `{}` is archived, restore it in Categories first",
+      category
+    )
+  } else {
+    format!("Unknown category `{}`", category)
+  }
+}
+
+/// Commit a single item, checked the same way as one typed into a `Food` dialog
+pub async fn add(model: &Model, args: &[String]) -> Result<String, String> {
+  let flags = Flags::parse(
+    args,
+    &["category", "name", "price", "amount", "unit", "date"]
+  )?;
+
+  if !flags.positional().is_empty() {
+    return Err(format!("Usage: {}", USAGE));
+  }
+
+  let category = required(&flags, "category")?;
+
+  let mut item = Item::new(
+    required(&flags, "name")?,
+    required(&flags, "price")?,
+    required(&flags, "amount")?,
+    flags.get("unit").unwrap_or(Unit::default().as_str()),
+    required(&flags, "date")?
+  );
+
+  item
+    .validate(model.rules())
+    .map_err(|err| err.to_string())?;
+  item
+    .resolve_purchase_date(model.rules().date_parser())
+    .map_err(|err| err.to_string())?;
+
+  // only what the `Food` menu offers, archived categories are hidden there
+  let categories = model
+    .store()
+    .list_categories(false)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  if !categories.iter().any(|known| known.name == category) {
+    return Err(unknown_category(model, category).await);
+  }
+
+  let mut food_item =
+    FoodItem::try_from(&item).map_err(|err| err.to_string())?;
+
+  food_item.set_table_name(category);
+  food_item.check_columns().map_err(|err| err.to_string())?;
+
+  let records = model
+    .store()
+    .create(vec![food_item])
+    .await
+    .map_err(|err| err.to_string())?;
+
+  Ok(format!("Added {} with id {}", item, records[0].id))
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use super::add;
+  use crate::{
+    model::Model,
+    money::Money,
+    store::{MemoryStore, PurchaseFilter},
+    validation::Rules
+  };
+
+  /// The same kind of model the commands run with, kept in memory
+  fn model() -> Arc<Model> {
+    Model::headless(MemoryStore::with_categories(&["Meat"]), Rules::default())
+  }
+
+  /// `add` with a half kilo of steak filed under `category`
+  fn args(category: &str) -> Vec<String> {
+    [
+      "--category",
+      category,
+      "--name",
+      "Steak",
+      "--price",
+      "12.99",
+      "--amount",
+      "0.5",
+      "--unit",
+      "kg",
+      "--date",
+      "2022-10-23"
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect()
+  }
+
+  #[tokio::test]
+  async fn commits_the_item_to_the_store() {
+    let model = model();
+
+    add(&model, &args("Meat")).await.unwrap();
+
+    let listed = model
+      .store()
+      .list(&PurchaseFilter::default())
+      .await
+      .unwrap();
+
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].name, "Steak");
+    assert_eq!(listed[0].total, Money::from_cents(650));
+  }
+
+  #[tokio::test]
+  async fn commits_nothing_under_an_unknown_category() {
+    let model = model();
+
+    assert!(add(&model, &args("Fish")).await.is_err());
+    assert_eq!(model.store().count().await.unwrap(), 0);
+  }
+
+  #[tokio::test]
+  async fn commits_nothing_under_an_archived_category() {
+    let model = model();
+    let meat = model.store().list_categories(false).await.unwrap()[0].id;
+
+    model
+      .store()
+      .set_category_archived(meat, true)
+      .await
+      .unwrap();
+
+    let error = add(&model, &args("Meat")).await.unwrap_err();
+
+    assert!(error.contains("archived"));
+    assert_eq!(model.store().count().await.unwrap(), 0);
+  }
+}
@@ -0,0 +1,70 @@
+use std::process;
+
+use super::{
+  add::{self, add},
+  export::{self, export},
+  list::{self, list},
+  report::{self, report}
+};
+use crate::{
+  config::{open_database, Settings},
+  model::Model,
+  store::PrismaStore
+};
+
+/// Every command that runs without opening the TUI
+const COMMANDS: [&str; 4] =
+  [add::USAGE, list::USAGE, report::USAGE, export::USAGE];
+
+/// Run the command in `args` (without the binary name) and exit.
+/// Errors are written to stderr with a non-zero exit code
+pub async fn run_command(args: Vec<String>) {
+  let (command, args) = args.split_first().unwrap();
+
+  if !COMMANDS
+    .iter()
+    .any(|usage| usage.split(' ').next() == Some(command.as_str()))
+  {
+    eprintln!("Unknown command `{}`, available commands:", command);
+
+    for usage in COMMANDS {
+      eprintln!("  {}", usage);
+    }
+
+    process::exit(2);
+  }
+
+  let settings = match Settings::load() {
+    Ok(settings) => settings,
+    Err(error) => {
+      eprintln!("Failed to read the settings:\n{}", error);
+      process::exit(1);
+    }
+  };
+
+  let client = match open_database(settings.database_url.clone()).await {
+    Ok(client) => client,
+    Err(error) => {
+      eprintln!("Failed to connect to the database:\n{}", error);
+      process::exit(1);
+    }
+  };
+
+  let model = Model::headless(PrismaStore::new(client), settings.rules.rules());
+
+  let result = match command.as_str() {
+    "add" => add(&model, args).await,
+    "list" => list(&model, args).await,
+    "report" => report(&model, args).await,
+    "export" => export(&model, args).await,
+    _ => unreachable!()
+  };
+
+  match result {
+    Ok(message) => println!("{}", message),
+    Err(message) => {
+      eprintln!("{}", message);
+      process::exit(1);
+    }
+  }
+}
@@ -0,0 +1,28 @@
+use std::path::Path;
+
+use super::flags::Flags;
+use crate::{export::export_csv, model::Model, store::PurchaseFilter};
+
+pub const USAGE: &str =
+  "export <file> [--from DATE] [--to DATE] [--category NAME]";
+
+/// Write the records into a CSV file, the same way the `Export CSV` dialog does
+pub async fn export(model: &Model, args: &[String]) -> Result<String, String> {
+  let flags = Flags::parse(args, &["from", "to", "category"])?;
+
+  let path = match flags.positional() {
+    [path] => Path::new(path),
+    _ => return Err(format!("Usage: {}", USAGE))
+  };
+
+  let filter = PurchaseFilter::new(
+    flags.get("from").unwrap_or_default(),
+    flags.get("to").unwrap_or_default(),
+    flags.get("category").map(String::from),
+    model.rules().date_parser()
+  )?;
+
+  let count = export_csv(model, &filter, path).await?;
+
+  Ok(format!("Exported {} records to {}", count, path.display()))
+}
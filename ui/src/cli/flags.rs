@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+/// Arguments of a command, split into positional ones and `--flag value` pairs
+#[derive(Debug, Default)]
+pub struct Flags {
+  positional: Vec<String>,
+  values: HashMap<String, String>
+}
+
+impl Flags {
+  /// Split `args` up, refusing any flag that isn't in `allowed`
+  /// or that is missing its value
+  pub fn parse(args: &[String], allowed: &[&str]) -> Result<Self, String> {
+    let mut flags = Self::default();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+      match arg.strip_prefix("--") {
+        Some(flag) => {
+          if !allowed.contains(&flag) {
+            return Err(format!("Unknown flag `--{}`", flag));
+          }
+
+          let value = args
+            .next()
+            .ok_or_else(|| format!("Flag `--{}` needs a value", flag))?;
+
+          flags.values.insert(flag.to_string(), value.clone());
+        }
+        None => flags.positional.push(arg.clone())
+      }
+    }
+
+    Ok(flags)
+  }
+
+  /// Value of `--flag`, if it was given
+  pub fn get(&self, flag: &str) -> Option<&str> {
+    self.values.get(flag).map(String::as_str)
+  }
+
+  /// Every argument that isn't a flag, in order
+  pub fn positional(&self) -> &[String] {
+    &self.positional
+  }
+}
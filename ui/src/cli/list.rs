@@ -0,0 +1,66 @@
+use super::flags::Flags;
+use crate::{model::Model, store::PurchaseFilter};
+
+pub const USAGE: &str = "list [--from DATE] [--to DATE] [--category NAME]";
+
+/// Print the committed records as a table, oldest purchases first.
+/// Takes the same filters as `export`
+pub async fn list(model: &Model, args: &[String]) -> Result<String, String> {
+  let flags = Flags::parse(args, &["from", "to", "category"])?;
+
+  if !flags.positional().is_empty() {
+    return Err(format!("Usage: {}", USAGE));
+  }
+
+  let filter = PurchaseFilter::new(
+    flags.get("from").unwrap_or_default(),
+    flags.get("to").unwrap_or_default(),
+    flags.get("category").map(String::from),
+    model.rules().date_parser()
+  )?;
+
+  let records = model
+    .store()
+    .list(&filter)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  let mut table = format!(
+    "{:>6}  {:<10}  {:<12}  {:<24}  {:>10}  {:>8} {:<4}  {:>10}  {:>14}",
+    "id",
+    "date",
+    "category",
+    "name",
+    "price",
+    "amount",
+    "",
+    "total",
+    "unit price"
+  );
+
+  let date_format = model.rules().date_parser().display_format();
+
+  for record in records.iter() {
+    let unit_price = record
+      .unit_price()
+      .map(|(price, unit)| format!("{}/{}", price, unit))
+      .unwrap_or_default();
+
+    table.push_str(&format!(
+      "\n{:>6}  {:<10}  {:<12}  {:<24}  {:>10}  {:>8} {:<4}  {:>10}  {:>14}",
+      record.id,
+      record.date.format(date_format),
+      record.category,
+      record.name,
+      record.price,
+      record.amount,
+      record.unit,
+      record.total,
+      unit_price
+    ));
+  }
+
+  table.push_str(&format!("\n{} records", records.len()));
+
+  Ok(table)
+}
@@ -0,0 +1,8 @@
+mod add;
+mod command;
+mod export;
+mod flags;
+mod list;
+mod report;
+
+pub use command::run_command;
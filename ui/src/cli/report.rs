@@ -0,0 +1,25 @@
+use prisma_client_rust::chrono::{Datelike, Local};
+
+use super::flags::Flags;
+use crate::{model::Model, report::monthly_report};
+
+pub const USAGE: &str = "report [--year YYYY]";
+
+/// Print the monthly spending report, the current year unless told otherwise
+pub async fn report(model: &Model, args: &[String]) -> Result<String, String> {
+  let flags = Flags::parse(args, &["year"])?;
+
+  if !flags.positional().is_empty() {
+    return Err(format!("Usage: {}", USAGE));
+  }
+
+  let year = match flags.get("year") {
+    Some(year) => year
+      .trim()
+      .parse::<i32>()
+      .map_err(|_| String::from("Year does not have a valid format"))?,
+    None => Local::now().year()
+  };
+
+  monthly_report(model, year).await
+}
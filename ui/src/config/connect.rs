@@ -0,0 +1,98 @@
+use cursive::{
+  views::{Dialog, TextView},
+  Cursive
+};
+
+use prisma_client_rust::raw;
+
+use super::{create_database_settings, Settings};
+use crate::prisma::{self, PrismaClient};
+
+/// What to do with the client once the database is reachable,
+/// along with the settings it was reached with
+pub type Start = fn(&mut Cursive, PrismaClient, Settings);
+
+/// Connect to the database at `url`, or at `DATABASE_URL` without one,
+/// and make sure it actually answers. Nothing is written to it
+pub async fn connect(url: Option<String>) -> Result<PrismaClient, String> {
+  let client = match url {
+    Some(url) => prisma::new_client_with_url(&url).await,
+    None => prisma::new_client().await
+  }
+  .map_err(|error| error.to_string())?;
+
+  // the connection is only opened by the first query
+  client
+    ._query_raw::<serde_json::Value>(raw!("SELECT 1"))
+    .exec()
+    .await
+    .map_err(|error| error.to_string())?;
+
+  Ok(client)
+}
+
+/// `connect`, then get the database ready to be used
+pub async fn open_database(
+  url: Option<String>
+) -> Result<PrismaClient, String> {
+  let client = connect(url).await?;
+
+  // a new SQLite file starts out without any tables
+  #[cfg(feature = "sqlite")]
+  crate::store::create_tables(&client)
+    .await
+    .map_err(|error| format!("Failed to create the tables:\n{}", error))?;
+
+  Ok(client)
+}
+
+/// Connect with the saved settings in a tokio task, then hand the client
+/// to `start`. If that fails, the user can retry or fix the settings
+pub fn connect_and_start(s: &mut Cursive, start: Start) {
+  let settings = match Settings::load() {
+    Ok(settings) => settings,
+    Err(error) => {
+      let message = format!("Failed to read the settings:\n{}", error);
+
+      return show_connection_error(s, start, message);
+    }
+  };
+
+  s.add_layer(Dialog::text("Connecting to the database..."));
+
+  let cb_sink = s.cb_sink().clone();
+
+  tokio::spawn(async move {
+    let result = open_database(settings.database_url.clone()).await;
+
+    let _ = cb_sink.send(Box::new(move |s| {
+      // the connecting message
+      s.pop_layer();
+
+      match result {
+        Ok(client) => start(s, client, settings),
+        Err(error) => show_connection_error(s, start, error)
+      }
+    }));
+  });
+}
+
+/// Show why the database couldn't be reached
+fn show_connection_error(s: &mut Cursive, start: Start, error: String) {
+  s.add_layer(
+    Dialog::around(TextView::new(format!(
+      "Failed to connect to the database:\n{}",
+      error
+    )))
+    .title("Database")
+    .button("Retry", move |s| {
+      s.pop_layer();
+
+      connect_and_start(s, start);
+    })
+    .button("Settings", move |s| {
+      create_database_settings(s, Some(start))
+    })
+    .button("Quit", Cursive::quit)
+  );
+}
@@ -0,0 +1,116 @@
+use cursive::{
+  view::{Nameable, Resizable},
+  views::{Dialog, DummyView, EditView, LinearLayout, TextView},
+  Cursive
+};
+
+use super::{connect, connect_and_start, Settings, Start};
+
+/// Open the database settings on top of the current layer.
+/// With `start`, saving connects right away, like on startup.
+/// Otherwise the new URL is used from the next start
+pub fn create_database_settings(s: &mut Cursive, start: Option<Start>) {
+  // a broken file must not lock the user out, saving replaces it
+  let (saved, error) = match Settings::load() {
+    Ok(settings) => (settings.database_url, None),
+    Err(error) => (None, Some(error))
+  };
+
+  // what's used right now, if nothing was saved
+  let url = saved
+    .or_else(|| std::env::var("DATABASE_URL").ok())
+    .unwrap_or_default();
+
+  s.add_layer(
+    Dialog::around(
+      LinearLayout::vertical()
+        .child(TextView::new("Connection URL:"))
+        .child(
+          EditView::new()
+            .content(url)
+            .with_name("database_url")
+            .fixed_width(60)
+        )
+        .child(TextView::new("Empty to use DATABASE_URL"))
+        .child(DummyView)
+        .child(TextView::new("").with_name("database_test"))
+    )
+    .title("Database")
+    .button("Test", test_url)
+    .button("Save", move |s| save_url(s, start))
+    .button("Cancel", |s| {
+      s.pop_layer();
+    })
+  );
+
+  if let Some(error) = error {
+    s.add_layer(Dialog::info(format!(
+      "Failed to read the settings, saving starts them over:\n{}",
+      error
+    )));
+  }
+}
+
+/// The URL typed in, `None` when it's empty
+fn read_url(s: &mut Cursive) -> Option<String> {
+  s.call_on_name("database_url", |edit_view: &mut EditView| {
+    edit_view.get_content().trim().to_string()
+  })
+  .filter(|url| !url.is_empty())
+}
+
+/// Try to connect with the URL typed in, without keeping the connection
+fn test_url(s: &mut Cursive) {
+  let url = read_url(s);
+
+  s.call_on_name("database_test", |text_view: &mut TextView| {
+    text_view.set_content("Testing...");
+  });
+
+  let cb_sink = s.cb_sink().clone();
+
+  tokio::spawn(async move {
+    let result = match connect(url).await {
+      Ok(_) => String::from("Connected"),
+      Err(error) => format!("Failed to connect:\n{}", error)
+    };
+
+    let _ = cb_sink.send(Box::new(move |s| {
+      s.call_on_name("database_test", |text_view: &mut TextView| {
+        text_view.set_content(result);
+      });
+    }));
+  });
+}
+
+/// Save the URL typed in to the settings file
+fn save_url(s: &mut Cursive, start: Option<Start>) {
+  let url = read_url(s);
+
+  // everything but the URL stays the way it was. Settings that can't be
+  // read were reported when the dialog opened, they're started over
+  let mut settings = Settings::load().unwrap_or_default();
+
+  settings.database_url = url;
+
+  if let Err(error) = settings.save() {
+    return s.add_layer(Dialog::info(format!(
+      "Failed to save the settings:\n{}",
+      error
+    )));
+  }
+
+  s.pop_layer();
+
+  match start {
+    // the connection error is below, it's tried again instead
+    Some(start) => {
+      s.pop_layer();
+
+      connect_and_start(s, start);
+    }
+    None => s.add_layer(Dialog::info(
+      "Saved, the new URL is used from the next start"
+    ))
+  }
+}
@@ -0,0 +1,9 @@
+mod connect;
+mod database_settings;
+mod paths;
+mod settings;
+
+pub use connect::{connect, connect_and_start, open_database, Start};
+pub use database_settings::create_database_settings;
+pub use paths::data_file;
+pub use settings::{RuleSettings, Settings};
@@ -0,0 +1,19 @@
+use std::path::PathBuf;
+
+use directories::ProjectDirs;
+
+/// The directories the system keeps for this app,
+/// `None` if it doesn't have any (e.g. no home directory)
+fn project_dirs() -> Option<ProjectDirs> {
+  ProjectDirs::from("", "", "food-tracker")
+}
+
+/// A file in the data directory of the user
+pub fn data_file(name: &str) -> Option<PathBuf> {
+  project_dirs().map(|dirs| dirs.data_dir().join(name))
+}
+
+/// A file in the config directory of the user
+pub fn config_file(name: &str) -> Option<PathBuf> {
+  project_dirs().map(|dirs| dirs.config_dir().join(name))
+}
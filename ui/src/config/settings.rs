@@ -0,0 +1,138 @@
+use std::{
+  fs::{self, OpenOptions},
+  io::{self, Write},
+  path::PathBuf
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::paths::config_file;
+use crate::{date::DateParser, money::Money, validation::Rules};
+
+/// Name of the settings file inside the config directory
+const FILE_NAME: &str = "settings.json";
+
+/// Whatever the user set up in the app, kept in the config directory
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Settings {
+  /// Connection URL of the database, `DATABASE_URL` is used without one
+  pub database_url: Option<String>,
+  /// Loosens or tightens the validation `Rules`
+  #[serde(default)]
+  pub rules: RuleSettings
+}
+
+/// The validation `Rules` that differ from the defaults,
+/// anything left out (or `null`) keeps its default
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RuleSettings {
+  pub allow_negative_price: Option<bool>,
+  pub allow_zero_amount: Option<bool>,
+  pub allow_future_dates: Option<bool>,
+  pub max_name_length: Option<usize>,
+  /// In cents
+  pub receipt_tolerance: Option<i64>,
+  /// `chrono` formats for the dates, tried in order
+  pub date_formats: Option<Vec<String>>,
+  /// Whether `today`, `yesterday` and `-3d` are understood
+  pub relative_dates: Option<bool>
+}
+
+impl RuleSettings {
+  /// The default `Rules` with these settings applied
+  pub fn rules(&self) -> Rules {
+    let mut rules = Rules::default();
+
+    if let Some(allow) = self.allow_negative_price {
+      rules.set_allow_negative_price(allow);
+    }
+
+    if let Some(allow) = self.allow_zero_amount {
+      rules.set_allow_zero_amount(allow);
+    }
+
+    if let Some(allow) = self.allow_future_dates {
+      rules.set_allow_future_dates(allow);
+    }
+
+    if let Some(length) = self.max_name_length {
+      rules.set_max_name_length(length);
+    }
+
+    if let Some(cents) = self.receipt_tolerance {
+      rules.set_receipt_tolerance(Money::from_cents(cents));
+    }
+
+    if self.date_formats.is_some() || self.relative_dates.is_some() {
+      rules.set_date_parser(self.date_parser());
+    }
+
+    rules
+  }
+
+  fn date_parser(&self) -> DateParser {
+    let mut parser = match &self.date_formats {
+      Some(formats) => {
+        DateParser::new(&formats.iter().map(String::as_str).collect::<Vec<_>>())
+      }
+      None => DateParser::default()
+    };
+
+    if let Some(relative) = self.relative_dates {
+      parser.set_relative(relative);
+    }
+
+    parser
+  }
+}
+
+fn settings_path() -> io::Result<PathBuf> {
+  config_file(FILE_NAME).ok_or_else(|| {
+    io::Error::new(io::ErrorKind::NotFound, "there's no config directory")
+  })
+}
+
+impl Settings {
+  /// The saved settings, or the defaults if nothing was saved yet
+  pub fn load() -> io::Result<Self> {
+    match fs::read(settings_path()?) {
+      Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+      Err(error) if error.kind() == io::ErrorKind::NotFound => {
+        Ok(Self::default())
+      }
+      Err(error) => Err(error)
+    }
+  }
+
+  /// Write the settings to the config directory. The database URL may
+  /// hold a password, so only the user can read the file on unix
+  pub fn save(&self) -> io::Result<()> {
+    let path = settings_path()?;
+
+    if let Some(dir) = path.parent() {
+      fs::create_dir_all(dir)?;
+    }
+
+    let mut options = OpenOptions::new();
+
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+      use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+      // the mode only counts for a new file
+      options.mode(0o600);
+
+      if path.exists() {
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+      }
+    }
+
+    let mut file = options.open(&path)?;
+
+    file.write_all(&serde_json::to_vec_pretty(self)?)?;
+    file.sync_all()
+  }
+}
@@ -0,0 +1,206 @@
+use prisma_client_rust::chrono::{Duration, Local, NaiveDate};
+
+/// How a resolved date is written back, e.g. into the pending list
+pub const ISO_FORMAT: &str = "%Y-%m-%d";
+
+/// Formats accepted when nothing else is configured
+const DEFAULT_FORMATS: [&str; 3] = [ISO_FORMAT, "%d.%m.%Y", "%Y/%m/%d"];
+
+/// Turns whatever was typed as a date into a `NaiveDate`.
+/// Besides the `formats`, `today`, `yesterday` and `-3d` (three days ago)
+/// are understood, unless `relative` is turned off
+#[derive(Debug, Clone)]
+pub struct DateParser {
+  formats: Vec<String>,
+  relative: bool
+}
+
+impl Default for DateParser {
+  fn default() -> Self {
+    Self::new(&DEFAULT_FORMATS)
+  }
+}
+
+impl DateParser {
+  /// Accept the given `chrono` formats, tried in order
+  pub fn new(formats: &[&str]) -> Self {
+    Self {
+      formats: formats.iter().map(|format| format.to_string()).collect(),
+      relative: true
+    }
+  }
+
+  /// Turn the relative keywords on or off
+  pub fn set_relative(&mut self, relative: bool) -> &mut Self {
+    self.relative = relative;
+    self
+  }
+
+  /// Parse `text` against today's date
+  pub fn parse(&self, text: &str) -> Result<NaiveDate, String> {
+    self.parse_from(text, Local::today().naive_local())
+  }
+
+  /// Parse `text`, with relative dates counted back from `today`
+  pub fn parse_from(
+    &self,
+    text: &str,
+    today: NaiveDate
+  ) -> Result<NaiveDate, String> {
+    let text = text.trim().to_lowercase();
+
+    if self.relative {
+      if let Some(date) = parse_relative(&text, today)? {
+        return Ok(date);
+      }
+    }
+
+    self
+      .formats
+      .iter()
+      .find_map(|format| NaiveDate::parse_from_str(&text, format).ok())
+      .ok_or_else(|| format!("expected {}", self.expected(today)))
+  }
+
+  /// How dates are shown, in the first of the `formats`
+  pub fn display_format(&self) -> &str {
+    self.formats.first().map_or(ISO_FORMAT, String::as_str)
+  }
+
+  /// The same parser, accepting `ISO_FORMAT` as well.
+  /// That's what the app writes, e.g. into an export
+  pub fn with_iso(&self) -> Self {
+    let mut parser = self.clone();
+
+    if !parser.formats.iter().any(|format| format == ISO_FORMAT) {
+      parser.formats.push(ISO_FORMAT.to_string());
+    }
+
+    parser
+  }
+
+  /// Examples of every accepted input, for the error messages
+  fn expected(&self, today: NaiveDate) -> String {
+    let mut examples = self
+      .formats
+      .iter()
+      .map(|format| today.format(format).to_string())
+      .collect::<Vec<_>>();
+
+    if self.relative {
+      examples.extend(["today", "yesterday", "-3d"].map(String::from));
+    }
+
+    match examples.split_last() {
+      Some((last, rest)) if !rest.is_empty() => {
+        format!("{} or {}", rest.join(", "), last)
+      }
+      Some((last, _)) => last.clone(),
+      None => String::from("nothing, no date format is configured")
+    }
+  }
+}
+
+/// `today`, `yesterday` or a number of days ago like `-3d`.
+/// `None` if `text` is none of them, an error if it's too far back
+fn parse_relative(
+  text: &str,
+  today: NaiveDate
+) -> Result<Option<NaiveDate>, String> {
+  let days = match text {
+    "today" => 0,
+    "yesterday" => 1,
+    _ => {
+      let days = text
+        .strip_prefix('-')
+        .and_then(|text| text.strip_suffix('d'))
+        .and_then(|days| days.parse::<u32>().ok());
+
+      match days {
+        Some(days) => days,
+        None => return Ok(None)
+      }
+    }
+  };
+
+  today
+    .checked_sub_signed(Duration::days(days as i64))
+    .map(Some)
+    .ok_or_else(|| format!("{} is too far back", text))
+}
+
+#[cfg(test)]
+mod tests {
+  use prisma_client_rust::chrono::NaiveDate;
+
+  use super::DateParser;
+
+  fn today() -> NaiveDate {
+    NaiveDate::from_ymd(2022, 10, 23)
+  }
+
+  #[test]
+  fn accepts_every_default_format() {
+    let parser = DateParser::default();
+
+    for text in ["2022-10-23", "23.10.2022", "2022/10/23", " 2022-10-23 "] {
+      assert_eq!(parser.parse_from(text, today()), Ok(today()));
+    }
+  }
+
+  #[test]
+  fn counts_relative_dates_back_from_today() {
+    let parser = DateParser::default();
+
+    assert_eq!(parser.parse_from("Today", today()), Ok(today()));
+    assert_eq!(
+      parser.parse_from("yesterday", today()),
+      Ok(NaiveDate::from_ymd(2022, 10, 22))
+    );
+    assert_eq!(
+      parser.parse_from("-3d", today()),
+      Ok(NaiveDate::from_ymd(2022, 10, 20))
+    );
+  }
+
+  #[test]
+  fn refuses_relative_dates_before_the_calendar_starts() {
+    assert_eq!(
+      DateParser::default().parse_from("-999999999d", today()),
+      Err(String::from("-999999999d is too far back"))
+    );
+  }
+
+  #[test]
+  fn lists_what_it_accepts_when_nothing_fits() {
+    assert_eq!(
+      DateParser::default().parse_from("someday", today()),
+      Err(String::from(
+        "expected 2022-10-23, 23.10.2022, 2022/10/23, today, yesterday or -3d"
+      ))
+    );
+  }
+
+  #[test]
+  fn reads_back_what_the_app_writes() {
+    let parser = DateParser::new(&["%d.%m.%Y"]);
+
+    assert_eq!(parser.display_format(), "%d.%m.%Y");
+    assert!(parser.parse_from("2022-10-23", today()).is_err());
+    assert_eq!(
+      parser.with_iso().parse_from("2022-10-23", today()),
+      Ok(today())
+    );
+  }
+
+  #[test]
+  fn only_accepts_what_it_was_set_up_with() {
+    let mut parser = DateParser::new(&["%Y-%m-%d"]);
+
+    parser.set_relative(false);
+
+    assert_eq!(parser.parse_from("2022-10-23", today()), Ok(today()));
+    assert!(parser.parse_from("today", today()).is_err());
+    assert!(parser.parse_from("23.10.2022", today()).is_err());
+  }
+}
@@ -0,0 +1,3 @@
+mod date_parser;
+
+pub use date_parser::{DateParser, ISO_FORMAT};
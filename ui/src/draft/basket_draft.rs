@@ -0,0 +1,56 @@
+use std::{
+  fs,
+  io::{self, Write},
+  path::PathBuf
+};
+
+use crate::{config::data_file, item::Item};
+
+/// Name of the draft file inside the data directory
+const FILE_NAME: &str = "basket_draft.json";
+
+fn draft_path() -> io::Result<PathBuf> {
+  data_file(FILE_NAME).ok_or_else(|| {
+    io::Error::new(io::ErrorKind::NotFound, "there's no data directory")
+  })
+}
+
+/// Keep the pending items around in case the app is closed before a commit.
+/// An empty basket has nothing worth keeping, so its draft is removed
+pub fn save_draft(items: &[Item]) -> io::Result<()> {
+  if items.is_empty() {
+    return discard_draft();
+  }
+
+  let path = draft_path()?;
+
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir)?;
+  }
+
+  // written next to the draft and renamed, so a crash can't cut it in half
+  let temp_path = path.with_extension("json.tmp");
+  let mut file = fs::File::create(&temp_path)?;
+
+  file.write_all(&serde_json::to_vec(items)?)?;
+  file.sync_all()?;
+
+  fs::rename(&temp_path, &path)
+}
+
+/// The items of the last basket that wasn't committed, if there's any
+pub fn load_draft() -> io::Result<Vec<Item>> {
+  match fs::read(draft_path()?) {
+    Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+    Err(error) => Err(error)
+  }
+}
+
+/// Forget about the draft, a missing one is just as good
+pub fn discard_draft() -> io::Result<()> {
+  match fs::remove_file(draft_path()?) {
+    Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+    _ => Ok(())
+  }
+}
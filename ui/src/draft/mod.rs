@@ -0,0 +1,5 @@
+mod basket_draft;
+mod restore_draft;
+
+pub use basket_draft::save_draft;
+pub use restore_draft::{draft_category, offer_restore};
@@ -0,0 +1,72 @@
+use cursive::{
+  views::{Dialog, SelectView, TextView},
+  Cursive
+};
+
+use super::basket_draft::{discard_draft, load_draft};
+use crate::{
+  buttons::{basket_changed, pending_row, select_category},
+  item::Item
+};
+
+/// Category of the first item of the basket that wasn't committed
+/// last time, `None` if there's nothing to restore
+pub fn draft_category() -> Option<String> {
+  let items = load_draft().ok()?;
+
+  items.first().map(|item| item.category().to_string())
+}
+
+/// Offer to bring the basket that wasn't committed last time back
+/// into the basket that was just opened.
+/// It's thrown away if the user doesn't want it
+pub fn offer_restore(s: &mut Cursive) {
+  let items = match load_draft() {
+    Ok(items) if items.is_empty() => return,
+    Ok(items) => items,
+    Err(error) => {
+      return s.add_layer(Dialog::info(format!(
+        "Failed to read the basket that wasn't committed:\n{}",
+        error
+      )));
+    }
+  };
+
+  s.add_layer(
+    Dialog::around(TextView::new(format!(
+      "{} items weren't committed last time. Restore them?",
+      items.len()
+    )))
+    .title("Restore basket")
+    .button("Restore", move |s| {
+      s.pop_layer();
+
+      restore_items(s, items.clone())
+    })
+    .button("Discard", |s| {
+      s.pop_layer();
+
+      if let Err(error) = discard_draft() {
+        s.add_layer(Dialog::info(format!(
+          "Failed to discard the basket:\n{}",
+          error
+        )));
+      }
+    })
+  );
+}
+
+/// Put the items back into the open basket
+fn restore_items(s: &mut Cursive, items: Vec<Item>) {
+  if let Some(item) = items.first() {
+    select_category(s, item.category());
+  }
+
+  s.call_on_name("select_item", |select_view: &mut SelectView<Item>| {
+    for item in items {
+      select_view.add_item(pending_row(&item), item);
+    }
+  });
+
+  basket_changed(s);
+}
@@ -0,0 +1,73 @@
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::{
+  date::ISO_FORMAT,
+  model::Model,
+  store::{Purchase, PurchaseFilter}
+};
+
+/// A line of the CSV, the columns follow the `Food` table.
+/// Columns added later go last, so spreadsheets built on the old
+/// layout keep working
+#[derive(Debug, Serialize)]
+struct Row<'a> {
+  id: i32,
+  food_type: &'a str,
+  name: &'a str,
+  price: String,
+  amount: f64,
+  total: String,
+  date: String,
+  unit: &'static str,
+  store: &'a str
+}
+
+impl<'a> From<&'a Purchase> for Row<'a> {
+  fn from(record: &'a Purchase) -> Self {
+    Self {
+      id: record.id,
+      food_type: &record.category,
+      name: &record.name,
+      price: record.price.to_string(),
+      amount: record.amount,
+      total: record.total.to_string(),
+      // read back by the import, whatever date formats are configured
+      date: record.date.format(ISO_FORMAT).to_string(),
+      unit: record.unit.as_str(),
+      store: record
+        .shop
+        .as_ref()
+        .map(|shop| shop.name.as_str())
+        .unwrap_or_default()
+    }
+  }
+}
+
+/// Write every record matching `filter` into a CSV file at `path`,
+/// oldest purchases first. Returns how many records were written
+pub async fn export_csv(
+  model: &Model,
+  filter: &PurchaseFilter,
+  path: &Path
+) -> Result<usize, String> {
+  let records = model
+    .store()
+    .list(filter)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  let mut writer =
+    csv::Writer::from_path(path).map_err(|err| err.to_string())?;
+
+  for record in records.iter() {
+    writer
+      .serialize(Row::from(record))
+      .map_err(|err| err.to_string())?;
+  }
+
+  writer.flush().map_err(|err| err.to_string())?;
+
+  Ok(records.len())
+}
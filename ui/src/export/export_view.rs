@@ -0,0 +1,143 @@
+use std::{path::PathBuf, sync::Arc};
+
+use cursive::{
+  view::{Nameable, Resizable},
+  views::{Dialog, DummyView, EditView, LinearLayout, SelectView, TextView},
+  Cursive
+};
+
+use super::csv_export::export_csv;
+use crate::{buttons::can_leave_basket, model::Model, store::PurchaseFilter};
+
+/// A labeled `EditView` on a single line
+fn labeled_field(label: &str, name: &str, content: &str) -> LinearLayout {
+  LinearLayout::horizontal()
+    .child(TextView::new(format!("{:<10}", label)))
+    .child(
+      EditView::new()
+        .content(content)
+        .with_name(name)
+        .fixed_width(30)
+    )
+}
+
+/// Get the content of a `NamedView<EditView>`
+fn get_content(s: &mut Cursive, name: &str) -> String {
+  s.call_on_name(name, |edit_view: &mut EditView| {
+    String::clone(&edit_view.get_content())
+  })
+  .unwrap_or_default()
+}
+
+/// Open the CSV export in place of the currently open dialog.
+/// Dates are optional, leaving one empty means no bound on that side
+pub fn create_export(s: &mut Cursive, model: Arc<Model>) {
+  // the basket may be open below
+  if !can_leave_basket(s) {
+    return;
+  }
+
+  // Pop previous layer
+  s.pop_layer();
+
+  let export_model = Model::clone(&model);
+
+  s.add_layer(
+    Dialog::around(
+      LinearLayout::vertical()
+        .child(labeled_field("File", "export_path", "food.csv"))
+        .child(DummyView)
+        .child(labeled_field("From", "export_from", ""))
+        .child(labeled_field("To", "export_to", ""))
+        .child(
+          LinearLayout::horizontal()
+            .child(TextView::new(format!("{:<10}", "Category")))
+            .child(
+              SelectView::<Option<String>>::new()
+                .popup()
+                .item("All", None)
+                .with_name("export_category")
+            )
+        )
+    )
+    .title("Export CSV")
+    .button("Export", move |s| {
+      handle_export(s, Model::clone(&export_model))
+    })
+    .button("Back", |s| {
+      s.pop_layer();
+    })
+  );
+
+  load_categories(model);
+}
+
+/// Fill the category picker, archived categories included
+/// since older records may still use them
+fn load_categories(model: Arc<Model>) {
+  tokio::spawn(async move {
+    let categories = match model.store().list_categories(true).await {
+      Ok(categories) => categories,
+      Err(error) => {
+        let message = format!("Failed to load the categories:\n{}", error);
+
+        return model.use_cb_sink(move |s| {
+          s.add_layer(Dialog::info(message));
+        });
+      }
+    };
+
+    model.use_cb_sink(move |s| {
+      s.call_on_name(
+        "export_category",
+        |select_view: &mut SelectView<Option<String>>| {
+          for category in categories {
+            select_view.add_item(category.name.clone(), Some(category.name));
+          }
+        }
+      );
+    });
+  });
+}
+
+/// Read the filter from the dialog, then write the file in a tokio task
+fn handle_export(s: &mut Cursive, model: Arc<Model>) {
+  let path = get_content(s, "export_path");
+
+  if path.trim().is_empty() {
+    return s.add_layer(Dialog::info("File cannot be empty"));
+  }
+
+  let category = s
+    .call_on_name(
+      "export_category",
+      |select_view: &mut SelectView<Option<String>>| select_view.selection()
+    )
+    .flatten()
+    .and_then(|category| Option::clone(&category));
+
+  let filter = match PurchaseFilter::new(
+    &get_content(s, "export_from"),
+    &get_content(s, "export_to"),
+    category,
+    model.rules().date_parser()
+  ) {
+    Ok(filter) => filter,
+    Err(message) => return s.add_layer(Dialog::info(message))
+  };
+
+  let path = PathBuf::from(path.trim());
+
+  tokio::spawn(async move {
+    let message = match export_csv(&model, &filter, &path).await {
+      Ok(count) => {
+        format!("Exported {} records to {}", count, path.display())
+      }
+      Err(reason) => format!("Failed to export records:\n{}", reason)
+    };
+
+    model.use_cb_sink(move |s| {
+      s.add_layer(Dialog::info(message));
+    });
+  });
+}
@@ -0,0 +1,5 @@
+mod csv_export;
+mod export_view;
+
+pub use csv_export::export_csv;
+pub use export_view::create_export;
@@ -0,0 +1,179 @@
+use std::sync::{
+  atomic::{AtomicI64, Ordering},
+  Arc
+};
+
+use cursive::{
+  view::{Nameable, Resizable, Scrollable},
+  views::{Dialog, LinearLayout, SelectView, TextView},
+  Cursive
+};
+
+use super::record_actions::record_actions;
+use crate::{buttons::can_leave_basket, model::Model, store::Purchase};
+
+/// How many records are shown on a single page
+const PAGE_SIZE: i64 = 20;
+
+/// Format a committed record into a single line of the `SelectView`,
+/// its date in `date_format`
+pub fn format_record(record: &Purchase, date_format: &str) -> String {
+  let line = format!(
+    "{} | {} | {} | {} | {} | {} {} | {}",
+    record.date.format(date_format),
+    record.shop.as_ref().map_or("-", |shop| shop.name.as_str()),
+    record.category,
+    record.name,
+    record.price,
+    record.amount,
+    record.unit,
+    record.total
+  );
+
+  match record.unit_price() {
+    Some((price, unit)) => format!("{} | {}/{}", line, price, unit),
+    None => line
+  }
+}
+
+/// Open the history browser in place of the currently open dialog.
+/// The first page is loaded right away, the rest with `Previous` and `Next`
+pub fn create_history(s: &mut Cursive, model: Arc<Model>) {
+  // the basket may be open below
+  if !can_leave_basket(s) {
+    return;
+  }
+
+  // Pop previous layer
+  s.pop_layer();
+
+  // the page that is currently on screen, shared between the buttons
+  let page = Arc::new(AtomicI64::new(0));
+
+  let submit_model = Model::clone(&model);
+  let submit_page = Arc::clone(&page);
+  let previous_model = Model::clone(&model);
+  let previous_page = Arc::clone(&page);
+  let next_model = Model::clone(&model);
+  let next_page = Arc::clone(&page);
+
+  s.add_layer(
+    Dialog::around(
+      LinearLayout::vertical()
+        .child(TextView::new("Loading...").with_name("history_page"))
+        .child(
+          SelectView::<Purchase>::new()
+            .on_submit(move |s, record| {
+              record_actions(
+                s,
+                Model::clone(&submit_model),
+                Arc::clone(&submit_page),
+                record
+              )
+            })
+            .with_name("history_items")
+            .scrollable()
+            .full_screen()
+        )
+    )
+    .title("History")
+    .button("Previous", move |s| {
+      let current = previous_page.load(Ordering::SeqCst);
+
+      if current == 0 {
+        return s.add_layer(Dialog::info("Already on the first page"));
+      }
+
+      load_page(
+        Model::clone(&previous_model),
+        Arc::clone(&previous_page),
+        current - 1
+      );
+    })
+    .button("Next", move |s| {
+      let current = next_page.load(Ordering::SeqCst);
+
+      load_page(
+        Model::clone(&next_model),
+        Arc::clone(&next_page),
+        current + 1
+      );
+    })
+    .button("Back", |s| {
+      s.pop_layer();
+    })
+    .with_name("history_dialog")
+  );
+
+  load_page(model, page, 0);
+}
+
+/// Fetch the `target` page in a tokio task, then fill the `SelectView` with it.
+/// Asking for a page past the end is refused, unless it's the page on screen
+/// (e.g. its last record was deleted), in which case the last page is shown
+pub fn load_page(model: Arc<Model>, page: Arc<AtomicI64>, target: i64) {
+  tokio::spawn(async move {
+    let count = match model.store().count().await {
+      Ok(count) => count,
+      Err(error) => {
+        let message = format!("Failed to load records:\n{}", error);
+
+        return model.use_cb_sink(move |s| {
+          s.add_layer(Dialog::info(message));
+        });
+      }
+    };
+
+    // an empty table still has a single (empty) page
+    let page_count = ((count + PAGE_SIZE - 1) / PAGE_SIZE).max(1);
+
+    let target = if target < page_count {
+      target
+    } else if target <= page.load(Ordering::SeqCst) {
+      page_count - 1
+    } else {
+      return model.use_cb_sink(|s| {
+        s.add_layer(Dialog::info("No more records"));
+      });
+    };
+
+    let records = match model.store().list_page(target, PAGE_SIZE).await {
+      Ok(records) => records,
+      Err(error) => {
+        let message = format!("Failed to load records:\n{}", error);
+
+        return model.use_cb_sink(move |s| {
+          s.add_layer(Dialog::info(message));
+        });
+      }
+    };
+
+    page.store(target, Ordering::SeqCst);
+
+    let date_format = model.rules().date_parser().display_format().to_string();
+
+    model.use_cb_sink(move |s| {
+      s.call_on_name("history_page", |text_view: &mut TextView| {
+        text_view.set_content(format!(
+          "Page {} of {} ({} records)",
+          target + 1,
+          page_count,
+          count
+        ));
+      });
+
+      s.call_on_name(
+        "history_items",
+        |select_view: &mut SelectView<Purchase>| {
+          select_view.clear();
+
+          for record in records {
+            let line = format_record(&record, &date_format);
+
+            select_view.add_item(line, record);
+          }
+        }
+      );
+    });
+  });
+}
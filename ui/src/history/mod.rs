@@ -0,0 +1,5 @@
+mod history_view;
+mod receipt_view;
+mod record_actions;
+
+pub use history_view::create_history;
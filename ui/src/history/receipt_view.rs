@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use cursive::{
+  view::{Resizable, Scrollable},
+  views::{Dialog, TextView}
+};
+
+use super::history_view::format_record;
+use crate::{
+  model::Model,
+  money::Money,
+  store::{Purchase, Receipt}
+};
+
+/// The receipt on top, then every item bought on that trip and their sum.
+/// Dates are shown in `date_format`
+fn format_receipt(
+  receipt: &Receipt,
+  purchases: &[Purchase],
+  date_format: &str
+) -> String {
+  let mut text = format!(
+    "{} | {}",
+    receipt.date.format(date_format),
+    receipt.shop.as_ref().map_or("-", |shop| shop.name.as_str())
+  );
+
+  if let Some(total) = receipt.total {
+    text.push_str(&format!("\nReceipt total: {}", total));
+  }
+
+  if !receipt.notes.is_empty() {
+    text.push_str(&format!("\nNotes: {}", receipt.notes));
+  }
+
+  text.push('\n');
+
+  for purchase in purchases {
+    text.push_str(&format!("\n{}", format_record(purchase, date_format)));
+  }
+
+  let sum = purchases
+    .iter()
+    .map(|purchase| purchase.total)
+    .sum::<Money>();
+
+  text.push_str(&format!("\n\n{} items, {} in total", purchases.len(), sum));
+
+  text
+}
+
+/// Fetch the receipt with `id` in a tokio task,
+/// then show it together with its items on top of the history
+pub fn show_receipt(model: Arc<Model>, id: i32) {
+  tokio::spawn(async move {
+    let (receipt, purchases) = match model.store().receipt(id).await {
+      Ok(receipt) => receipt,
+      Err(error) => {
+        let message = format!("Failed to load the receipt:\n{}", error);
+
+        return model.use_cb_sink(move |s| {
+          s.add_layer(Dialog::info(message));
+        });
+      }
+    };
+
+    let date_format = model.rules().date_parser().display_format();
+    let text = format_receipt(&receipt, &purchases, date_format);
+
+    model.use_cb_sink(move |s| {
+      s.add_layer(
+        Dialog::around(TextView::new(text).scrollable().full_screen())
+          .title(format!("Receipt {}", receipt.id))
+          .button("Back", |s| {
+            s.pop_layer();
+          })
+      );
+    });
+  });
+}
@@ -0,0 +1,182 @@
+use std::sync::{
+  atomic::{AtomicI64, Ordering},
+  Arc
+};
+
+use cursive::{
+  views::{Dialog, TextView},
+  Cursive
+};
+
+use super::{history_view::load_page, receipt_view::show_receipt};
+use crate::{
+  buttons::{create_menu_item, fill_menu_item, read_menu_item},
+  item::{FoodItem, Item},
+  model::Model,
+  store::Purchase
+};
+
+/// Ask what should happen with a record selected in the history view.
+/// Records committed from a dialog can also show the rest of their trip
+pub fn record_actions(
+  s: &mut Cursive,
+  model: Arc<Model>,
+  page: Arc<AtomicI64>,
+  record: &Purchase
+) {
+  let edit_model = Model::clone(&model);
+  let edit_page = Arc::clone(&page);
+  let edit_data = record.clone();
+  let receipt_model = Model::clone(&model);
+  let id = record.id;
+
+  let mut dialog = Dialog::around(TextView::new(format!(
+    "What should happen with '{}'?",
+    record.name
+  )))
+  .title("Record")
+  .button("Edit", move |s| {
+    s.pop_layer();
+
+    edit_record(
+      s,
+      Model::clone(&edit_model),
+      Arc::clone(&edit_page),
+      &edit_data
+    );
+  })
+  .button("Delete", move |s| {
+    s.pop_layer();
+
+    delete_record(s, Model::clone(&model), Arc::clone(&page), id);
+  });
+
+  if let Some(receipt_id) = record.receipt_id {
+    dialog.add_button("Receipt", move |s| {
+      s.pop_layer();
+
+      show_receipt(Model::clone(&receipt_model), receipt_id);
+    });
+  }
+
+  dialog.add_button("Cancel", |s| {
+    s.pop_layer();
+  });
+
+  s.add_layer(dialog);
+}
+
+/// Open the same fields used for adding an item, loaded with the record.
+/// Saving recalculates the `total`, since price or amount may have changed
+fn edit_record(
+  s: &mut Cursive,
+  model: Arc<Model>,
+  page: Arc<AtomicI64>,
+  record: &Purchase
+) {
+  let id = record.id;
+  let category = record.category.clone();
+  let shop_id = record.shop.as_ref().map(|shop| shop.id);
+  // shown the way it's typed in, so saving reads it back
+  let date = record
+    .date
+    .format(model.rules().date_parser().display_format())
+    .to_string();
+
+  s.add_layer(
+    Dialog::around(create_menu_item())
+      .title(format!("Edit {}", record.name))
+      .button("Save", move |s| {
+        let item = match read_menu_item(s, model.rules()) {
+          Some(item) => item,
+          None => return
+        };
+
+        // `TryFrom<&Item>` calls `calc_total`
+        let mut food_item = match FoodItem::try_from(&item) {
+          Ok(food_item) => food_item,
+          Err(error) => return s.add_layer(Dialog::info(error.to_string()))
+        };
+
+        food_item.set_table_name(category.clone());
+        food_item.set_shop_id(shop_id);
+
+        s.pop_layer();
+
+        let model = Model::clone(&model);
+        let page = Arc::clone(&page);
+
+        tokio::spawn(async move {
+          match model.store().update(id, food_item).await {
+            Ok(_) => {
+              let current = page.load(Ordering::SeqCst);
+
+              load_page(model, page, current);
+            }
+            Err(error) => {
+              let message = format!("Failed to update record:\n{}", error);
+
+              model.use_cb_sink(move |s| {
+                s.add_layer(Dialog::info(message));
+              });
+            }
+          }
+        });
+      })
+      .button("Cancel", |s| {
+        s.pop_layer();
+      })
+  );
+
+  fill_menu_item(
+    s,
+    &Item::new(
+      record.name.clone(),
+      record.price.to_string(),
+      record.amount.to_string(),
+      record.unit.to_string(),
+      date
+    )
+  );
+}
+
+/// Delete a committed record after the user confirms it, the same way
+/// `callbacks::delete_item` does for the pending list
+fn delete_record(
+  s: &mut Cursive,
+  model: Arc<Model>,
+  page: Arc<AtomicI64>,
+  id: i32
+) {
+  s.add_layer(
+    // Popup window that asks for confirmation
+    Dialog::around(TextView::new("Delete record from the database?"))
+      .button("Confirm", move |s| {
+        // remove the window from the view upon action
+        s.pop_layer();
+
+        let model = Model::clone(&model);
+        let page = Arc::clone(&page);
+
+        tokio::spawn(async move {
+          match model.store().delete(id).await {
+            Ok(_) => {
+              let current = page.load(Ordering::SeqCst);
+
+              load_page(model, page, current);
+            }
+            Err(error) => {
+              let message = format!("Failed to delete record:\n{}", error);
+
+              model.use_cb_sink(move |s| {
+                s.add_layer(Dialog::info(message));
+              });
+            }
+          }
+        });
+      })
+      .button("Cancel", |s| {
+        s.pop_layer();
+      })
+  );
+}
@@ -0,0 +1,177 @@
+use std::{fmt::Display, path::Path};
+
+use crate::{
+  item::{FoodItem, Item, Unit},
+  validation::Rules
+};
+
+/// Header names accepted for every column. The first name of each is what
+/// the CSV export writes, so exported files can be imported again
+const COLUMNS: [&[&str]; 5] = [
+  &["name"],
+  &["price"],
+  &["amount"],
+  &["date", "purchase_date"],
+  &["food_type", "category"]
+];
+
+/// Files exported before units existed don't have this column,
+/// their rows (and empty cells) are counted in pieces
+const UNIT_COLUMN: &[&str] = &["unit"];
+
+/// A row that passed every check and can be committed
+#[derive(Debug)]
+pub struct ImportRow {
+  line: u64,
+  category: String,
+  item: Item,
+  food_item: FoodItem
+}
+
+impl Display for ImportRow {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "line {}: {} | {}", self.line, self.category, self.item)
+  }
+}
+
+impl ImportRow {
+  /// The row the way it was converted by `check_row`
+  pub fn to_food_item(&self) -> FoodItem {
+    self.food_item.clone()
+  }
+}
+
+/// A row that didn't pass the checks, with the reason why
+#[derive(Debug)]
+pub struct RejectedRow {
+  line: u64,
+  reason: String
+}
+
+impl Display for RejectedRow {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "line {}: {}", self.line, self.reason.replace('\n', " "))
+  }
+}
+
+/// Every row of the file, sorted into valid and rejected ones
+#[derive(Debug, Default)]
+pub struct ImportPreview {
+  pub valid: Vec<ImportRow>,
+  pub rejected: Vec<RejectedRow>
+}
+
+/// Find the column with one of the header `names`
+fn find_column(headers: &csv::StringRecord, names: &[&str]) -> Option<usize> {
+  headers.iter().position(|header| {
+    let header = header.trim().to_lowercase();
+
+    names.contains(&header.as_str())
+  })
+}
+
+/// Find which column of the file holds each of `COLUMNS`
+fn map_columns(headers: &csv::StringRecord) -> Result<[usize; 5], String> {
+  let mut columns = [0; 5];
+
+  for (column, names) in columns.iter_mut().zip(COLUMNS) {
+    *column = find_column(headers, names)
+      .ok_or_else(|| format!("The file has no `{}` column", names[0]))?;
+  }
+
+  Ok(columns)
+}
+
+/// Run a row through the same checks as the menu items,
+/// then make sure it fits the `Food` table
+fn check_row(
+  item: &mut Item,
+  category: &str,
+  categories: &[String],
+  rules: &Rules
+) -> Result<FoodItem, String> {
+  item.validate(rules).map_err(|err| err.to_string())?;
+  item
+    .resolve_purchase_date(rules.date_parser())
+    .map_err(|err| err.to_string())?;
+
+  if !categories.iter().any(|known| known == category) {
+    return Err(format!("Unknown category `{}`", category));
+  }
+
+  let mut food_item =
+    FoodItem::try_from(item).map_err(|err| err.to_string())?;
+
+  food_item.set_table_name(category);
+  food_item.check_columns().map_err(|err| err.to_string())?;
+
+  Ok(food_item)
+}
+
+/// Read the CSV at `path` and check every row of it.
+/// Only problems with the file itself are returned as an error,
+/// problems with single rows end up in `ImportPreview::rejected`
+pub fn read_csv(
+  path: &Path,
+  categories: &[String],
+  rules: &Rules
+) -> Result<ImportPreview, String> {
+  let mut reader =
+    csv::Reader::from_path(path).map_err(|err| err.to_string())?;
+
+  // exports have ISO dates, even if the user types them differently
+  let mut rules = rules.clone();
+
+  rules.set_date_parser(rules.date_parser().with_iso());
+
+  let headers = reader.headers().map_err(|err| err.to_string())?.clone();
+  let [name, price, amount, date, category] = map_columns(&headers)?;
+  let unit_column = find_column(&headers, UNIT_COLUMN);
+
+  let mut preview = ImportPreview::default();
+
+  for record in reader.records() {
+    let record = match record {
+      Ok(record) => record,
+      Err(err) => {
+        let line = err
+          .position()
+          .map(|position| position.line())
+          .unwrap_or_default();
+
+        preview.rejected.push(RejectedRow {
+          line,
+          reason: err.to_string()
+        });
+        continue;
+      }
+    };
+
+    let line = record
+      .position()
+      .map(|position| position.line())
+      .unwrap_or_default();
+    let field = |idx: usize| record.get(idx).unwrap_or_default().trim();
+
+    let unit = match unit_column.map(field) {
+      Some(unit) if !unit.is_empty() => unit,
+      _ => Unit::default().as_str()
+    };
+
+    let mut item =
+      Item::new(field(name), field(price), field(amount), unit, field(date));
+    let category = field(category).to_string();
+
+    match check_row(&mut item, &category, categories, &rules) {
+      Ok(food_item) => preview.valid.push(ImportRow {
+        line,
+        category,
+        item,
+        food_item
+      }),
+      Err(reason) => preview.rejected.push(RejectedRow { line, reason })
+    }
+  }
+
+  Ok(preview)
+}
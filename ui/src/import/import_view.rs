@@ -0,0 +1,219 @@
+use std::{
+  path::PathBuf,
+  sync::{Arc, Mutex}
+};
+
+use cursive::{
+  view::{Nameable, Resizable, Scrollable},
+  views::{Dialog, DummyView, EditView, LinearLayout, TextView},
+  Cursive
+};
+
+use super::csv_import::{read_csv, ImportPreview, ImportRow};
+use crate::{buttons::can_leave_basket, model::Model};
+
+/// Describe which rows will be imported and which ones are refused
+fn render_preview(preview: &ImportPreview) -> String {
+  let mut text = format!(
+    "{} valid, {} rejected\n",
+    preview.valid.len(),
+    preview.rejected.len()
+  );
+
+  if !preview.valid.is_empty() {
+    text.push_str("\nValid:\n");
+
+    for row in preview.valid.iter() {
+      text.push_str(&format!("  {}\n", row));
+    }
+  }
+
+  if !preview.rejected.is_empty() {
+    text.push_str("\nRejected:\n");
+
+    for row in preview.rejected.iter() {
+      text.push_str(&format!("  {}\n", row));
+    }
+  }
+
+  text
+}
+
+/// Open the CSV import in place of the currently open dialog.
+/// A file has to be previewed before its valid rows can be imported
+pub fn create_import(s: &mut Cursive, model: Arc<Model>) {
+  // the basket may be open below
+  if !can_leave_basket(s) {
+    return;
+  }
+
+  // Pop previous layer
+  s.pop_layer();
+
+  // the valid rows of the last preview, shared between the buttons
+  let rows = Arc::new(Mutex::new(Vec::<ImportRow>::new()));
+
+  let preview_model = Model::clone(&model);
+  let preview_rows = Arc::clone(&rows);
+
+  s.add_layer(
+    Dialog::around(
+      LinearLayout::vertical()
+        .child(
+          LinearLayout::horizontal()
+            .child(TextView::new("File: "))
+            .child(EditView::new().with_name("import_path").fixed_width(30))
+        )
+        .child(DummyView)
+        .child(
+          TextView::new("Pick a file and press Preview")
+            .with_name("import_preview")
+            .scrollable()
+            .full_screen()
+        )
+    )
+    .title("Import CSV")
+    .button("Preview", move |s| {
+      preview_import(s, Model::clone(&preview_model), Arc::clone(&preview_rows))
+    })
+    .button("Import", move |s| {
+      handle_import(s, Model::clone(&model), Arc::clone(&rows))
+    })
+    .button("Back", |s| {
+      s.pop_layer();
+    })
+  );
+}
+
+/// Read and check the file in a tokio task, then show the result.
+/// The valid rows are kept around for the `Import` button
+fn preview_import(
+  s: &mut Cursive,
+  model: Arc<Model>,
+  rows: Arc<Mutex<Vec<ImportRow>>>
+) {
+  let path = s
+    .call_on_name("import_path", |edit_view: &mut EditView| {
+      edit_view.get_content().trim().to_string()
+    })
+    .unwrap_or_default();
+
+  if path.is_empty() {
+    return s.add_layer(Dialog::info("File cannot be empty"));
+  }
+
+  let path = PathBuf::from(path);
+
+  tokio::spawn(async move {
+    // rows may only use categories that exist, archived ones included
+    let categories = match model.store().list_categories(true).await {
+      Ok(categories) => categories
+        .into_iter()
+        .map(|category| category.name)
+        .collect::<Vec<_>>(),
+      Err(error) => {
+        let message = format!("Failed to load the categories:\n{}", error);
+
+        return model.use_cb_sink(move |s| {
+          s.add_layer(Dialog::info(message));
+        });
+      }
+    };
+
+    let preview = match read_csv(&path, &categories, model.rules()) {
+      Ok(preview) => preview,
+      Err(reason) => {
+        return model.use_cb_sink(move |s| {
+          s.add_layer(Dialog::info(format!(
+            "Failed to read {}:\n{}",
+            path.display(),
+            reason
+          )));
+        });
+      }
+    };
+
+    let text = render_preview(&preview);
+
+    *rows.lock().unwrap() = preview.valid;
+
+    model.use_cb_sink(move |s| {
+      s.call_on_name("import_preview", |text_view: &mut TextView| {
+        text_view.set_content(text);
+      });
+    });
+  });
+}
+
+/// Commit the valid rows of the last preview in a single transaction,
+/// the same way the pending list of a `Food` dialog is committed
+fn handle_import(
+  s: &mut Cursive,
+  model: Arc<Model>,
+  rows: Arc<Mutex<Vec<ImportRow>>>
+) {
+  let count = rows.lock().unwrap().len();
+
+  if count == 0 {
+    return s.add_layer(Dialog::info(
+      "Nothing to import. Preview a file with valid rows first."
+    ));
+  }
+
+  s.add_layer(
+    Dialog::around(TextView::new(format!("Import {} rows?", count)))
+      .button("Import", move |s| {
+        s.pop_layer();
+
+        // taken out right away, so pressing Import again while this
+        // runs can't import the same rows twice
+        let taken = std::mem::take(&mut *rows.lock().unwrap());
+
+        if taken.is_empty() {
+          return s.add_layer(Dialog::info("Already importing these rows"));
+        }
+
+        let food_items = taken
+          .iter()
+          .map(ImportRow::to_food_item)
+          .collect::<Vec<_>>();
+
+        let model = Model::clone(&model);
+        let rows = Arc::clone(&rows);
+
+        tokio::spawn(async move {
+          let (imported, message) = match model.store().create(food_items).await
+          {
+            Ok(records) => {
+              (true, format!("Imported {} records", records.len()))
+            }
+            Err(error) => {
+              // nothing was saved, so they can be imported again
+              *rows.lock().unwrap() = taken;
+
+              (
+                false,
+                format!(
+                  "Error occured when setting records, nothing was saved:\n{}",
+                  error
+                )
+              )
+            }
+          };
+
+          model.use_cb_sink(move |s| {
+            if imported {
+              s.call_on_name("import_preview", |text_view: &mut TextView| {
+                text_view.set_content("Pick a file and press Preview");
+              });
+            }
+
+            s.add_layer(Dialog::info(message));
+          });
+        });
+      })
+      .button("Cancel", |s| {
+        s.pop_layer();
+      })
+  );
+}
@@ -0,0 +1,4 @@
+mod csv_import;
+mod import_view;
+
+pub use import_view::create_import;
@@ -1,38 +1,73 @@
 use std::borrow::Cow;
 
-use prisma_client_rust::chrono::{DateTime, FixedOffset};
+use prisma_client_rust::chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
-use super::Item;
+use super::{Field, Item, ItemError, Unit};
+use crate::{date::ISO_FORMAT, money::Money};
+
+/// Longest text the `VarChar(100)` columns of the database can hold
+pub const MAX_TEXT_LENGTH: usize = 100;
 
 /// Holds the same data as `Item`, just parsed into the correct data format
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FoodItem {
   table_name: String,
   name: String,
-  price: f64,
+  price: Money,
   amount: f64,
-  total: f64,
-  purchase_date: DateTime<FixedOffset>
+  unit: Unit,
+  total: Money,
+  purchase_date: NaiveDate,
+  shop_id: Option<i32>
+}
+
+/// Parse the text of a number field
+fn parse_number(field: Field, value: &str) -> Result<f64, ItemError> {
+  value.parse::<f64>().map_err(|err| ItemError::Parse {
+    field,
+    value: value.to_string(),
+    reason: err.to_string()
+  })
 }
 
 // Convert an `&Item` into a `FoodItem`
-impl From<&Item> for FoodItem {
-  fn from(value: &Item) -> Self {
+impl TryFrom<&Item> for FoodItem {
+  type Error = ItemError;
+
+  fn try_from(value: &Item) -> Result<Self, Self::Error> {
     let mut food_item = Self::new();
 
-    let (name, price, amount, mut purchase_date) = value.to_owned().to_parts();
+    let (name, price, amount, unit, purchase_date) =
+      value.to_owned().to_parts();
+
+    // the date was resolved by `Item::resolve_purchase_date` before
+    let parsed_date = NaiveDate::parse_from_str(&purchase_date, ISO_FORMAT)
+      .map_err(|err| ItemError::Parse {
+        field: Field::PurchaseDate,
+        value: purchase_date.clone(),
+        reason: err.to_string()
+      })?;
 
-    purchase_date.push_str(" 00:00:00 +0000");
+    let parsed_price =
+      Money::parse(&price).map_err(|reason| ItemError::Parse {
+        field: Field::Price,
+        value: price.clone(),
+        reason
+      })?;
 
+    food_item.set_price(parsed_price);
+    food_item.set_amount(parse_number(Field::Amount, &amount)?);
+    food_item.set_unit(unit.parse().map_err(|reason| ItemError::Parse {
+      field: Field::Unit,
+      value: unit.clone(),
+      reason
+    })?);
     food_item.set_name(name);
-    food_item.set_price(price.parse::<f64>().unwrap());
-    food_item.set_amount(amount.parse::<f64>().unwrap());
-    food_item.set_purchase_date(
-      DateTime::parse_from_str(&purchase_date, "%Y/%m/%d %H:%M:%S %z").unwrap()
-    );
+    food_item.set_purchase_date(parsed_date);
     food_item.calc_total();
 
-    food_item
+    Ok(food_item)
   }
 }
 
@@ -41,22 +76,25 @@ impl FoodItem {
     Self {
       table_name: String::default(),
       name: String::default(),
-      price: f64::default(),
+      price: Money::default(),
       amount: f64::default(),
-      total: f64::default(),
-      purchase_date: DateTime::default()
+      unit: Unit::default(),
+      total: Money::default(),
+      purchase_date: NaiveDate::default(),
+      shop_id: None
     }
   }
 
   /// Consume Self and return the building blocks
   pub fn to_parts(
     self
-  ) -> (String, String, f64, f64, f64, DateTime<FixedOffset>) {
+  ) -> (String, String, Money, f64, Unit, Money, NaiveDate) {
     (
       self.table_name,
       self.name,
       self.price,
       self.amount,
+      self.unit,
       self.total,
       self.purchase_date
     )
@@ -71,6 +109,11 @@ impl FoodItem {
     self
   }
 
+  /// The category the item gets committed under
+  pub fn table_name(&self) -> &str {
+    &self.table_name
+  }
+
   /// Set the product name
   pub fn set_name<'a, T>(&mut self, name: T) -> &mut Self
   where
@@ -80,8 +123,12 @@ impl FoodItem {
     self
   }
 
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
   /// Set the product price
-  pub fn set_price(&mut self, price: f64) -> &mut Self {
+  pub fn set_price(&mut self, price: Money) -> &mut Self {
     self.price = price;
     self
   }
@@ -92,18 +139,122 @@ impl FoodItem {
     self
   }
 
-  /// Set total, which is `price * amount`
+  /// Set the unit the amount is counted in
+  pub fn set_unit(&mut self, unit: Unit) -> &mut Self {
+    self.unit = unit;
+    self
+  }
+
+  /// Set total, which is `price * amount` rounded to whole cents
   pub fn calc_total(&mut self) -> &mut Self {
-    self.total = self.price * self.amount;
+    self.total = self.price.times(self.amount);
     self
   }
 
+  /// What the item cost in total, once `calc_total` was called
+  pub fn total(&self) -> Money {
+    self.total
+  }
+
   /// Set the purchase date
-  pub fn set_purchase_date(
-    &mut self,
-    purchase_date: DateTime<FixedOffset>
-  ) -> &mut Self {
+  pub fn set_purchase_date(&mut self, purchase_date: NaiveDate) -> &mut Self {
     self.purchase_date = purchase_date;
     self
   }
+
+  /// The day the item was bought on
+  pub fn purchase_date(&self) -> NaiveDate {
+    self.purchase_date
+  }
+
+  /// Set the store the whole basket was bought in, `None` if it's not known
+  pub fn set_shop_id(&mut self, shop_id: Option<i32>) -> &mut Self {
+    self.shop_id = shop_id;
+    self
+  }
+
+  /// The id of the `Store` row the item was bought in
+  pub fn shop_id(&self) -> Option<i32> {
+    self.shop_id
+  }
+
+  /// Check the values against the columns of the `Food` table, so a record
+  /// the database would reject can be reported before anything is sent
+  pub fn check_columns(&self) -> Result<(), ItemError> {
+    for (field, text) in [
+      (Field::Category, &self.table_name),
+      (Field::Name, &self.name)
+    ] {
+      if text.chars().count() > MAX_TEXT_LENGTH {
+        return Err(ItemError::Column {
+          field,
+          value: text.clone(),
+          reason: format!("is longer than {} characters", MAX_TEXT_LENGTH)
+        });
+      }
+    }
+
+    // `REAL` is single precision, so anything out of `f32` range is rejected
+    if !(self.amount as f32).is_finite() {
+      return Err(ItemError::Column {
+        field: Field::Amount,
+        value: self.amount.to_string(),
+        reason: String::from("is not a number the database can store")
+      });
+    }
+
+    // cents are kept in an `INTEGER` column
+    for (field, value) in
+      [(Field::Price, self.price), (Field::Total, self.total)]
+    {
+      if i32::try_from(value.cents()).is_err() {
+        return Err(ItemError::Column {
+          field,
+          value: value.to_string(),
+          reason: String::from("is more than the database can store")
+        });
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use prisma_client_rust::chrono::NaiveDate;
+
+  use super::FoodItem;
+  use crate::{
+    item::{Field, Item, Unit},
+    money::Money
+  };
+
+  #[test]
+  fn parses_an_item_and_works_out_the_total() {
+    let cheese = Item::new("Cheese", "12.99", "0.5", "kg", "2022-10-23");
+    let food_item = FoodItem::try_from(&cheese).unwrap();
+
+    assert_eq!(food_item.total(), Money::from_cents(650));
+    assert_eq!(food_item.purchase_date(), NaiveDate::from_ymd(2022, 10, 23));
+    assert_eq!(food_item.to_parts().4, Unit::Kilograms);
+  }
+
+  #[test]
+  fn names_the_field_that_doesnt_parse() {
+    let cheese = Item::new("Cheese", "12.99", "lots", "kg", "2022-10-23");
+
+    assert_eq!(
+      FoodItem::try_from(&cheese).unwrap_err().field(),
+      Field::Amount
+    );
+  }
+
+  #[test]
+  fn refuses_values_the_columns_cant_hold() {
+    let cheese = Item::new("Cheese", "99999999", "1", "kg", "2022-10-23");
+    let food_item = FoodItem::try_from(&cheese).unwrap();
+
+    assert_eq!(food_item.check_columns().unwrap_err().field(), Field::Price);
+  }
 }
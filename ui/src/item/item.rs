@@ -1,42 +1,126 @@
-use std::{borrow::Cow, fmt::Display, num::ParseFloatError};
+use std::{
+  borrow::Cow,
+  fmt::Display,
+  num::ParseFloatError,
+  sync::atomic::{AtomicU64, Ordering}
+};
 
-use prisma_client_rust::chrono::{NaiveDate, ParseError as ParseDateError};
+use prisma_client_rust::chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{Field, ItemError, Unit};
+use crate::{
+  date::{DateParser, ISO_FORMAT},
+  money::Money,
+  validation::{Rules, Violations}
+};
+
+/// Hands out the ids of the items, unique for as long as the app runs
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+fn next_id() -> u64 {
+  NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
 
 /// Items to hold the elements of the input boxes
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Item {
+  // tells the pending items apart, no matter where they moved in the list
+  #[serde(skip, default = "next_id")]
+  id: u64,
+  // the `Food` menu category it gets committed under, empty until it's picked
+  category: String,
   name: String,
   price: String,
   amount: String,
-  purchase_date: String
+  unit: String,
+  purchase_date: String,
+  // why the database (or the checks before it) refused this item on commit
+  #[serde(skip)]
+  rejection: Option<String>,
+  // loaded into the form, the next `Add` replaces it
+  #[serde(skip)]
+  editing: bool,
+  // sent off with a commit the database hasn't answered yet
+  #[serde(skip)]
+  committing: bool
 }
 
 impl Display for Item {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if !self.category.is_empty() {
+      write!(f, "{} | ", self.category)?;
+    }
+
     write!(
       f,
-      "{} | {} | {} | {}",
-      self.name, self.price, self.amount, self.purchase_date
-    )
+      "{} | {} | {} {} | {}",
+      self.name, self.price, self.amount, self.unit, self.purchase_date
+    )?;
+
+    if let Some((price, unit)) = self.unit_price() {
+      write!(f, " | {}/{}", price, unit)?;
+    }
+
+    if self.editing {
+      write!(f, " | editing")?;
+    }
+
+    if self.committing {
+      write!(f, " | committing")?;
+    }
+
+    match &self.rejection {
+      Some(rejection) => write!(f, " | rejected: {}", rejection),
+      None => Ok(())
+    }
   }
 }
 
 impl Item {
   /// Construct a new `Item` with the given elements
-  pub fn new<'a, T>(name: T, price: T, amount: T, purchase_date: T) -> Self
+  pub fn new<'a, T>(
+    name: T,
+    price: T,
+    amount: T,
+    unit: T,
+    purchase_date: T
+  ) -> Self
   where
     // Convert whatever we get into a `Cow` then convert that into a `String`
     T: Into<Cow<'a, str>>
   {
     Self {
+      id: next_id(),
+      category: String::new(),
       name: name.into().into_owned(),
       price: price.into().into_owned(),
       amount: amount.into().into_owned(),
-      purchase_date: purchase_date.into().into_owned()
+      unit: unit.into().into_owned(),
+      purchase_date: purchase_date.into().into_owned(),
+      rejection: None,
+      editing: false,
+      committing: false
+    }
+  }
+
+  /// A copy of the item that lives on its own, with a new id
+  /// and without the marks of the original
+  pub fn duplicate(&self) -> Self {
+    Self {
+      id: next_id(),
+      rejection: None,
+      editing: false,
+      committing: false,
+      ..self.clone()
     }
   }
 
-  /// Return every field in a `Vec`
+  pub fn id(&self) -> u64 {
+    self.id
+  }
+
+  /// Return every field typed into an `EditView` in a `Vec`, so not the unit
   pub fn as_vec(&self) -> Vec<&str> {
     vec![
       self.name.as_str(),
@@ -47,17 +131,90 @@ impl Item {
   }
 
   /// Parse the value of `Price`
-  pub fn validate_price(&self) -> Result<f64, ParseFloatError> {
-    self.price.parse::<f64>()
+  pub fn validate_price(&self) -> Result<Money, String> {
+    Money::parse(&self.price)
+  }
+  /// Parse the value of `Unit`
+  pub fn validate_unit(&self) -> Result<Unit, String> {
+    self.unit.parse::<Unit>()
+  }
+
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// The price as it was typed
+  pub fn price(&self) -> &str {
+    &self.price
+  }
+
+  /// The amount as it was typed
+  pub fn amount(&self) -> &str {
+    &self.amount
+  }
+
+  /// The unit as it was picked or typed
+  pub fn unit(&self) -> &str {
+    &self.unit
+  }
+
+  /// The purchase date as it was typed, in ISO format once it was resolved
+  pub fn purchase_date(&self) -> &str {
+    &self.purchase_date
   }
+
+  /// `price * amount` rounded to whole cents, like `FoodItem::calc_total`,
+  /// if both of them parse
+  pub fn total(&self) -> Option<Money> {
+    let price = self.validate_price().ok()?;
+    let amount = self.validate_amount().ok()?;
+
+    Some(price.times(amount))
+  }
+
+  /// Price per kg, l, piece or pack, if every field needed for it parses
+  pub fn unit_price(&self) -> Option<(Money, Unit)> {
+    let total = self.total()?;
+    let amount = self.validate_amount().ok()?;
+
+    self.validate_unit().ok()?.unit_price(total, amount)
+  }
+
   /// Parse the value of `Amount`
   pub fn validate_amount(&self) -> Result<f64, ParseFloatError> {
     self.amount.parse::<f64>()
   }
 
-  /// Parse the value of `Purchase Date`
-  pub fn validate_purchase_date(&self) -> Result<NaiveDate, ParseDateError> {
-    NaiveDate::parse_from_str(&self.purchase_date, "%Y/%m/%d")
+  /// Parse the value of `Purchase Date` with any of the formats of `parser`
+  pub fn validate_purchase_date(
+    &self,
+    parser: &DateParser
+  ) -> Result<NaiveDate, String> {
+    parser.parse(&self.purchase_date)
+  }
+
+  /// Replace the typed `Purchase Date` with the date it stands for,
+  /// so `today` stays the same day once the item sits in the list
+  pub fn resolve_purchase_date(
+    &mut self,
+    parser: &DateParser
+  ) -> Result<&mut Self, ItemError> {
+    let date = self.validate_purchase_date(parser).map_err(|reason| {
+      ItemError::Parse {
+        field: Field::PurchaseDate,
+        value: self.purchase_date.clone(),
+        reason
+      }
+    })?;
+
+    self.purchase_date = date.format(ISO_FORMAT).to_string();
+    Ok(self)
+  }
+
+  /// Run every check of `rules` on the fields.
+  /// The error tells which fields are wrong, all of them at once
+  pub fn validate(&self, rules: &Rules) -> Result<(), Violations> {
+    rules.check(self)
   }
 
   /// Joins the structs elements together with the given separator.
@@ -71,8 +228,58 @@ impl Item {
     self.as_vec().iter().all(|element| element.is_empty())
   }
 
+  /// Set the category the item gets committed under
+  pub fn set_category<'a, T>(&mut self, category: T) -> &mut Self
+  where
+    T: Into<Cow<'a, str>>
+  {
+    self.category = category.into().into_owned();
+    self
+  }
+
+  pub fn category(&self) -> &str {
+    &self.category
+  }
+
+  /// Mark the item as refused on commit, or clear the mark with `None`
+  pub fn set_rejection(&mut self, rejection: Option<String>) -> &mut Self {
+    self.rejection = rejection;
+    self
+  }
+
+  pub fn rejection(&self) -> Option<&str> {
+    self.rejection.as_deref()
+  }
+
+  /// Mark the item as loaded into the form for editing
+  pub fn set_editing(&mut self, editing: bool) -> &mut Self {
+    self.editing = editing;
+    self
+  }
+
+  pub fn is_editing(&self) -> bool {
+    self.editing
+  }
+
+  /// Mark the item as sent off with a commit, or clear the mark once
+  /// the database answered
+  pub fn set_committing(&mut self, committing: bool) -> &mut Self {
+    self.committing = committing;
+    self
+  }
+
+  pub fn is_committing(&self) -> bool {
+    self.committing
+  }
+
   /// Deconstruct an `Item` instance into it's parts
-  pub fn to_parts(self) -> (String, String, String, String) {
-    (self.name, self.price, self.amount, self.purchase_date)
+  pub fn to_parts(self) -> (String, String, String, String, String) {
+    (
+      self.name,
+      self.price,
+      self.amount,
+      self.unit,
+      self.purchase_date
+    )
   }
 }
@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+/// The fields of an `Item`, plus the ones derived from it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+  Category,
+  Store,
+  Name,
+  Price,
+  Amount,
+  Unit,
+  Total,
+  PurchaseDate
+}
+
+impl Display for Field {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let label = match self {
+      Self::Category => "Category",
+      Self::Store => "Store",
+      Self::Name => "Name",
+      Self::Price => "Price",
+      Self::Amount => "Amount",
+      Self::Unit => "Unit",
+      Self::Total => "Total",
+      Self::PurchaseDate => "Purchase Date"
+    };
+
+    write!(f, "{}", label)
+  }
+}
+
+/// Why an `Item` can't become a `FoodItem`, or a `FoodItem` can't be stored
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItemError {
+  /// The text of the field doesn't parse into the type it needs
+  Parse {
+    field: Field,
+    value: String,
+    reason: String
+  },
+  /// The value parsed, but the `Food` table can't hold it
+  Column {
+    field: Field,
+    value: String,
+    reason: String
+  },
+  /// The value breaks one of the validation `Rules`
+  Rule {
+    field: Field,
+    value: String,
+    reason: String
+  }
+}
+
+impl ItemError {
+  /// The field that caused the error
+  pub fn field(&self) -> Field {
+    match self {
+      Self::Parse { field, .. }
+      | Self::Column { field, .. }
+      | Self::Rule { field, .. } => *field
+    }
+  }
+}
+
+impl Display for ItemError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Parse {
+        field,
+        value,
+        reason
+      } => write!(
+        f,
+        "{} '{}' does not have a valid format: {}",
+        field, value, reason
+      ),
+      Self::Column {
+        field,
+        value,
+        reason
+      }
+      | Self::Rule {
+        field,
+        value,
+        reason
+      } => write!(f, "{} '{}' {}", field, value, reason)
+    }
+  }
+}
+
+impl std::error::Error for ItemError {}
@@ -1,4 +1,11 @@
 mod food_item;
 mod item;
+mod item_error;
+mod unit;
 
-pub use {food_item::FoodItem, item::Item};
+pub use {
+  food_item::{FoodItem, MAX_TEXT_LENGTH},
+  item::Item,
+  item_error::{Field, ItemError},
+  unit::Unit
+};
@@ -0,0 +1,108 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::money::Money;
+
+/// What the `amount` of a purchase is counted in.
+/// Serialized by its short name, the same as in the database
+#[derive(
+  Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+#[serde(into = "&'static str", try_from = "String")]
+pub enum Unit {
+  #[default]
+  Pieces,
+  Grams,
+  Kilograms,
+  Millilitres,
+  Litres,
+  Packs
+}
+
+impl Unit {
+  /// Every unit, in the order of the unit picker
+  pub const ALL: [Unit; 6] = [
+    Self::Pieces,
+    Self::Grams,
+    Self::Kilograms,
+    Self::Millilitres,
+    Self::Litres,
+    Self::Packs
+  ];
+
+  /// Short name, the way it's typed and stored
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Self::Pieces => "pcs",
+      Self::Grams => "g",
+      Self::Kilograms => "kg",
+      Self::Millilitres => "ml",
+      Self::Litres => "l",
+      Self::Packs => "pack"
+    }
+  }
+
+  /// The unit prices are compared in, with how many of those one `self` is.
+  /// Grams become kilograms, millilitres become litres
+  pub fn base(self) -> (Unit, f64) {
+    match self {
+      Self::Grams => (Self::Kilograms, 0.001),
+      Self::Millilitres => (Self::Litres, 0.001),
+      unit => (unit, 1.0)
+    }
+  }
+
+  /// Convert `amount` of `self` into `to`.
+  /// `None` when the two don't measure the same thing, like `g` and `l`
+  pub fn convert(self, amount: f64, to: Unit) -> Option<f64> {
+    let (base, factor) = self.base();
+    let (to_base, to_factor) = to.base();
+
+    (base == to_base).then(|| amount * factor / to_factor)
+  }
+
+  /// What one kg, l, piece or pack cost when `amount` of `self` cost `total`
+  pub fn unit_price(self, total: Money, amount: f64) -> Option<(Money, Unit)> {
+    let (base, factor) = self.base();
+
+    total.divided_by(amount * factor).map(|price| (price, base))
+  }
+}
+
+impl Display for Unit {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.pad(self.as_str())
+  }
+}
+
+impl From<Unit> for &'static str {
+  fn from(unit: Unit) -> Self {
+    unit.as_str()
+  }
+}
+
+impl TryFrom<String> for Unit {
+  type Error = String;
+
+  fn try_from(value: String) -> Result<Self, Self::Error> {
+    value.parse()
+  }
+}
+
+impl FromStr for Unit {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let s = s.trim().to_lowercase();
+
+    Self::ALL
+      .into_iter()
+      .find(|unit| unit.as_str() == s)
+      .ok_or_else(|| {
+        let names = Self::ALL.map(Unit::as_str);
+
+        format!("expected one of {}", names.join(", "))
+      })
+  }
+}
@@ -3,20 +3,50 @@
 
 mod buttons;
 mod callbacks;
+mod categories;
+mod cli;
+mod config;
+mod date;
+mod draft;
+mod export;
+mod history;
+mod import;
 mod item;
 mod model;
+mod money;
+#[cfg(not(feature = "sqlite"))]
 mod prisma;
+#[cfg(feature = "sqlite")]
+#[path = "prisma_sqlite.rs"]
+mod prisma;
+mod queue;
+mod report;
+mod store;
+mod validation;
 
 use buttons::create_leaf;
-use cursive::{
-  menu::Tree,
-  views::{Dialog, TextView},
-  Cursive
-};
+use categories::{create_categories, reload_food_menu};
+use config::{connect_and_start, create_database_settings, Settings};
+use cursive::{menu::Tree, views::Dialog, Cursive};
+use draft::draft_category;
+use export::create_export;
+use history::create_history;
+use import::create_import;
 use model::Model;
+use prisma::PrismaClient;
+use queue::{create_queue_view, start_sync, CommitQueue};
+use report::{create_monthly_report, create_store_report};
+use store::PrismaStore;
 
 #[tokio::main]
 async fn main() {
+  // arguments run a single command instead of opening the TUI
+  let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+  if !args.is_empty() {
+    return cli::run_command(args).await;
+  }
+
   // instantiate a Cursive instance
   let mut siv = cursive::default();
 
@@ -38,66 +68,76 @@ async fn main() {
     s.select_menubar();
   });
 
-  // Failing to instantiate a client should result in a quit (for now)
-  let client = match prisma::new_client().await {
-    Ok(success) => success,
-    Err(error) => {
-      siv.add_layer(
-        Dialog::around(TextView::new("Failed to initialize prisma. Quitting"))
-          .button("Quit", Cursive::quit)
-      );
-
-      return siv.run();
-    }
-  };
-
-  let model = Model::new(&siv, client);
-
-  // separate, cloned `Model` for every `leaf`
-  let meat_model = Model::clone(&model);
-  let vegetable_model = Model::clone(&model);
-  let fruit_model = Model::clone(&model);
-  let side_model = Model::clone(&model);
-  let pasta_model = Model::clone(&model);
-  let bread_model = Model::clone(&model);
-  let ingredient_model = Model::clone(&model);
-
-  // top menu
-  siv.menubar().add_subtree(
-    "Food",
+  // the rest is built once the database answers
+  connect_and_start(&mut siv, start);
+
+  siv.run();
+}
+
+/// Build the app around a connected client
+fn start(s: &mut Cursive, client: PrismaClient, settings: Settings) {
+  // baskets wait in here while the database can't be reached
+  let queue = CommitQueue::default_path().map(CommitQueue::open);
+  let rules = settings.rules.rules();
+  let model = Model::new(s, PrismaStore::new(client), rules, queue);
+
+  start_sync(Model::clone(&model));
+
+  let category_model = Model::clone(&model);
+  let history_model = Model::clone(&model);
+  let export_model = Model::clone(&model);
+  let import_model = Model::clone(&model);
+  let queue_model = Model::clone(&model);
+  let report_model = Model::clone(&model);
+  let store_report_model = Model::clone(&model);
+
+  // top menu, `Food` is filled from the categories stored in the database
+  s.menubar().add_subtree("Food", Tree::new());
+  reload_food_menu(Model::clone(&model));
+
+  s.menubar().add_subtree(
+    "Categories",
+    Tree::new().leaf("Manage", move |s| {
+      create_categories(s, Model::clone(&category_model))
+    })
+  );
+
+  s.menubar().add_subtree(
+    "History",
     Tree::new()
-      .leaf("Meat", move |s| {
-        create_leaf(s, Model::clone(&meat_model), "meat_dialog", "Meat")
+      .leaf("Browse", move |s| {
+        create_history(s, Model::clone(&history_model))
       })
-      .leaf("Vegetable", move |s| {
-        create_leaf(
-          s,
-          Model::clone(&vegetable_model),
-          "vegetable_dialog",
-          "Vegetable"
-        )
+      .leaf("Export CSV", move |s| {
+        create_export(s, Model::clone(&export_model))
       })
-      .leaf("Fruit", move |s| {
-        create_leaf(s, Model::clone(&fruit_model), "fruit_dialog", "Fruit")
+      .leaf("Import CSV", move |s| {
+        create_import(s, Model::clone(&import_model))
       })
-      .leaf("Side", move |s| {
-        create_leaf(s, Model::clone(&side_model), "side_dialog", "Side")
+      .leaf("Queued commits", move |s| {
+        create_queue_view(s, Model::clone(&queue_model))
       })
-      .leaf("Bread", move |s| {
-        create_leaf(s, Model::clone(&bread_model), "bread_dialog", "Bread")
-      })
-      .leaf("Pasta", move |s| {
-        create_leaf(s, Model::clone(&pasta_model), "pasta_dialog", "Pasta");
+  );
+
+  s.menubar().add_subtree(
+    "Reports",
+    Tree::new()
+      .leaf("Monthly spending", move |s| {
+        create_monthly_report(s, Model::clone(&report_model))
       })
-      .leaf("Ingredients", move |s| {
-        create_leaf(
-          s,
-          Model::clone(&ingredient_model),
-          "ingredient_dialog",
-          "Ingredient"
-        )
+      .leaf("Spending by store", move |s| {
+        create_store_report(s, Model::clone(&store_report_model))
       })
   );
 
-  siv.run();
+  s.menubar().add_subtree(
+    "Settings",
+    Tree::new().leaf("Database", |s| create_database_settings(s, None))
+  );
+
+  // a basket that wasn't committed last time opens right away,
+  // which offers to restore it
+  if let Some(category) = draft_category() {
+    create_leaf(s, model, category);
+  }
 }
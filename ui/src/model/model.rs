@@ -1,31 +1,51 @@
 use std::sync::Arc;
 
-use cursive::{
-  reexports::crossbeam_channel::SendError, Cursive, CursiveRunnable
-};
-use prisma_client_rust::Create;
+use cursive::{reexports::crossbeam_channel, Cursive};
 
-use crate::{
-  item::FoodItem,
-  prisma::{
-    food::{Data, SetParam, WithParam},
-    PrismaClient
-  }
-};
+use crate::{queue::CommitQueue, store::PurchaseStore, validation::Rules};
 
-/// Container for PrismaClient and a callback sink provided by Cursive
-/// which is used to call and build UI elements inside tokio tasks
+/// Container for the `PurchaseStore`, the validation `Rules` and a callback sink
+/// provided by Cursive which is used to call and build UI elements inside tokio tasks
 pub struct Model {
   cb_sink: cursive::CbSink,
-  prisma: PrismaClient
+  store: Box<dyn PurchaseStore>,
+  rules: Rules,
+  queue: Option<CommitQueue>
 }
 
 impl Model {
-  /// Construct a `Model` struct wrapped in an `Arc`
-  pub fn new(siv: &CursiveRunnable, prisma: PrismaClient) -> Arc<Self> {
+  /// Construct a `Model` struct wrapped in an `Arc`.
+  /// Without a `queue`, commits the database refuses aren't kept for later
+  pub fn new<T>(
+    siv: &Cursive,
+    store: T,
+    rules: Rules,
+    queue: Option<CommitQueue>
+  ) -> Arc<Self>
+  where
+    T: PurchaseStore + 'static
+  {
     Arc::new(Self {
       cb_sink: siv.cb_sink().clone(),
-      prisma
+      store: Box::new(store),
+      rules,
+      queue
+    })
+  }
+
+  /// Construct a `Model` for running without the TUI, e.g. from the command line.
+  /// Nothing listens on its callback sink, so `use_cb_sink` always fails
+  pub fn headless<T>(store: T, rules: Rules) -> Arc<Self>
+  where
+    T: PurchaseStore + 'static
+  {
+    let (cb_sink, _) = crossbeam_channel::unbounded();
+
+    Arc::new(Self {
+      cb_sink,
+      store: Box::new(store),
+      rules,
+      queue: None
     })
   }
 
@@ -33,36 +53,29 @@ impl Model {
     Arc::clone(model)
   }
 
-  /// Creates a record that will need to be executed in an async context
-  /// Basically, call `.exec()` on the result of this fn
-  pub fn create_item(
-    &self,
-    food_item: FoodItem,
-    params: Vec<SetParam>
-  ) -> Create<SetParam, WithParam, Data> {
-    let (table_name, name, price, amount, total, purchase_date) =
-      food_item.to_parts();
+  /// Where the purchases and categories are read from and written to
+  pub fn store(&self) -> &dyn PurchaseStore {
+    self.store.as_ref()
+  }
+
+  /// What every item has to pass before it's added or committed
+  pub fn rules(&self) -> &Rules {
+    &self.rules
+  }
 
-    self.prisma.food().create(
-      table_name,
-      name,
-      price,
-      amount,
-      total,
-      purchase_date,
-      params
-    )
+  /// Where commits wait while the database can't be reached
+  pub fn queue(&self) -> Option<&CommitQueue> {
+    self.queue.as_ref()
   }
 
   /// The callback sink let's you call methods on `Cursive` in a multithreaded context
-  /// Uses a passed closure that receives `Cursive` as an argument
-  pub fn use_cb_sink<F>(
-    &self,
-    f: F
-  ) -> Result<(), SendError<Box<dyn FnOnce(&mut Cursive) + Send>>>
+  /// Uses a passed closure that receives `Cursive` as an argument.
+  /// Sending only fails once Cursive is gone (or was never there, see `headless`),
+  /// at which point there's nobody left to show anything to
+  pub fn use_cb_sink<F>(&self, f: F)
   where
     F: FnOnce(&mut Cursive) + Send + 'static
   {
-    self.cb_sink.clone().send(Box::new(f))
+    let _ = self.cb_sink.clone().send(Box::new(f));
   }
 }
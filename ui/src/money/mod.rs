@@ -0,0 +1,3 @@
+mod money;
+
+pub use money::Money;
@@ -0,0 +1,176 @@
+use std::{
+  fmt::Display,
+  iter::Sum,
+  ops::{Add, AddAssign, Sub}
+};
+
+use serde::{Deserialize, Serialize};
+
+/// An exact amount of money in cents, so sums don't drift like `f64` does.
+/// Wherever cents have to be rounded, it's half away from zero
+#[derive(
+  Debug,
+  Clone,
+  Copy,
+  Default,
+  PartialEq,
+  Eq,
+  PartialOrd,
+  Ord,
+  Serialize,
+  Deserialize,
+)]
+#[serde(from = "i64", into = "i64")]
+pub struct Money(i64);
+
+impl From<i64> for Money {
+  fn from(cents: i64) -> Self {
+    Self(cents)
+  }
+}
+
+impl From<Money> for i64 {
+  fn from(money: Money) -> Self {
+    money.0
+  }
+}
+
+impl Display for Money {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let sign = if self.0 < 0 { "-" } else { "" };
+    let cents = self.0.unsigned_abs();
+
+    // `pad` so the width and alignment of the format string still apply
+    f.pad(&format!("{}{}.{:02}", sign, cents / 100, cents % 100))
+  }
+}
+
+impl Add for Money {
+  type Output = Self;
+
+  fn add(self, rhs: Self) -> Self::Output {
+    Self(self.0 + rhs.0)
+  }
+}
+
+impl Sub for Money {
+  type Output = Self;
+
+  fn sub(self, rhs: Self) -> Self::Output {
+    Self(self.0 - rhs.0)
+  }
+}
+
+impl AddAssign for Money {
+  fn add_assign(&mut self, rhs: Self) {
+    self.0 += rhs.0;
+  }
+}
+
+impl Sum for Money {
+  fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+    iter.fold(Self::default(), Add::add)
+  }
+}
+
+impl<'a> Sum<&'a Money> for Money {
+  fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+    iter.copied().sum()
+  }
+}
+
+impl Money {
+  pub fn from_cents(cents: i64) -> Self {
+    Self(cents)
+  }
+
+  pub fn cents(self) -> i64 {
+    self.0
+  }
+
+  /// Parse a price like `3`, `3.5` or `-3,49` without going through `f64`.
+  /// More than two decimal places are refused instead of rounded
+  pub fn parse(text: &str) -> Result<Self, String> {
+    let text = text.trim();
+
+    let (negative, digits) = match text.strip_prefix('-') {
+      Some(digits) => (true, digits),
+      None => (false, text.strip_prefix('+').unwrap_or(text))
+    };
+
+    let (units, decimals) = match digits.split_once(['.', ',']) {
+      Some((units, decimals)) => (units, decimals),
+      None => (digits, "")
+    };
+
+    let is_number = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+
+    if units.is_empty() || !is_number(units) || !is_number(decimals) {
+      return Err(String::from("expected a number like 3.49"));
+    }
+
+    if decimals.len() > 2 {
+      return Err(String::from("has more than 2 decimal places"));
+    }
+
+    let cents = format!("{}{:0<2}", units, decimals)
+      .parse::<i64>()
+      .map_err(|_| String::from("is too large"))?;
+
+    Ok(Self(if negative { -cents } else { cents }))
+  }
+
+  /// The price of a single piece when `amount` of them cost `self`,
+  /// rounded to whole cents. `None` when there's nothing to divide by
+  pub fn divided_by(self, amount: f64) -> Option<Self> {
+    (amount.is_finite() && amount != 0.0)
+      .then(|| Self((self.0 as f64 / amount).round() as i64))
+  }
+
+  /// How far the amount is from zero, either way
+  pub fn abs(self) -> Self {
+    Self(self.0.abs())
+  }
+
+  /// The price of `amount` pieces, rounded to whole cents
+  pub fn times(self, amount: f64) -> Self {
+    Self((self.0 as f64 * amount).round() as i64)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Money;
+
+  #[test]
+  fn parses_prices_without_rounding() {
+    assert_eq!(Money::parse("3"), Ok(Money::from_cents(300)));
+    assert_eq!(Money::parse("3.5"), Ok(Money::from_cents(350)));
+    assert_eq!(Money::parse("-3,49"), Ok(Money::from_cents(-349)));
+    assert_eq!(Money::parse(" +0.07 "), Ok(Money::from_cents(7)));
+  }
+
+  #[test]
+  fn refuses_anything_that_isnt_a_price() {
+    for text in ["", "abc", ".5", "3.499", "3.4.5", "NaN", "inf", "1e3"] {
+      assert!(Money::parse(text).is_err(), "'{}' was accepted", text);
+    }
+  }
+
+  #[test]
+  fn rounds_half_away_from_zero() {
+    assert_eq!(Money::from_cents(1299).times(0.5), Money::from_cents(650));
+    assert_eq!(Money::from_cents(-1299).times(0.5), Money::from_cents(-650));
+    assert_eq!(
+      Money::from_cents(100).divided_by(3.0),
+      Some(Money::from_cents(33))
+    );
+    assert_eq!(Money::from_cents(100).divided_by(0.0), None);
+  }
+
+  #[test]
+  fn shows_two_decimal_places() {
+    assert_eq!(Money::from_cents(-5).to_string(), "-0.05");
+    assert_eq!(format!("{:>6}", Money::from_cents(1234)), " 12.34");
+  }
+}
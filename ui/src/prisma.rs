@@ -1,7 +1,7 @@
 // Code generated by Prisma Client Rust. DO NOT EDIT
 
 #![allow(warnings, unused)]
-static DATAMODEL_STR : & 'static str = "datasource db {\n  provider = \"postgres\"\n  url      = env(\"DATABASE_URL\")\n}\n\ngenerator client {\n  provider = \"cargo prisma\"\n  output   = \"../ui/src/prisma.rs\"\n}\n\nmodel Food {\n  id        Int      @id @default(autoincrement())\n  food_type String   @db.VarChar(100)\n  name      String   @db.VarChar(100)\n  price     Float    @db.Real\n  amount    Float    @db.Real\n  total     Float    @db.Real\n  date      DateTime @db.Date\n}\n" ;
+static DATAMODEL_STR : & 'static str = "datasource db {\n  provider = \"postgres\"\n  url      = env(\"DATABASE_URL\")\n}\n\ngenerator client {\n  provider = \"cargo prisma\"\n  output   = \"../ui/src/prisma.rs\"\n}\n\nmodel Category {\n  id       Int     @id @default(autoincrement())\n  name     String  @unique @db.VarChar(100)\n  position Int     @default(0)\n  archived Boolean @default(false)\n  foods    Food[]\n}\n\nmodel Store {\n  id       Int       @id @default(autoincrement())\n  name     String    @unique @db.VarChar(100)\n  foods    Food[]\n  receipts Receipt[]\n}\n\n// a single shopping trip, every item committed together links to it\nmodel Receipt {\n  id       Int      @id @default(autoincrement())\n  date     DateTime @db.Date\n  store_id Int?\n  store    Store?   @relation(fields: [store_id], references: [id])\n  // in cents, as printed on the receipt, if it was typed in\n  total    Int?\n  notes    String   @default(\"\")\n  foods    Food[]\n}\n\nmodel Food {\n  id         Int      @id @default(autoincrement())\n  food_type  String   @db.VarChar(100)\n  category   Category @relation(fields: [food_type], references: [name])\n  name       String   @db.VarChar(100)\n  // in cents, `total` is rounded half away from zero\n  price      Int\n  amount     Float    @db.Real\n  // pcs, g, kg, ml, l or pack\n  unit       String   @default(\"pcs\") @db.VarChar(10)\n  total      Int\n  date       DateTime @db.Date\n  store_id   Int?\n  store      Store?   @relation(fields: [store_id], references: [id])\n  receipt_id Int?\n  receipt    Receipt? @relation(fields: [receipt_id], references: [id])\n}\n" ;
 static DATABASE_STR: &'static str = "postgres";
 pub async fn new_client(
 ) -> Result<PrismaClient, ::prisma_client_rust::NewClientError> {
@@ -60,7 +60,7 @@ pub async fn new_client_with_url(
   executor.primary_connector().get_connection().await?;
   Ok(PrismaClient::_new(executor, query_schema))
 }
-pub mod food {
+pub mod category {
   use super::_prisma::*;
   use super::*;
   pub mod id {
@@ -140,7 +140,7 @@ pub mod food {
       }
     }
   }
-  pub mod food_type {
+  pub mod name {
     use super::super::*;
     use super::_prisma::*;
     use super::{
@@ -149,488 +149,5592 @@ pub mod food {
     pub fn set<T: From<Set>>(value: String) -> T {
       Set(value).into()
     }
-    pub fn equals(value: String) -> WhereParam {
-      WhereParam::FoodTypeEquals(value).into()
+    pub fn equals<T: From<UniqueWhereParam>>(value: String) -> T {
+      UniqueWhereParam::NameEquals(value).into()
     }
     pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
-      OrderByParam::FoodType(direction)
+      OrderByParam::Name(direction)
     }
     pub fn in_vec(value: Vec<String>) -> WhereParam {
-      WhereParam::FoodTypeInVec(value)
+      WhereParam::NameInVec(value)
     }
     pub fn not_in_vec(value: Vec<String>) -> WhereParam {
-      WhereParam::FoodTypeNotInVec(value)
+      WhereParam::NameNotInVec(value)
     }
     pub fn lt(value: String) -> WhereParam {
-      WhereParam::FoodTypeLt(value)
+      WhereParam::NameLt(value)
     }
     pub fn lte(value: String) -> WhereParam {
-      WhereParam::FoodTypeLte(value)
+      WhereParam::NameLte(value)
     }
     pub fn gt(value: String) -> WhereParam {
-      WhereParam::FoodTypeGt(value)
+      WhereParam::NameGt(value)
     }
     pub fn gte(value: String) -> WhereParam {
-      WhereParam::FoodTypeGte(value)
+      WhereParam::NameGte(value)
     }
     pub fn contains(value: String) -> WhereParam {
-      WhereParam::FoodTypeContains(value)
+      WhereParam::NameContains(value)
     }
     pub fn starts_with(value: String) -> WhereParam {
-      WhereParam::FoodTypeStartsWith(value)
+      WhereParam::NameStartsWith(value)
     }
     pub fn ends_with(value: String) -> WhereParam {
-      WhereParam::FoodTypeEndsWith(value)
+      WhereParam::NameEndsWith(value)
     }
     pub fn mode(value: QueryMode) -> WhereParam {
-      WhereParam::FoodTypeMode(value)
+      WhereParam::NameMode(value)
     }
     pub fn not(value: String) -> WhereParam {
-      WhereParam::FoodTypeNot(value)
+      WhereParam::NameNot(value)
     }
     pub struct Set(pub String);
     impl From<Set> for SetParam {
       fn from(value: Set) -> Self {
-        Self::SetFoodType(value.0)
+        Self::SetName(value.0)
       }
     }
     pub struct Include;
     impl Into<super::IncludeParam> for Include {
       fn into(self) -> super::IncludeParam {
-        super::IncludeParam::FoodType(self)
+        super::IncludeParam::Name(self)
       }
     }
     impl Include {
       pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-        ::prisma_client_rust::Selection::builder("food_type").build()
+        ::prisma_client_rust::Selection::builder("name").build()
       }
     }
     pub struct Select;
     impl Into<super::SelectParam> for Select {
       fn into(self) -> super::SelectParam {
-        super::SelectParam::FoodType(self)
+        super::SelectParam::Name(self)
       }
     }
     impl Select {
       pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-        ::prisma_client_rust::Selection::builder("food_type").build()
+        ::prisma_client_rust::Selection::builder("name").build()
       }
     }
   }
-  pub mod name {
+  pub mod position {
     use super::super::*;
     use super::_prisma::*;
     use super::{
       OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
     };
-    pub fn set<T: From<Set>>(value: String) -> T {
+    pub fn set<T: From<Set>>(value: i32) -> T {
       Set(value).into()
     }
-    pub fn equals(value: String) -> WhereParam {
-      WhereParam::NameEquals(value).into()
+    pub fn equals(value: i32) -> WhereParam {
+      WhereParam::PositionEquals(value).into()
     }
     pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
-      OrderByParam::Name(direction)
+      OrderByParam::Position(direction)
     }
-    pub fn in_vec(value: Vec<String>) -> WhereParam {
-      WhereParam::NameInVec(value)
+    pub fn in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::PositionInVec(value)
     }
-    pub fn not_in_vec(value: Vec<String>) -> WhereParam {
-      WhereParam::NameNotInVec(value)
+    pub fn not_in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::PositionNotInVec(value)
     }
-    pub fn lt(value: String) -> WhereParam {
-      WhereParam::NameLt(value)
+    pub fn lt(value: i32) -> WhereParam {
+      WhereParam::PositionLt(value)
     }
-    pub fn lte(value: String) -> WhereParam {
-      WhereParam::NameLte(value)
+    pub fn lte(value: i32) -> WhereParam {
+      WhereParam::PositionLte(value)
     }
-    pub fn gt(value: String) -> WhereParam {
-      WhereParam::NameGt(value)
+    pub fn gt(value: i32) -> WhereParam {
+      WhereParam::PositionGt(value)
     }
-    pub fn gte(value: String) -> WhereParam {
-      WhereParam::NameGte(value)
+    pub fn gte(value: i32) -> WhereParam {
+      WhereParam::PositionGte(value)
     }
-    pub fn contains(value: String) -> WhereParam {
-      WhereParam::NameContains(value)
+    pub fn not(value: i32) -> WhereParam {
+      WhereParam::PositionNot(value)
     }
-    pub fn starts_with(value: String) -> WhereParam {
-      WhereParam::NameStartsWith(value)
+    pub fn increment(value: i32) -> SetParam {
+      SetParam::IncrementPosition(value)
     }
-    pub fn ends_with(value: String) -> WhereParam {
-      WhereParam::NameEndsWith(value)
+    pub fn decrement(value: i32) -> SetParam {
+      SetParam::DecrementPosition(value)
     }
-    pub fn mode(value: QueryMode) -> WhereParam {
-      WhereParam::NameMode(value)
+    pub fn multiply(value: i32) -> SetParam {
+      SetParam::MultiplyPosition(value)
     }
-    pub fn not(value: String) -> WhereParam {
-      WhereParam::NameNot(value)
+    pub fn divide(value: i32) -> SetParam {
+      SetParam::DividePosition(value)
     }
-    pub struct Set(pub String);
+    pub struct Set(pub i32);
     impl From<Set> for SetParam {
       fn from(value: Set) -> Self {
-        Self::SetName(value.0)
+        Self::SetPosition(value.0)
       }
     }
     pub struct Include;
     impl Into<super::IncludeParam> for Include {
       fn into(self) -> super::IncludeParam {
-        super::IncludeParam::Name(self)
+        super::IncludeParam::Position(self)
       }
     }
     impl Include {
       pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-        ::prisma_client_rust::Selection::builder("name").build()
+        ::prisma_client_rust::Selection::builder("position").build()
       }
     }
     pub struct Select;
     impl Into<super::SelectParam> for Select {
       fn into(self) -> super::SelectParam {
-        super::SelectParam::Name(self)
+        super::SelectParam::Position(self)
       }
     }
     impl Select {
       pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-        ::prisma_client_rust::Selection::builder("name").build()
+        ::prisma_client_rust::Selection::builder("position").build()
       }
     }
   }
-  pub mod price {
+  pub mod archived {
     use super::super::*;
     use super::_prisma::*;
     use super::{
       OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
     };
-    pub fn set<T: From<Set>>(value: f64) -> T {
+    pub fn set<T: From<Set>>(value: bool) -> T {
       Set(value).into()
     }
-    pub fn equals(value: f64) -> WhereParam {
-      WhereParam::PriceEquals(value).into()
+    pub fn equals(value: bool) -> WhereParam {
+      WhereParam::ArchivedEquals(value).into()
     }
     pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
-      OrderByParam::Price(direction)
-    }
-    pub fn in_vec(value: Vec<f64>) -> WhereParam {
-      WhereParam::PriceInVec(value)
-    }
-    pub fn not_in_vec(value: Vec<f64>) -> WhereParam {
-      WhereParam::PriceNotInVec(value)
+      OrderByParam::Archived(direction)
     }
-    pub fn lt(value: f64) -> WhereParam {
-      WhereParam::PriceLt(value)
-    }
-    pub fn lte(value: f64) -> WhereParam {
-      WhereParam::PriceLte(value)
-    }
-    pub fn gt(value: f64) -> WhereParam {
-      WhereParam::PriceGt(value)
-    }
-    pub fn gte(value: f64) -> WhereParam {
-      WhereParam::PriceGte(value)
-    }
-    pub fn not(value: f64) -> WhereParam {
-      WhereParam::PriceNot(value)
-    }
-    pub fn increment(value: f64) -> SetParam {
-      SetParam::IncrementPrice(value)
-    }
-    pub fn decrement(value: f64) -> SetParam {
-      SetParam::DecrementPrice(value)
-    }
-    pub fn multiply(value: f64) -> SetParam {
-      SetParam::MultiplyPrice(value)
-    }
-    pub fn divide(value: f64) -> SetParam {
-      SetParam::DividePrice(value)
+    pub fn not(value: bool) -> WhereParam {
+      WhereParam::ArchivedNot(value)
     }
-    pub struct Set(pub f64);
+    pub struct Set(pub bool);
     impl From<Set> for SetParam {
       fn from(value: Set) -> Self {
-        Self::SetPrice(value.0)
+        Self::SetArchived(value.0)
       }
     }
     pub struct Include;
     impl Into<super::IncludeParam> for Include {
       fn into(self) -> super::IncludeParam {
-        super::IncludeParam::Price(self)
+        super::IncludeParam::Archived(self)
       }
     }
     impl Include {
       pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-        ::prisma_client_rust::Selection::builder("price").build()
+        ::prisma_client_rust::Selection::builder("archived").build()
       }
     }
     pub struct Select;
     impl Into<super::SelectParam> for Select {
       fn into(self) -> super::SelectParam {
-        super::SelectParam::Price(self)
+        super::SelectParam::Archived(self)
       }
     }
     impl Select {
       pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-        ::prisma_client_rust::Selection::builder("price").build()
+        ::prisma_client_rust::Selection::builder("archived").build()
       }
     }
   }
-  pub mod amount {
+  pub mod foods {
     use super::super::*;
     use super::_prisma::*;
     use super::{
       OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
     };
-    pub fn set<T: From<Set>>(value: f64) -> T {
-      Set(value).into()
+    pub fn some(value: Vec<super::super::food::WhereParam>) -> WhereParam {
+      WhereParam::FoodsSome(value)
+    }
+    pub fn every(value: Vec<super::super::food::WhereParam>) -> WhereParam {
+      WhereParam::FoodsEvery(value)
+    }
+    pub fn none(value: Vec<super::super::food::WhereParam>) -> WhereParam {
+      WhereParam::FoodsNone(value)
+    }
+    pub struct Fetch(pub super::super::food::ManyArgs);
+    impl Fetch {
+      pub fn with(
+        mut self,
+        params: impl Into<super::super::food::WithParam>
+      ) -> Self {
+        self.0 = self.0.with(params.into());
+        self
+      }
+      pub fn order_by(
+        mut self,
+        param: super::super::food::OrderByParam
+      ) -> Self {
+        self.0 = self.0.order_by(param);
+        self
+      }
+      pub fn skip(mut self, value: i64) -> Self {
+        self.0 = self.0.skip(value);
+        self
+      }
+      pub fn take(mut self, value: i64) -> Self {
+        self.0 = self.0.take(value);
+        self
+      }
+      pub fn cursor(
+        mut self,
+        value: super::super::food::UniqueWhereParam
+      ) -> Self {
+        self.0 = self.0.cursor(value.into());
+        self
+      }
     }
-    pub fn equals(value: f64) -> WhereParam {
-      WhereParam::AmountEquals(value).into()
+    impl From<Fetch> for WithParam {
+      fn from(fetch: Fetch) -> Self {
+        WithParam::Foods(fetch.0)
+      }
     }
-    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
-      OrderByParam::Amount(direction)
+    pub fn fetch(params: Vec<super::super::food::WhereParam>) -> Fetch {
+      Fetch(super::super::food::ManyArgs::new(params))
     }
-    pub fn in_vec(value: Vec<f64>) -> WhereParam {
-      WhereParam::AmountInVec(value)
+    pub fn connect<T: From<Connect>>(
+      params: Vec<super::super::food::UniqueWhereParam>
+    ) -> T {
+      Connect(params).into()
     }
-    pub fn not_in_vec(value: Vec<f64>) -> WhereParam {
-      WhereParam::AmountNotInVec(value)
+    pub fn disconnect(
+      params: Vec<super::super::food::UniqueWhereParam>
+    ) -> SetParam {
+      SetParam::DisconnectFoods(params)
     }
-    pub fn lt(value: f64) -> WhereParam {
-      WhereParam::AmountLt(value)
+    pub fn set(params: Vec<super::super::food::UniqueWhereParam>) -> SetParam {
+      SetParam::SetFoods(params)
     }
-    pub fn lte(value: f64) -> WhereParam {
-      WhereParam::AmountLte(value)
+    pub struct Connect(pub Vec<super::super::food::UniqueWhereParam>);
+    impl From<Connect> for SetParam {
+      fn from(value: Connect) -> Self {
+        Self::ConnectFoods(value.0)
+      }
     }
-    pub fn gt(value: f64) -> WhereParam {
-      WhereParam::AmountGt(value)
+    pub enum Include {
+      Select(
+        super::super::food::ManyArgs,
+        Vec<super::super::food::SelectParam>
+      ),
+      Include(
+        super::super::food::ManyArgs,
+        Vec<super::super::food::IncludeParam>
+      ),
+      Fetch(super::super::food::ManyArgs)
     }
-    pub fn gte(value: f64) -> WhereParam {
-      WhereParam::AmountGte(value)
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Foods(self)
+      }
+    }
+    impl Include {
+      pub fn select(
+        args: super::super::food::ManyArgs,
+        nested_selections: Vec<super::super::food::SelectParam>
+      ) -> Self {
+        Self::Select(args, nested_selections)
+      }
+      pub fn include(
+        args: super::super::food::ManyArgs,
+        nested_selections: Vec<super::super::food::IncludeParam>
+      ) -> Self {
+        Self::Include(args, nested_selections)
+      }
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        let mut selection = ::prisma_client_rust::Selection::builder("foods");
+        match self {
+          Self::Select(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            let mut nested_selections = super::super::food::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch(args) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(super::super::food::_outputs());
+          }
+        }
+        selection.build()
+      }
+    }
+    pub enum Select {
+      Select(
+        super::super::food::ManyArgs,
+        Vec<super::super::food::SelectParam>
+      ),
+      Include(
+        super::super::food::ManyArgs,
+        Vec<super::super::food::IncludeParam>
+      ),
+      Fetch(super::super::food::ManyArgs)
+    }
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Foods(self)
+      }
+    }
+    impl Select {
+      pub fn select(
+        args: super::super::food::ManyArgs,
+        nested_selections: Vec<super::super::food::SelectParam>
+      ) -> Self {
+        Self::Select(args, nested_selections)
+      }
+      pub fn include(
+        args: super::super::food::ManyArgs,
+        nested_selections: Vec<super::super::food::IncludeParam>
+      ) -> Self {
+        Self::Include(args, nested_selections)
+      }
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        let mut selection = ::prisma_client_rust::Selection::builder("foods");
+        match self {
+          Self::Select(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            let mut nested_selections = super::super::food::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch(args) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(super::super::food::_outputs());
+          }
+        }
+        selection.build()
+      }
+    }
+  }
+  pub fn _outputs() -> Vec<::prisma_client_rust::Selection> {
+    ["id", "name", "position", "archived"]
+      .into_iter()
+      .map(|o| {
+        let builder = ::prisma_client_rust::Selection::builder(o);
+        builder.build()
+      })
+      .collect()
+  }
+  pub fn create(
+    name: String,
+    _params: Vec<SetParam>
+  ) -> (String, Vec<SetParam>) {
+    (name, _params)
+  }
+  #[macro_export]
+  macro_rules ! _select_category { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: category :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: category :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Select { Select ($ crate :: prisma :: category :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: category :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: category :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Select ($ crate :: prisma :: category :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , name , position , archived , foods } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug)] pub struct Data { $ (pub $ field : $ crate :: prisma :: category :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (name) , stringify ! (position) , stringify ! (archived) , stringify ! (foods)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: category :: select ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: category :: select ! (@ field_serde_name ; $ field) , ", ") , +)) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: category :: select ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: category :: select ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: category :: select ! (@ field_serde_name ; $ field))) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "name" , "position" , "archived" , "foods"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: category :: select ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { i32 } ; (@ field_type ; name) => { String } ; (@ field_type ; position) => { i32 } ; (@ field_type ; archived) => { bool } ; (@ field_type ; foods : $ selection_mode : ident { $ ($ selections : tt) + }) => { Vec < foods :: Data > } ; (@ field_type ; foods) => { Vec < $ crate :: prisma :: food :: Data > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot select field nonexistent field $ field on model "Category" , available fields are "id, name, position, archived, foods")) } ; (@ field_module ; foods : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: food :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < $ crate :: prisma :: category :: SelectParam > :: into ($ crate :: prisma :: category :: id :: Select) } ; (@ selection_field_to_selection_param ; name) => { Into :: < $ crate :: prisma :: category :: SelectParam > :: into ($ crate :: prisma :: category :: name :: Select) } ; (@ selection_field_to_selection_param ; position) => { Into :: < $ crate :: prisma :: category :: SelectParam > :: into ($ crate :: prisma :: category :: position :: Select) } ; (@ selection_field_to_selection_param ; archived) => { Into :: < $ crate :: prisma :: category :: SelectParam > :: into ($ crate :: prisma :: category :: archived :: Select) } ; (@ selection_field_to_selection_param ; foods $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? : $ selection_mode : ident { $ ($ selections : tt) + }) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: food :: ManyArgs :: new ($ crate :: prisma :: category :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: category :: SelectParam > :: into ($ crate :: prisma :: category :: foods :: Select :: $ selection_mode (args , $ crate :: prisma :: food :: $ selection_mode ! (@ selections_to_select_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } } ; (@ selection_field_to_selection_param ; foods $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ?) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: food :: ManyArgs :: new ($ crate :: prisma :: category :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: category :: SelectParam > :: into ($ crate :: prisma :: category :: foods :: Select :: Fetch (args)) } } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_select_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: category :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; name) => { "name" } ; (@ field_serde_name ; position) => { "position" } ; (@ field_serde_name ; archived) => { "archived" } ; (@ field_serde_name ; foods) => { "foods" } ; }
+  pub use _select_category as select;
+  pub enum SelectParam {
+    Id(id::Select),
+    Name(name::Select),
+    Position(position::Select),
+    Archived(archived::Select),
+    Foods(foods::Select)
+  }
+  impl SelectParam {
+    pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+      match self {
+        Self::Id(data) => data.to_selection(),
+        Self::Name(data) => data.to_selection(),
+        Self::Position(data) => data.to_selection(),
+        Self::Archived(data) => data.to_selection(),
+        Self::Foods(data) => data.to_selection()
+      }
+    }
+  }
+  #[macro_export]
+  macro_rules ! _include_category { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: category :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: category :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Include { let mut selections = $ crate :: prisma :: category :: _outputs () ; selections . extend ($ crate :: prisma :: category :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; Include (selections) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: category :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: category :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Include ({ let mut selections = $ crate :: prisma :: category :: _outputs () ; selections . extend ($ crate :: prisma :: category :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; selections }) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { foods } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug)] pub struct Data { pub id : i32 , pub name : String , pub position : i32 , pub archived : bool , $ (pub $ field : $ crate :: prisma :: category :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (name) , stringify ! (position) , stringify ! (archived)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . serialize_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; id) , & self . id) ? ; state . serialize_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; name) , & self . name) ? ; state . serialize_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; position) , & self . position) ? ; state . serialize_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; archived) , & self . archived) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , name , position , archived } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: category :: include ! (@ field_serde_name ; $ field) , ", ") , + , $ crate :: prisma :: category :: include ! (@ field_serde_name ; id) , ", " , $ crate :: prisma :: category :: include ! (@ field_serde_name ; name) , ", " , $ crate :: prisma :: category :: include ! (@ field_serde_name ; position) , ", " , $ crate :: prisma :: category :: include ! (@ field_serde_name ; archived) , ", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: category :: include ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , $ crate :: prisma :: category :: include ! (@ field_serde_name ; id) => Ok (Field :: id) , $ crate :: prisma :: category :: include ! (@ field_serde_name ; name) => Ok (Field :: name) , $ crate :: prisma :: category :: include ! (@ field_serde_name ; position) => Ok (Field :: position) , $ crate :: prisma :: category :: include ! (@ field_serde_name ; archived) => Ok (Field :: archived) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut name = None ; let mut position = None ; let mut archived = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; id))) ; } id = Some (map . next_value () ?) ; } Field :: name => { if name . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; name))) ; } name = Some (map . next_value () ?) ; } Field :: position => { if position . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; position))) ; } position = Some (map . next_value () ?) ; } Field :: archived => { if archived . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; archived))) ; } archived = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; $ field))) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; id))) ? ; let name = name . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; name))) ? ; let position = position . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; position))) ? ; let archived = archived . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: category :: include ! (@ field_serde_name ; archived))) ? ; Ok (Data { id , name , position , archived , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "name" , "position" , "archived" , "foods"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: category :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; foods : $ selection_mode : ident { $ ($ selections : tt) + }) => { Vec < foods :: Data > } ; (@ field_type ; foods) => { Vec < $ crate :: prisma :: food :: Data > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "Category" , available relations are "foods")) } ; (@ field_module ; foods : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: food :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; foods $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? : $ selection_mode : ident { $ ($ selections : tt) + }) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: food :: ManyArgs :: new ($ crate :: prisma :: category :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: category :: IncludeParam > :: into ($ crate :: prisma :: category :: foods :: Include :: $ selection_mode (args , $ crate :: prisma :: food :: $ selection_mode ! (@ selections_to_include_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } } ; (@ selection_field_to_selection_param ; foods $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ?) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: food :: ManyArgs :: new ($ crate :: prisma :: category :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: category :: IncludeParam > :: into ($ crate :: prisma :: category :: foods :: Include :: Fetch (args)) } } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_include_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: category :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; name) => { "name" } ; (@ field_serde_name ; position) => { "position" } ; (@ field_serde_name ; archived) => { "archived" } ; (@ field_serde_name ; foods) => { "foods" } ; }
+  pub use _include_category as include;
+  pub enum IncludeParam {
+    Id(id::Include),
+    Name(name::Include),
+    Position(position::Include),
+    Archived(archived::Include),
+    Foods(foods::Include)
+  }
+  impl IncludeParam {
+    pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+      match self {
+        Self::Id(data) => data.to_selection(),
+        Self::Name(data) => data.to_selection(),
+        Self::Position(data) => data.to_selection(),
+        Self::Archived(data) => data.to_selection(),
+        Self::Foods(data) => data.to_selection()
+      }
+    }
+  }
+  #[derive(Debug, Clone, :: serde :: Serialize, :: serde :: Deserialize)]
+  pub struct Data {
+    #[serde(rename = "id")]
+    pub id: i32,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "position")]
+    pub position: i32,
+    #[serde(rename = "archived")]
+    pub archived: bool,
+    #[serde(rename = "foods")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foods: Option<Vec<super::food::Data>>
+  }
+  impl Data {
+    pub fn foods(
+      &self
+    ) -> Result<
+      &Vec<super::food::Data>,
+      ::prisma_client_rust::RelationNotFetchedError
+    > {
+      self.foods.as_ref().ok_or(
+        ::prisma_client_rust::RelationNotFetchedError::new(stringify!(foods))
+      )
+    }
+  }
+  #[derive(Clone)]
+  pub enum WithParam {
+    Foods(super::food::ManyArgs)
+  }
+  impl Into<::prisma_client_rust::Selection> for WithParam {
+    fn into(self) -> ::prisma_client_rust::Selection {
+      match self {
+        Self::Foods(args) => {
+          let (arguments, mut nested_selections) = args.to_graphql();
+          nested_selections.extend(super::food::_outputs());
+          ::prisma_client_rust::Selection::builder("foods")
+            .nested_selections(nested_selections)
+            .set_arguments(arguments)
+            .build()
+        }
+      }
+    }
+  }
+  #[derive(Clone)]
+  pub enum SetParam {
+    SetId(i32),
+    IncrementId(i32),
+    DecrementId(i32),
+    MultiplyId(i32),
+    DivideId(i32),
+    SetName(String),
+    SetPosition(i32),
+    IncrementPosition(i32),
+    DecrementPosition(i32),
+    MultiplyPosition(i32),
+    DividePosition(i32),
+    SetArchived(bool),
+    ConnectFoods(Vec<super::food::UniqueWhereParam>),
+    DisconnectFoods(Vec<super::food::UniqueWhereParam>),
+    SetFoods(Vec<super::food::UniqueWhereParam>)
+  }
+  impl Into<(String, ::prisma_client_rust::PrismaValue)> for SetParam {
+    fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+      match self {
+        SetParam::SetId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Int(value as i64)
+        ),
+        SetParam::IncrementId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "increment".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::DecrementId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "decrement".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::MultiplyId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "multiply".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::DivideId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "divide".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::SetName(value) => (
+          "name".to_string(),
+          ::prisma_client_rust::PrismaValue::String(value)
+        ),
+        SetParam::SetPosition(value) => (
+          "position".to_string(),
+          ::prisma_client_rust::PrismaValue::Int(value as i64)
+        ),
+        SetParam::IncrementPosition(value) => (
+          "position".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "increment".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::DecrementPosition(value) => (
+          "position".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "decrement".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::MultiplyPosition(value) => (
+          "position".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "multiply".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::DividePosition(value) => (
+          "position".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "divide".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::SetArchived(value) => (
+          "archived".to_string(),
+          ::prisma_client_rust::PrismaValue::Boolean(value)
+        ),
+        SetParam::ConnectFoods(where_params) => (
+          "foods".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "connect".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              where_params
+                .into_iter()
+                .map(Into::<super::food::WhereParam>::into)
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                .collect()
+            )
+          )])
+        ),
+        SetParam::DisconnectFoods(where_params) => (
+          "foods".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "disconnect".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              where_params
+                .into_iter()
+                .map(Into::<super::food::WhereParam>::into)
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                .collect()
+            )
+          )])
+        ),
+        SetParam::SetFoods(where_params) => (
+          "foods".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "set".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              where_params
+                .into_iter()
+                .map(Into::<super::food::WhereParam>::into)
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                .collect()
+            )
+          )])
+        )
+      }
+    }
+  }
+  #[derive(Clone)]
+  pub enum OrderByParam {
+    Id(::prisma_client_rust::Direction),
+    Name(::prisma_client_rust::Direction),
+    Position(::prisma_client_rust::Direction),
+    Archived(::prisma_client_rust::Direction)
+  }
+  impl Into<(String, ::prisma_client_rust::PrismaValue)> for OrderByParam {
+    fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+      match self {
+        Self::Id(direction) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        ),
+        Self::Name(direction) => (
+          "name".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        ),
+        Self::Position(direction) => (
+          "position".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        ),
+        Self::Archived(direction) => (
+          "archived".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        )
+      }
+    }
+  }
+  #[derive(Clone)]
+  pub enum WhereParam {
+    Not(Vec<WhereParam>),
+    Or(Vec<WhereParam>),
+    And(Vec<WhereParam>),
+    IdEquals(i32),
+    IdInVec(Vec<i32>),
+    IdNotInVec(Vec<i32>),
+    IdLt(i32),
+    IdLte(i32),
+    IdGt(i32),
+    IdGte(i32),
+    IdNot(i32),
+    NameEquals(String),
+    NameInVec(Vec<String>),
+    NameNotInVec(Vec<String>),
+    NameLt(String),
+    NameLte(String),
+    NameGt(String),
+    NameGte(String),
+    NameContains(String),
+    NameStartsWith(String),
+    NameEndsWith(String),
+    NameMode(QueryMode),
+    NameNot(String),
+    PositionEquals(i32),
+    PositionInVec(Vec<i32>),
+    PositionNotInVec(Vec<i32>),
+    PositionLt(i32),
+    PositionLte(i32),
+    PositionGt(i32),
+    PositionGte(i32),
+    PositionNot(i32),
+    ArchivedEquals(bool),
+    ArchivedNot(bool),
+    FoodsSome(Vec<super::food::WhereParam>),
+    FoodsEvery(Vec<super::food::WhereParam>),
+    FoodsNone(Vec<super::food::WhereParam>)
+  }
+  impl Into<::prisma_client_rust::SerializedWhere> for WhereParam {
+    fn into(self) -> ::prisma_client_rust::SerializedWhere {
+      match self {
+        Self::Not(value) => ::prisma_client_rust::SerializedWhere::new(
+          "NOT",
+          ::prisma_client_rust::SerializedWhereValue::Object(
+            value
+              .into_iter()
+              .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+              .map(Into::into)
+              .collect()
+          )
+        ),
+        Self::Or(value) => ::prisma_client_rust::SerializedWhere::new(
+          "OR",
+          ::prisma_client_rust::SerializedWhereValue::List(
+            value
+              .into_iter()
+              .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+              .map(Into::into)
+              .map(|v| vec![v])
+              .map(::prisma_client_rust::PrismaValue::Object)
+              .collect()
+          )
+        ),
+        Self::And(value) => ::prisma_client_rust::SerializedWhere::new(
+          "AND",
+          ::prisma_client_rust::SerializedWhereValue::Object(
+            value
+              .into_iter()
+              .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+              .map(Into::into)
+              .collect()
+          )
+        ),
+        Self::IdEquals(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "equals".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "in".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              value
+                .into_iter()
+                .map(|v| ::prisma_client_rust::PrismaValue::Int(v as i64))
+                .collect()
+            )
+          )])
+        ),
+        Self::IdNotInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "notIn".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              value
+                .into_iter()
+                .map(|v| ::prisma_client_rust::PrismaValue::Int(v as i64))
+                .collect()
+            )
+          )])
+        ),
+        Self::IdLt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lt".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdLte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lte".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdGt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gt".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdGte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gte".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdNot(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "not".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::NameEquals(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "equals".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NameInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "in".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              value
+                .into_iter()
+                .map(|v| ::prisma_client_rust::PrismaValue::String(v))
+                .collect()
+            )
+          )])
+        ),
+        Self::NameNotInVec(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "name",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "notIn".to_string(),
+              ::prisma_client_rust::PrismaValue::List(
+                value
+                  .into_iter()
+                  .map(|v| ::prisma_client_rust::PrismaValue::String(v))
+                  .collect()
+              )
+            )])
+          )
+        }
+        Self::NameLt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lt".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NameLte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lte".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NameGt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gt".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NameGte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gte".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NameContains(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "name",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "contains".to_string(),
+              ::prisma_client_rust::PrismaValue::String(value)
+            )])
+          )
+        }
+        Self::NameStartsWith(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "name",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "startsWith".to_string(),
+              ::prisma_client_rust::PrismaValue::String(value)
+            )])
+          )
+        }
+        Self::NameEndsWith(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "name",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "endsWith".to_string(),
+              ::prisma_client_rust::PrismaValue::String(value)
+            )])
+          )
+        }
+        Self::NameMode(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "mode".to_string(),
+            ::prisma_client_rust::PrismaValue::Enum(value.to_string())
+          )])
+        ),
+        Self::NameNot(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "not".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::PositionEquals(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "position",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "equals".to_string(),
+              ::prisma_client_rust::PrismaValue::Int(value as i64)
+            )])
+          )
+        }
+        Self::PositionInVec(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "position",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "in".to_string(),
+              ::prisma_client_rust::PrismaValue::List(
+                value
+                  .into_iter()
+                  .map(|v| ::prisma_client_rust::PrismaValue::Int(v as i64))
+                  .collect()
+              )
+            )])
+          )
+        }
+        Self::PositionNotInVec(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "position",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "notIn".to_string(),
+              ::prisma_client_rust::PrismaValue::List(
+                value
+                  .into_iter()
+                  .map(|v| ::prisma_client_rust::PrismaValue::Int(v as i64))
+                  .collect()
+              )
+            )])
+          )
+        }
+        Self::PositionLt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "position",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lt".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::PositionLte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "position",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lte".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::PositionGt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "position",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gt".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::PositionGte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "position",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gte".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::PositionNot(value) => ::prisma_client_rust::SerializedWhere::new(
+          "position",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "not".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::ArchivedEquals(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "archived",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "equals".to_string(),
+              ::prisma_client_rust::PrismaValue::Boolean(value)
+            )])
+          )
+        }
+        Self::ArchivedNot(value) => ::prisma_client_rust::SerializedWhere::new(
+          "archived",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "not".to_string(),
+            ::prisma_client_rust::PrismaValue::Boolean(value)
+          )])
+        ),
+        Self::FoodsSome(value) => ::prisma_client_rust::SerializedWhere::new(
+          "foods",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "some".to_string(),
+            ::prisma_client_rust::PrismaValue::Object(
+              value
+                .into_iter()
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(Into::into)
+                .collect()
+            )
+          )])
+        ),
+        Self::FoodsEvery(value) => ::prisma_client_rust::SerializedWhere::new(
+          "foods",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "every".to_string(),
+            ::prisma_client_rust::PrismaValue::Object(
+              value
+                .into_iter()
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(Into::into)
+                .collect()
+            )
+          )])
+        ),
+        Self::FoodsNone(value) => ::prisma_client_rust::SerializedWhere::new(
+          "foods",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "none".to_string(),
+            ::prisma_client_rust::PrismaValue::Object(
+              value
+                .into_iter()
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(Into::into)
+                .collect()
+            )
+          )])
+        )
+      }
+    }
+  }
+  #[derive(Clone)]
+  pub enum UniqueWhereParam {
+    IdEquals(i32),
+    NameEquals(String)
+  }
+  impl From<UniqueWhereParam> for WhereParam {
+    fn from(value: UniqueWhereParam) -> Self {
+      match value {
+        UniqueWhereParam::IdEquals(value) => Self::IdEquals(value),
+        UniqueWhereParam::NameEquals(value) => Self::NameEquals(value)
+      }
+    }
+  }
+  impl From<::prisma_client_rust::Operator<Self>> for WhereParam {
+    fn from(op: ::prisma_client_rust::Operator<Self>) -> Self {
+      match op {
+        ::prisma_client_rust::Operator::Not(value) => Self::Not(value),
+        ::prisma_client_rust::Operator::And(value) => Self::And(value),
+        ::prisma_client_rust::Operator::Or(value) => Self::Or(value)
+      }
+    }
+  }
+  pub type UniqueArgs = ::prisma_client_rust::UniqueArgs<WithParam>;
+  pub type ManyArgs = ::prisma_client_rust::ManyArgs<
+    WhereParam,
+    WithParam,
+    OrderByParam,
+    UniqueWhereParam
+  >;
+  pub type Count<'a> =
+    ::prisma_client_rust::Count<'a, WhereParam, OrderByParam, UniqueWhereParam>;
+  pub type Create<'a> =
+    ::prisma_client_rust::Create<'a, SetParam, WithParam, Data>;
+  pub type CreateMany<'a> = ::prisma_client_rust::CreateMany<'a, SetParam>;
+  pub type FindUnique<'a> =
+    ::prisma_client_rust::FindUnique<'a, WhereParam, WithParam, SetParam, Data>;
+  pub type FindMany<'a> = ::prisma_client_rust::FindMany<
+    'a,
+    WhereParam,
+    WithParam,
+    OrderByParam,
+    UniqueWhereParam,
+    SetParam,
+    Data
+  >;
+  pub type FindFirst<'a> = ::prisma_client_rust::FindFirst<
+    'a,
+    WhereParam,
+    WithParam,
+    OrderByParam,
+    UniqueWhereParam,
+    Data
+  >;
+  pub type Update<'a> =
+    ::prisma_client_rust::Update<'a, WhereParam, WithParam, SetParam, Data>;
+  pub type UpdateMany<'a> =
+    ::prisma_client_rust::UpdateMany<'a, WhereParam, SetParam>;
+  pub type Upsert<'a> =
+    ::prisma_client_rust::Upsert<'a, WhereParam, SetParam, WithParam, Data>;
+  pub type Delete<'a> =
+    ::prisma_client_rust::Delete<'a, WhereParam, WithParam, Data>;
+  pub type DeleteMany<'a> = ::prisma_client_rust::DeleteMany<'a, WhereParam>;
+  pub struct Actions<'a> {
+    pub client: &'a PrismaClient
+  }
+  impl<'a> Actions<'a> {
+    pub fn find_unique(self, _where: UniqueWhereParam) -> FindUnique<'a> {
+      FindUnique::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Category", _outputs()),
+        _where.into()
+      )
+    }
+    pub fn find_first(self, _where: Vec<WhereParam>) -> FindFirst<'a> {
+      FindFirst::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Category", _outputs()),
+        _where
+      )
+    }
+    pub fn find_many(self, _where: Vec<WhereParam>) -> FindMany<'a> {
+      FindMany::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Category", _outputs()),
+        _where
+      )
+    }
+    pub fn create(
+      self,
+      name: String,
+      mut _params: Vec<SetParam>
+    ) -> Create<'a> {
+      _params.push(name::set(name));
+      Create::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Category", _outputs()),
+        _params
+      )
+    }
+    pub fn create_many(
+      self,
+      data: Vec<(String, Vec<SetParam>)>
+    ) -> CreateMany<'a> {
+      let data = data
+        .into_iter()
+        .map(|(name, mut _params)| {
+          _params.push(name::set(name));
+          _params
+        })
+        .collect();
+      CreateMany::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Category", _outputs()),
+        data
+      )
+    }
+    pub fn update(
+      self,
+      _where: UniqueWhereParam,
+      _params: Vec<SetParam>
+    ) -> Update<'a> {
+      Update::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Category", _outputs()),
+        _where.into(),
+        _params,
+        vec![]
+      )
+    }
+    pub fn update_many(
+      self,
+      _where: Vec<WhereParam>,
+      _params: Vec<SetParam>
+    ) -> UpdateMany<'a> {
+      UpdateMany::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Category", _outputs()),
+        _where,
+        _params
+      )
+    }
+    pub fn upsert(
+      self,
+      _where: UniqueWhereParam,
+      (name, mut _params): (String, Vec<SetParam>),
+      _update: Vec<SetParam>
+    ) -> Upsert<'a> {
+      _params.push(name::set(name));
+      Upsert::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Category", _outputs()),
+        _where.into(),
+        _params,
+        _update
+      )
+    }
+    pub fn delete(self, _where: UniqueWhereParam) -> Delete<'a> {
+      Delete::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Category", _outputs()),
+        _where.into(),
+        vec![]
+      )
+    }
+    pub fn delete_many(self, _where: Vec<WhereParam>) -> DeleteMany<'a> {
+      DeleteMany::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Category", _outputs()),
+        _where.into()
+      )
+    }
+    pub fn count(self, _where: Vec<WhereParam>) -> Count<'a> {
+      Count::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Category", _outputs()),
+        vec![]
+      )
+    }
+  }
+}
+pub mod store {
+  use super::_prisma::*;
+  use super::*;
+  pub mod id {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: i32) -> T {
+      Set(value).into()
+    }
+    pub fn equals<T: From<UniqueWhereParam>>(value: i32) -> T {
+      UniqueWhereParam::IdEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::Id(direction)
+    }
+    pub fn in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::IdInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::IdNotInVec(value)
+    }
+    pub fn lt(value: i32) -> WhereParam {
+      WhereParam::IdLt(value)
+    }
+    pub fn lte(value: i32) -> WhereParam {
+      WhereParam::IdLte(value)
+    }
+    pub fn gt(value: i32) -> WhereParam {
+      WhereParam::IdGt(value)
+    }
+    pub fn gte(value: i32) -> WhereParam {
+      WhereParam::IdGte(value)
+    }
+    pub fn not(value: i32) -> WhereParam {
+      WhereParam::IdNot(value)
+    }
+    pub fn increment(value: i32) -> SetParam {
+      SetParam::IncrementId(value)
+    }
+    pub fn decrement(value: i32) -> SetParam {
+      SetParam::DecrementId(value)
+    }
+    pub fn multiply(value: i32) -> SetParam {
+      SetParam::MultiplyId(value)
+    }
+    pub fn divide(value: i32) -> SetParam {
+      SetParam::DivideId(value)
+    }
+    pub struct Set(pub i32);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetId(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Id(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("id").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Id(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("id").build()
+      }
+    }
+  }
+  pub mod name {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: String) -> T {
+      Set(value).into()
+    }
+    pub fn equals<T: From<UniqueWhereParam>>(value: String) -> T {
+      UniqueWhereParam::NameEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::Name(direction)
+    }
+    pub fn in_vec(value: Vec<String>) -> WhereParam {
+      WhereParam::NameInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<String>) -> WhereParam {
+      WhereParam::NameNotInVec(value)
+    }
+    pub fn lt(value: String) -> WhereParam {
+      WhereParam::NameLt(value)
+    }
+    pub fn lte(value: String) -> WhereParam {
+      WhereParam::NameLte(value)
+    }
+    pub fn gt(value: String) -> WhereParam {
+      WhereParam::NameGt(value)
+    }
+    pub fn gte(value: String) -> WhereParam {
+      WhereParam::NameGte(value)
+    }
+    pub fn contains(value: String) -> WhereParam {
+      WhereParam::NameContains(value)
+    }
+    pub fn starts_with(value: String) -> WhereParam {
+      WhereParam::NameStartsWith(value)
+    }
+    pub fn ends_with(value: String) -> WhereParam {
+      WhereParam::NameEndsWith(value)
+    }
+    pub fn mode(value: QueryMode) -> WhereParam {
+      WhereParam::NameMode(value)
+    }
+    pub fn not(value: String) -> WhereParam {
+      WhereParam::NameNot(value)
+    }
+    pub struct Set(pub String);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetName(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Name(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("name").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Name(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("name").build()
+      }
+    }
+  }
+  pub mod foods {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn some(value: Vec<super::super::food::WhereParam>) -> WhereParam {
+      WhereParam::FoodsSome(value)
+    }
+    pub fn every(value: Vec<super::super::food::WhereParam>) -> WhereParam {
+      WhereParam::FoodsEvery(value)
+    }
+    pub fn none(value: Vec<super::super::food::WhereParam>) -> WhereParam {
+      WhereParam::FoodsNone(value)
+    }
+    pub struct Fetch(pub super::super::food::ManyArgs);
+    impl Fetch {
+      pub fn with(
+        mut self,
+        params: impl Into<super::super::food::WithParam>
+      ) -> Self {
+        self.0 = self.0.with(params.into());
+        self
+      }
+      pub fn order_by(
+        mut self,
+        param: super::super::food::OrderByParam
+      ) -> Self {
+        self.0 = self.0.order_by(param);
+        self
+      }
+      pub fn skip(mut self, value: i64) -> Self {
+        self.0 = self.0.skip(value);
+        self
+      }
+      pub fn take(mut self, value: i64) -> Self {
+        self.0 = self.0.take(value);
+        self
+      }
+      pub fn cursor(
+        mut self,
+        value: super::super::food::UniqueWhereParam
+      ) -> Self {
+        self.0 = self.0.cursor(value.into());
+        self
+      }
+    }
+    impl From<Fetch> for WithParam {
+      fn from(fetch: Fetch) -> Self {
+        WithParam::Foods(fetch.0)
+      }
+    }
+    pub fn fetch(params: Vec<super::super::food::WhereParam>) -> Fetch {
+      Fetch(super::super::food::ManyArgs::new(params))
+    }
+    pub fn connect<T: From<Connect>>(
+      params: Vec<super::super::food::UniqueWhereParam>
+    ) -> T {
+      Connect(params).into()
+    }
+    pub fn disconnect(
+      params: Vec<super::super::food::UniqueWhereParam>
+    ) -> SetParam {
+      SetParam::DisconnectFoods(params)
+    }
+    pub fn set(params: Vec<super::super::food::UniqueWhereParam>) -> SetParam {
+      SetParam::SetFoods(params)
+    }
+    pub struct Connect(pub Vec<super::super::food::UniqueWhereParam>);
+    impl From<Connect> for SetParam {
+      fn from(value: Connect) -> Self {
+        Self::ConnectFoods(value.0)
+      }
+    }
+    pub enum Include {
+      Select(
+        super::super::food::ManyArgs,
+        Vec<super::super::food::SelectParam>
+      ),
+      Include(
+        super::super::food::ManyArgs,
+        Vec<super::super::food::IncludeParam>
+      ),
+      Fetch(super::super::food::ManyArgs)
+    }
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Foods(self)
+      }
+    }
+    impl Include {
+      pub fn select(
+        args: super::super::food::ManyArgs,
+        nested_selections: Vec<super::super::food::SelectParam>
+      ) -> Self {
+        Self::Select(args, nested_selections)
+      }
+      pub fn include(
+        args: super::super::food::ManyArgs,
+        nested_selections: Vec<super::super::food::IncludeParam>
+      ) -> Self {
+        Self::Include(args, nested_selections)
+      }
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        let mut selection = ::prisma_client_rust::Selection::builder("foods");
+        match self {
+          Self::Select(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            let mut nested_selections = super::super::food::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch(args) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(super::super::food::_outputs());
+          }
+        }
+        selection.build()
+      }
+    }
+    pub enum Select {
+      Select(
+        super::super::food::ManyArgs,
+        Vec<super::super::food::SelectParam>
+      ),
+      Include(
+        super::super::food::ManyArgs,
+        Vec<super::super::food::IncludeParam>
+      ),
+      Fetch(super::super::food::ManyArgs)
+    }
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Foods(self)
+      }
+    }
+    impl Select {
+      pub fn select(
+        args: super::super::food::ManyArgs,
+        nested_selections: Vec<super::super::food::SelectParam>
+      ) -> Self {
+        Self::Select(args, nested_selections)
+      }
+      pub fn include(
+        args: super::super::food::ManyArgs,
+        nested_selections: Vec<super::super::food::IncludeParam>
+      ) -> Self {
+        Self::Include(args, nested_selections)
+      }
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        let mut selection = ::prisma_client_rust::Selection::builder("foods");
+        match self {
+          Self::Select(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            let mut nested_selections = super::super::food::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch(args) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(super::super::food::_outputs());
+          }
+        }
+        selection.build()
+      }
+    }
+  }
+  pub mod receipts {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn some(value: Vec<super::super::receipt::WhereParam>) -> WhereParam {
+      WhereParam::ReceiptsSome(value)
+    }
+    pub fn every(value: Vec<super::super::receipt::WhereParam>) -> WhereParam {
+      WhereParam::ReceiptsEvery(value)
+    }
+    pub fn none(value: Vec<super::super::receipt::WhereParam>) -> WhereParam {
+      WhereParam::ReceiptsNone(value)
+    }
+    pub struct Fetch(pub super::super::receipt::ManyArgs);
+    impl Fetch {
+      pub fn with(
+        mut self,
+        params: impl Into<super::super::receipt::WithParam>
+      ) -> Self {
+        self.0 = self.0.with(params.into());
+        self
+      }
+      pub fn order_by(
+        mut self,
+        param: super::super::receipt::OrderByParam
+      ) -> Self {
+        self.0 = self.0.order_by(param);
+        self
+      }
+      pub fn skip(mut self, value: i64) -> Self {
+        self.0 = self.0.skip(value);
+        self
+      }
+      pub fn take(mut self, value: i64) -> Self {
+        self.0 = self.0.take(value);
+        self
+      }
+      pub fn cursor(
+        mut self,
+        value: super::super::receipt::UniqueWhereParam
+      ) -> Self {
+        self.0 = self.0.cursor(value.into());
+        self
+      }
+    }
+    impl From<Fetch> for WithParam {
+      fn from(fetch: Fetch) -> Self {
+        WithParam::Receipts(fetch.0)
+      }
+    }
+    pub fn fetch(params: Vec<super::super::receipt::WhereParam>) -> Fetch {
+      Fetch(super::super::receipt::ManyArgs::new(params))
+    }
+    pub fn connect<T: From<Connect>>(
+      params: Vec<super::super::receipt::UniqueWhereParam>
+    ) -> T {
+      Connect(params).into()
+    }
+    pub fn disconnect(
+      params: Vec<super::super::receipt::UniqueWhereParam>
+    ) -> SetParam {
+      SetParam::DisconnectReceipts(params)
+    }
+    pub fn set(
+      params: Vec<super::super::receipt::UniqueWhereParam>
+    ) -> SetParam {
+      SetParam::SetReceipts(params)
+    }
+    pub struct Connect(pub Vec<super::super::receipt::UniqueWhereParam>);
+    impl From<Connect> for SetParam {
+      fn from(value: Connect) -> Self {
+        Self::ConnectReceipts(value.0)
+      }
+    }
+    pub enum Include {
+      Select(
+        super::super::receipt::ManyArgs,
+        Vec<super::super::receipt::SelectParam>
+      ),
+      Include(
+        super::super::receipt::ManyArgs,
+        Vec<super::super::receipt::IncludeParam>
+      ),
+      Fetch(super::super::receipt::ManyArgs)
+    }
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Receipts(self)
+      }
+    }
+    impl Include {
+      pub fn select(
+        args: super::super::receipt::ManyArgs,
+        nested_selections: Vec<super::super::receipt::SelectParam>
+      ) -> Self {
+        Self::Select(args, nested_selections)
+      }
+      pub fn include(
+        args: super::super::receipt::ManyArgs,
+        nested_selections: Vec<super::super::receipt::IncludeParam>
+      ) -> Self {
+        Self::Include(args, nested_selections)
+      }
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        let mut selection =
+          ::prisma_client_rust::Selection::builder("receipts");
+        match self {
+          Self::Select(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            let mut nested_selections = super::super::receipt::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch(args) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(super::super::receipt::_outputs());
+          }
+        }
+        selection.build()
+      }
+    }
+    pub enum Select {
+      Select(
+        super::super::receipt::ManyArgs,
+        Vec<super::super::receipt::SelectParam>
+      ),
+      Include(
+        super::super::receipt::ManyArgs,
+        Vec<super::super::receipt::IncludeParam>
+      ),
+      Fetch(super::super::receipt::ManyArgs)
+    }
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Receipts(self)
+      }
+    }
+    impl Select {
+      pub fn select(
+        args: super::super::receipt::ManyArgs,
+        nested_selections: Vec<super::super::receipt::SelectParam>
+      ) -> Self {
+        Self::Select(args, nested_selections)
+      }
+      pub fn include(
+        args: super::super::receipt::ManyArgs,
+        nested_selections: Vec<super::super::receipt::IncludeParam>
+      ) -> Self {
+        Self::Include(args, nested_selections)
+      }
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        let mut selection =
+          ::prisma_client_rust::Selection::builder("receipts");
+        match self {
+          Self::Select(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            let mut nested_selections = super::super::receipt::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch(args) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(super::super::receipt::_outputs());
+          }
+        }
+        selection.build()
+      }
+    }
+  }
+  pub fn _outputs() -> Vec<::prisma_client_rust::Selection> {
+    ["id", "name"]
+      .into_iter()
+      .map(|o| {
+        let builder = ::prisma_client_rust::Selection::builder(o);
+        builder.build()
+      })
+      .collect()
+  }
+  pub fn create(
+    name: String,
+    _params: Vec<SetParam>
+  ) -> (String, Vec<SetParam>) {
+    (name, _params)
+  }
+  #[macro_export]
+  macro_rules ! _select_store { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: store :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: store :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Select { Select ($ crate :: prisma :: store :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: store :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: store :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Select ($ crate :: prisma :: store :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , name , foods , receipts } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug)] pub struct Data { $ (pub $ field : $ crate :: prisma :: store :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (name) , stringify ! (foods) , stringify ! (receipts)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: store :: select ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: store :: select ! (@ field_serde_name ; $ field) , ", ") , +)) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: store :: select ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: store :: select ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: store :: select ! (@ field_serde_name ; $ field))) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "name" , "foods" , "receipts"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: store :: select ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { i32 } ; (@ field_type ; name) => { String } ; (@ field_type ; foods : $ selection_mode : ident { $ ($ selections : tt) + }) => { Vec < foods :: Data > } ; (@ field_type ; foods) => { Vec < $ crate :: prisma :: food :: Data > } ; (@ field_type ; receipts : $ selection_mode : ident { $ ($ selections : tt) + }) => { Vec < receipts :: Data > } ; (@ field_type ; receipts) => { Vec < $ crate :: prisma :: receipt :: Data > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot select field nonexistent field $ field on model "Store" , available fields are "id, name, foods, receipts")) } ; (@ field_module ; foods : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: food :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; receipts : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: receipt :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < $ crate :: prisma :: store :: SelectParam > :: into ($ crate :: prisma :: store :: id :: Select) } ; (@ selection_field_to_selection_param ; name) => { Into :: < $ crate :: prisma :: store :: SelectParam > :: into ($ crate :: prisma :: store :: name :: Select) } ; (@ selection_field_to_selection_param ; foods $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? : $ selection_mode : ident { $ ($ selections : tt) + }) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: food :: ManyArgs :: new ($ crate :: prisma :: store :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: store :: SelectParam > :: into ($ crate :: prisma :: store :: foods :: Select :: $ selection_mode (args , $ crate :: prisma :: food :: $ selection_mode ! (@ selections_to_select_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } } ; (@ selection_field_to_selection_param ; foods $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ?) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: food :: ManyArgs :: new ($ crate :: prisma :: store :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: store :: SelectParam > :: into ($ crate :: prisma :: store :: foods :: Select :: Fetch (args)) } } ; (@ selection_field_to_selection_param ; receipts $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? : $ selection_mode : ident { $ ($ selections : tt) + }) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: receipt :: ManyArgs :: new ($ crate :: prisma :: store :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: store :: SelectParam > :: into ($ crate :: prisma :: store :: receipts :: Select :: $ selection_mode (args , $ crate :: prisma :: receipt :: $ selection_mode ! (@ selections_to_select_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } } ; (@ selection_field_to_selection_param ; receipts $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ?) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: receipt :: ManyArgs :: new ($ crate :: prisma :: store :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: store :: SelectParam > :: into ($ crate :: prisma :: store :: receipts :: Select :: Fetch (args)) } } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_select_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: store :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; name) => { "name" } ; (@ field_serde_name ; foods) => { "foods" } ; (@ field_serde_name ; receipts) => { "receipts" } ; }
+  pub use _select_store as select;
+  pub enum SelectParam {
+    Id(id::Select),
+    Name(name::Select),
+    Foods(foods::Select),
+    Receipts(receipts::Select)
+  }
+  impl SelectParam {
+    pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+      match self {
+        Self::Id(data) => data.to_selection(),
+        Self::Name(data) => data.to_selection(),
+        Self::Foods(data) => data.to_selection(),
+        Self::Receipts(data) => data.to_selection()
+      }
+    }
+  }
+  #[macro_export]
+  macro_rules ! _include_store { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: store :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: store :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Include { let mut selections = $ crate :: prisma :: store :: _outputs () ; selections . extend ($ crate :: prisma :: store :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; Include (selections) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: store :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: store :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Include ({ let mut selections = $ crate :: prisma :: store :: _outputs () ; selections . extend ($ crate :: prisma :: store :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; selections }) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { foods , receipts } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug)] pub struct Data { pub id : i32 , pub name : String , $ (pub $ field : $ crate :: prisma :: store :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (name)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: store :: include ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . serialize_field ($ crate :: prisma :: store :: include ! (@ field_serde_name ; id) , & self . id) ? ; state . serialize_field ($ crate :: prisma :: store :: include ! (@ field_serde_name ; name) , & self . name) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , name } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: store :: include ! (@ field_serde_name ; $ field) , ", ") , + , $ crate :: prisma :: store :: include ! (@ field_serde_name ; id) , ", " , $ crate :: prisma :: store :: include ! (@ field_serde_name ; name) , ", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: store :: include ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , $ crate :: prisma :: store :: include ! (@ field_serde_name ; id) => Ok (Field :: id) , $ crate :: prisma :: store :: include ! (@ field_serde_name ; name) => Ok (Field :: name) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut name = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: store :: include ! (@ field_serde_name ; id))) ; } id = Some (map . next_value () ?) ; } Field :: name => { if name . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: store :: include ! (@ field_serde_name ; name))) ; } name = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: store :: include ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: store :: include ! (@ field_serde_name ; $ field))) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: store :: include ! (@ field_serde_name ; id))) ? ; let name = name . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: store :: include ! (@ field_serde_name ; name))) ? ; Ok (Data { id , name , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "name" , "foods" , "receipts"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: store :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; foods : $ selection_mode : ident { $ ($ selections : tt) + }) => { Vec < foods :: Data > } ; (@ field_type ; foods) => { Vec < $ crate :: prisma :: food :: Data > } ; (@ field_type ; receipts : $ selection_mode : ident { $ ($ selections : tt) + }) => { Vec < receipts :: Data > } ; (@ field_type ; receipts) => { Vec < $ crate :: prisma :: receipt :: Data > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "Store" , available relations are "foods, receipts")) } ; (@ field_module ; foods : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: food :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; receipts : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: receipt :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; foods $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? : $ selection_mode : ident { $ ($ selections : tt) + }) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: food :: ManyArgs :: new ($ crate :: prisma :: store :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: store :: IncludeParam > :: into ($ crate :: prisma :: store :: foods :: Include :: $ selection_mode (args , $ crate :: prisma :: food :: $ selection_mode ! (@ selections_to_include_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } } ; (@ selection_field_to_selection_param ; foods $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ?) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: food :: ManyArgs :: new ($ crate :: prisma :: store :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: store :: IncludeParam > :: into ($ crate :: prisma :: store :: foods :: Include :: Fetch (args)) } } ; (@ selection_field_to_selection_param ; receipts $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? : $ selection_mode : ident { $ ($ selections : tt) + }) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: receipt :: ManyArgs :: new ($ crate :: prisma :: store :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: store :: IncludeParam > :: into ($ crate :: prisma :: store :: receipts :: Include :: $ selection_mode (args , $ crate :: prisma :: receipt :: $ selection_mode ! (@ selections_to_include_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } } ; (@ selection_field_to_selection_param ; receipts $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ?) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: receipt :: ManyArgs :: new ($ crate :: prisma :: store :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: store :: IncludeParam > :: into ($ crate :: prisma :: store :: receipts :: Include :: Fetch (args)) } } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_include_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: store :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; name) => { "name" } ; (@ field_serde_name ; foods) => { "foods" } ; (@ field_serde_name ; receipts) => { "receipts" } ; }
+  pub use _include_store as include;
+  pub enum IncludeParam {
+    Id(id::Include),
+    Name(name::Include),
+    Foods(foods::Include),
+    Receipts(receipts::Include)
+  }
+  impl IncludeParam {
+    pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+      match self {
+        Self::Id(data) => data.to_selection(),
+        Self::Name(data) => data.to_selection(),
+        Self::Foods(data) => data.to_selection(),
+        Self::Receipts(data) => data.to_selection()
+      }
+    }
+  }
+  #[derive(Debug, Clone, :: serde :: Serialize, :: serde :: Deserialize)]
+  pub struct Data {
+    #[serde(rename = "id")]
+    pub id: i32,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "foods")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foods: Option<Vec<super::food::Data>>,
+    #[serde(rename = "receipts")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipts: Option<Vec<super::receipt::Data>>
+  }
+  impl Data {
+    pub fn foods(
+      &self
+    ) -> Result<
+      &Vec<super::food::Data>,
+      ::prisma_client_rust::RelationNotFetchedError
+    > {
+      self.foods.as_ref().ok_or(
+        ::prisma_client_rust::RelationNotFetchedError::new(stringify!(foods))
+      )
+    }
+    pub fn receipts(
+      &self
+    ) -> Result<
+      &Vec<super::receipt::Data>,
+      ::prisma_client_rust::RelationNotFetchedError
+    > {
+      self.receipts.as_ref().ok_or(
+        ::prisma_client_rust::RelationNotFetchedError::new(stringify!(
+          receipts
+        ))
+      )
+    }
+  }
+  #[derive(Clone)]
+  pub enum WithParam {
+    Foods(super::food::ManyArgs),
+    Receipts(super::receipt::ManyArgs)
+  }
+  impl Into<::prisma_client_rust::Selection> for WithParam {
+    fn into(self) -> ::prisma_client_rust::Selection {
+      match self {
+        Self::Foods(args) => {
+          let (arguments, mut nested_selections) = args.to_graphql();
+          nested_selections.extend(super::food::_outputs());
+          ::prisma_client_rust::Selection::builder("foods")
+            .nested_selections(nested_selections)
+            .set_arguments(arguments)
+            .build()
+        }
+        Self::Receipts(args) => {
+          let (arguments, mut nested_selections) = args.to_graphql();
+          nested_selections.extend(super::receipt::_outputs());
+          ::prisma_client_rust::Selection::builder("receipts")
+            .nested_selections(nested_selections)
+            .set_arguments(arguments)
+            .build()
+        }
+      }
+    }
+  }
+  #[derive(Clone)]
+  pub enum SetParam {
+    SetId(i32),
+    IncrementId(i32),
+    DecrementId(i32),
+    MultiplyId(i32),
+    DivideId(i32),
+    SetName(String),
+    ConnectFoods(Vec<super::food::UniqueWhereParam>),
+    DisconnectFoods(Vec<super::food::UniqueWhereParam>),
+    SetFoods(Vec<super::food::UniqueWhereParam>),
+    ConnectReceipts(Vec<super::receipt::UniqueWhereParam>),
+    DisconnectReceipts(Vec<super::receipt::UniqueWhereParam>),
+    SetReceipts(Vec<super::receipt::UniqueWhereParam>)
+  }
+  impl Into<(String, ::prisma_client_rust::PrismaValue)> for SetParam {
+    fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+      match self {
+        SetParam::SetId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Int(value as i64)
+        ),
+        SetParam::IncrementId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "increment".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::DecrementId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "decrement".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::MultiplyId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "multiply".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::DivideId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "divide".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::SetName(value) => (
+          "name".to_string(),
+          ::prisma_client_rust::PrismaValue::String(value)
+        ),
+        SetParam::ConnectFoods(where_params) => (
+          "foods".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "connect".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              where_params
+                .into_iter()
+                .map(Into::<super::food::WhereParam>::into)
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                .collect()
+            )
+          )])
+        ),
+        SetParam::DisconnectFoods(where_params) => (
+          "foods".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "disconnect".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              where_params
+                .into_iter()
+                .map(Into::<super::food::WhereParam>::into)
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                .collect()
+            )
+          )])
+        ),
+        SetParam::SetFoods(where_params) => (
+          "foods".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "set".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              where_params
+                .into_iter()
+                .map(Into::<super::food::WhereParam>::into)
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                .collect()
+            )
+          )])
+        ),
+        SetParam::ConnectReceipts(where_params) => (
+          "receipts".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "connect".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              where_params
+                .into_iter()
+                .map(Into::<super::receipt::WhereParam>::into)
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                .collect()
+            )
+          )])
+        ),
+        SetParam::DisconnectReceipts(where_params) => (
+          "receipts".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "disconnect".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              where_params
+                .into_iter()
+                .map(Into::<super::receipt::WhereParam>::into)
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                .collect()
+            )
+          )])
+        ),
+        SetParam::SetReceipts(where_params) => (
+          "receipts".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "set".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              where_params
+                .into_iter()
+                .map(Into::<super::receipt::WhereParam>::into)
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                .collect()
+            )
+          )])
+        )
+      }
+    }
+  }
+  #[derive(Clone)]
+  pub enum OrderByParam {
+    Id(::prisma_client_rust::Direction),
+    Name(::prisma_client_rust::Direction)
+  }
+  impl Into<(String, ::prisma_client_rust::PrismaValue)> for OrderByParam {
+    fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+      match self {
+        Self::Id(direction) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        ),
+        Self::Name(direction) => (
+          "name".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        )
+      }
+    }
+  }
+  #[derive(Clone)]
+  pub enum WhereParam {
+    Not(Vec<WhereParam>),
+    Or(Vec<WhereParam>),
+    And(Vec<WhereParam>),
+    IdEquals(i32),
+    IdInVec(Vec<i32>),
+    IdNotInVec(Vec<i32>),
+    IdLt(i32),
+    IdLte(i32),
+    IdGt(i32),
+    IdGte(i32),
+    IdNot(i32),
+    NameEquals(String),
+    NameInVec(Vec<String>),
+    NameNotInVec(Vec<String>),
+    NameLt(String),
+    NameLte(String),
+    NameGt(String),
+    NameGte(String),
+    NameContains(String),
+    NameStartsWith(String),
+    NameEndsWith(String),
+    NameMode(QueryMode),
+    NameNot(String),
+    FoodsSome(Vec<super::food::WhereParam>),
+    FoodsEvery(Vec<super::food::WhereParam>),
+    FoodsNone(Vec<super::food::WhereParam>),
+    ReceiptsSome(Vec<super::receipt::WhereParam>),
+    ReceiptsEvery(Vec<super::receipt::WhereParam>),
+    ReceiptsNone(Vec<super::receipt::WhereParam>)
+  }
+  impl Into<::prisma_client_rust::SerializedWhere> for WhereParam {
+    fn into(self) -> ::prisma_client_rust::SerializedWhere {
+      match self {
+        Self::Not(value) => ::prisma_client_rust::SerializedWhere::new(
+          "NOT",
+          ::prisma_client_rust::SerializedWhereValue::Object(
+            value
+              .into_iter()
+              .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+              .map(Into::into)
+              .collect()
+          )
+        ),
+        Self::Or(value) => ::prisma_client_rust::SerializedWhere::new(
+          "OR",
+          ::prisma_client_rust::SerializedWhereValue::List(
+            value
+              .into_iter()
+              .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+              .map(Into::into)
+              .map(|v| vec![v])
+              .map(::prisma_client_rust::PrismaValue::Object)
+              .collect()
+          )
+        ),
+        Self::And(value) => ::prisma_client_rust::SerializedWhere::new(
+          "AND",
+          ::prisma_client_rust::SerializedWhereValue::Object(
+            value
+              .into_iter()
+              .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+              .map(Into::into)
+              .collect()
+          )
+        ),
+        Self::IdEquals(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "equals".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "in".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              value
+                .into_iter()
+                .map(|v| ::prisma_client_rust::PrismaValue::Int(v as i64))
+                .collect()
+            )
+          )])
+        ),
+        Self::IdNotInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "notIn".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              value
+                .into_iter()
+                .map(|v| ::prisma_client_rust::PrismaValue::Int(v as i64))
+                .collect()
+            )
+          )])
+        ),
+        Self::IdLt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lt".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdLte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lte".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdGt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gt".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdGte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gte".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdNot(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "not".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::NameEquals(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "equals".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NameInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "in".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              value
+                .into_iter()
+                .map(|v| ::prisma_client_rust::PrismaValue::String(v))
+                .collect()
+            )
+          )])
+        ),
+        Self::NameNotInVec(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "name",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "notIn".to_string(),
+              ::prisma_client_rust::PrismaValue::List(
+                value
+                  .into_iter()
+                  .map(|v| ::prisma_client_rust::PrismaValue::String(v))
+                  .collect()
+              )
+            )])
+          )
+        }
+        Self::NameLt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lt".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NameLte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lte".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NameGt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gt".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NameGte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gte".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NameContains(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "name",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "contains".to_string(),
+              ::prisma_client_rust::PrismaValue::String(value)
+            )])
+          )
+        }
+        Self::NameStartsWith(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "name",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "startsWith".to_string(),
+              ::prisma_client_rust::PrismaValue::String(value)
+            )])
+          )
+        }
+        Self::NameEndsWith(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "name",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "endsWith".to_string(),
+              ::prisma_client_rust::PrismaValue::String(value)
+            )])
+          )
+        }
+        Self::NameMode(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "mode".to_string(),
+            ::prisma_client_rust::PrismaValue::Enum(value.to_string())
+          )])
+        ),
+        Self::NameNot(value) => ::prisma_client_rust::SerializedWhere::new(
+          "name",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "not".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::FoodsSome(value) => ::prisma_client_rust::SerializedWhere::new(
+          "foods",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "some".to_string(),
+            ::prisma_client_rust::PrismaValue::Object(
+              value
+                .into_iter()
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(Into::into)
+                .collect()
+            )
+          )])
+        ),
+        Self::FoodsEvery(value) => ::prisma_client_rust::SerializedWhere::new(
+          "foods",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "every".to_string(),
+            ::prisma_client_rust::PrismaValue::Object(
+              value
+                .into_iter()
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(Into::into)
+                .collect()
+            )
+          )])
+        ),
+        Self::FoodsNone(value) => ::prisma_client_rust::SerializedWhere::new(
+          "foods",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "none".to_string(),
+            ::prisma_client_rust::PrismaValue::Object(
+              value
+                .into_iter()
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(Into::into)
+                .collect()
+            )
+          )])
+        ),
+        Self::ReceiptsSome(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "receipts",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "some".to_string(),
+              ::prisma_client_rust::PrismaValue::Object(
+                value
+                  .into_iter()
+                  .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                  .map(Into::into)
+                  .collect()
+              )
+            )])
+          )
+        }
+        Self::ReceiptsEvery(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "receipts",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "every".to_string(),
+              ::prisma_client_rust::PrismaValue::Object(
+                value
+                  .into_iter()
+                  .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                  .map(Into::into)
+                  .collect()
+              )
+            )])
+          )
+        }
+        Self::ReceiptsNone(value) => ::prisma_client_rust::SerializedWhere::new(
+          "receipts",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "none".to_string(),
+            ::prisma_client_rust::PrismaValue::Object(
+              value
+                .into_iter()
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(Into::into)
+                .collect()
+            )
+          )])
+        )
+      }
+    }
+  }
+  #[derive(Clone)]
+  pub enum UniqueWhereParam {
+    IdEquals(i32),
+    NameEquals(String)
+  }
+  impl From<UniqueWhereParam> for WhereParam {
+    fn from(value: UniqueWhereParam) -> Self {
+      match value {
+        UniqueWhereParam::IdEquals(value) => Self::IdEquals(value),
+        UniqueWhereParam::NameEquals(value) => Self::NameEquals(value)
+      }
+    }
+  }
+  impl From<::prisma_client_rust::Operator<Self>> for WhereParam {
+    fn from(op: ::prisma_client_rust::Operator<Self>) -> Self {
+      match op {
+        ::prisma_client_rust::Operator::Not(value) => Self::Not(value),
+        ::prisma_client_rust::Operator::And(value) => Self::And(value),
+        ::prisma_client_rust::Operator::Or(value) => Self::Or(value)
+      }
+    }
+  }
+  pub type UniqueArgs = ::prisma_client_rust::UniqueArgs<WithParam>;
+  pub type ManyArgs = ::prisma_client_rust::ManyArgs<
+    WhereParam,
+    WithParam,
+    OrderByParam,
+    UniqueWhereParam
+  >;
+  pub type Count<'a> =
+    ::prisma_client_rust::Count<'a, WhereParam, OrderByParam, UniqueWhereParam>;
+  pub type Create<'a> =
+    ::prisma_client_rust::Create<'a, SetParam, WithParam, Data>;
+  pub type CreateMany<'a> = ::prisma_client_rust::CreateMany<'a, SetParam>;
+  pub type FindUnique<'a> =
+    ::prisma_client_rust::FindUnique<'a, WhereParam, WithParam, SetParam, Data>;
+  pub type FindMany<'a> = ::prisma_client_rust::FindMany<
+    'a,
+    WhereParam,
+    WithParam,
+    OrderByParam,
+    UniqueWhereParam,
+    SetParam,
+    Data
+  >;
+  pub type FindFirst<'a> = ::prisma_client_rust::FindFirst<
+    'a,
+    WhereParam,
+    WithParam,
+    OrderByParam,
+    UniqueWhereParam,
+    Data
+  >;
+  pub type Update<'a> =
+    ::prisma_client_rust::Update<'a, WhereParam, WithParam, SetParam, Data>;
+  pub type UpdateMany<'a> =
+    ::prisma_client_rust::UpdateMany<'a, WhereParam, SetParam>;
+  pub type Upsert<'a> =
+    ::prisma_client_rust::Upsert<'a, WhereParam, SetParam, WithParam, Data>;
+  pub type Delete<'a> =
+    ::prisma_client_rust::Delete<'a, WhereParam, WithParam, Data>;
+  pub type DeleteMany<'a> = ::prisma_client_rust::DeleteMany<'a, WhereParam>;
+  pub struct Actions<'a> {
+    pub client: &'a PrismaClient
+  }
+  impl<'a> Actions<'a> {
+    pub fn find_unique(self, _where: UniqueWhereParam) -> FindUnique<'a> {
+      FindUnique::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Store", _outputs()),
+        _where.into()
+      )
+    }
+    pub fn find_first(self, _where: Vec<WhereParam>) -> FindFirst<'a> {
+      FindFirst::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Store", _outputs()),
+        _where
+      )
+    }
+    pub fn find_many(self, _where: Vec<WhereParam>) -> FindMany<'a> {
+      FindMany::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Store", _outputs()),
+        _where
+      )
+    }
+    pub fn create(
+      self,
+      name: String,
+      mut _params: Vec<SetParam>
+    ) -> Create<'a> {
+      _params.push(name::set(name));
+      Create::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Store", _outputs()),
+        _params
+      )
+    }
+    pub fn create_many(
+      self,
+      data: Vec<(String, Vec<SetParam>)>
+    ) -> CreateMany<'a> {
+      let data = data
+        .into_iter()
+        .map(|(name, mut _params)| {
+          _params.push(name::set(name));
+          _params
+        })
+        .collect();
+      CreateMany::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Store", _outputs()),
+        data
+      )
+    }
+    pub fn update(
+      self,
+      _where: UniqueWhereParam,
+      _params: Vec<SetParam>
+    ) -> Update<'a> {
+      Update::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Store", _outputs()),
+        _where.into(),
+        _params,
+        vec![]
+      )
+    }
+    pub fn update_many(
+      self,
+      _where: Vec<WhereParam>,
+      _params: Vec<SetParam>
+    ) -> UpdateMany<'a> {
+      UpdateMany::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Store", _outputs()),
+        _where,
+        _params
+      )
+    }
+    pub fn upsert(
+      self,
+      _where: UniqueWhereParam,
+      (name, mut _params): (String, Vec<SetParam>),
+      _update: Vec<SetParam>
+    ) -> Upsert<'a> {
+      _params.push(name::set(name));
+      Upsert::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Store", _outputs()),
+        _where.into(),
+        _params,
+        _update
+      )
+    }
+    pub fn delete(self, _where: UniqueWhereParam) -> Delete<'a> {
+      Delete::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Store", _outputs()),
+        _where.into(),
+        vec![]
+      )
+    }
+    pub fn delete_many(self, _where: Vec<WhereParam>) -> DeleteMany<'a> {
+      DeleteMany::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Store", _outputs()),
+        _where.into()
+      )
+    }
+    pub fn count(self, _where: Vec<WhereParam>) -> Count<'a> {
+      Count::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Store", _outputs()),
+        vec![]
+      )
+    }
+  }
+}
+pub mod receipt {
+  use super::_prisma::*;
+  use super::*;
+  pub mod id {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: i32) -> T {
+      Set(value).into()
+    }
+    pub fn equals<T: From<UniqueWhereParam>>(value: i32) -> T {
+      UniqueWhereParam::IdEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::Id(direction)
+    }
+    pub fn in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::IdInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::IdNotInVec(value)
+    }
+    pub fn lt(value: i32) -> WhereParam {
+      WhereParam::IdLt(value)
+    }
+    pub fn lte(value: i32) -> WhereParam {
+      WhereParam::IdLte(value)
+    }
+    pub fn gt(value: i32) -> WhereParam {
+      WhereParam::IdGt(value)
+    }
+    pub fn gte(value: i32) -> WhereParam {
+      WhereParam::IdGte(value)
+    }
+    pub fn not(value: i32) -> WhereParam {
+      WhereParam::IdNot(value)
+    }
+    pub fn increment(value: i32) -> SetParam {
+      SetParam::IncrementId(value)
+    }
+    pub fn decrement(value: i32) -> SetParam {
+      SetParam::DecrementId(value)
+    }
+    pub fn multiply(value: i32) -> SetParam {
+      SetParam::MultiplyId(value)
+    }
+    pub fn divide(value: i32) -> SetParam {
+      SetParam::DivideId(value)
+    }
+    pub struct Set(pub i32);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetId(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Id(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("id").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Id(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("id").build()
+      }
+    }
+  }
+  pub mod date {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> T {
+      Set(value).into()
+    }
+    pub fn equals(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> WhereParam {
+      WhereParam::DateEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::Date(direction)
+    }
+    pub fn in_vec(
+      value: Vec<
+        ::prisma_client_rust::chrono::DateTime<
+          ::prisma_client_rust::chrono::FixedOffset
+        >
+      >
+    ) -> WhereParam {
+      WhereParam::DateInVec(value)
+    }
+    pub fn not_in_vec(
+      value: Vec<
+        ::prisma_client_rust::chrono::DateTime<
+          ::prisma_client_rust::chrono::FixedOffset
+        >
+      >
+    ) -> WhereParam {
+      WhereParam::DateNotInVec(value)
+    }
+    pub fn lt(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> WhereParam {
+      WhereParam::DateLt(value)
+    }
+    pub fn lte(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> WhereParam {
+      WhereParam::DateLte(value)
+    }
+    pub fn gt(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> WhereParam {
+      WhereParam::DateGt(value)
+    }
+    pub fn gte(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> WhereParam {
+      WhereParam::DateGte(value)
+    }
+    pub fn not(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> WhereParam {
+      WhereParam::DateNot(value)
+    }
+    pub struct Set(
+      pub  ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    );
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetDate(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Date(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("date").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Date(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("date").build()
+      }
+    }
+  }
+  pub mod store_id {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: Option<i32>) -> T {
+      Set(value).into()
+    }
+    pub fn equals(value: Option<i32>) -> WhereParam {
+      WhereParam::StoreIdEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::StoreId(direction)
+    }
+    pub fn in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::StoreIdInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::StoreIdNotInVec(value)
+    }
+    pub fn lt(value: i32) -> WhereParam {
+      WhereParam::StoreIdLt(value)
+    }
+    pub fn lte(value: i32) -> WhereParam {
+      WhereParam::StoreIdLte(value)
+    }
+    pub fn gt(value: i32) -> WhereParam {
+      WhereParam::StoreIdGt(value)
+    }
+    pub fn gte(value: i32) -> WhereParam {
+      WhereParam::StoreIdGte(value)
+    }
+    pub fn not(value: Option<i32>) -> WhereParam {
+      WhereParam::StoreIdNot(value)
+    }
+    pub fn increment(value: i32) -> SetParam {
+      SetParam::IncrementStoreId(value)
+    }
+    pub fn decrement(value: i32) -> SetParam {
+      SetParam::DecrementStoreId(value)
+    }
+    pub fn multiply(value: i32) -> SetParam {
+      SetParam::MultiplyStoreId(value)
+    }
+    pub fn divide(value: i32) -> SetParam {
+      SetParam::DivideStoreId(value)
+    }
+    pub struct Set(pub Option<i32>);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetStoreId(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::StoreId(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("store_id").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::StoreId(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("store_id").build()
+      }
+    }
+  }
+  pub mod store {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn is(value: Vec<super::super::store::WhereParam>) -> WhereParam {
+      WhereParam::StoreIs(value)
+    }
+    pub fn is_not(value: Vec<super::super::store::WhereParam>) -> WhereParam {
+      WhereParam::StoreIsNot(value)
+    }
+    pub struct Fetch(pub super::super::store::UniqueArgs);
+    impl Fetch {
+      pub fn with(
+        mut self,
+        params: impl Into<super::super::store::WithParam>
+      ) -> Self {
+        self.0 = self.0.with(params.into());
+        self
+      }
+    }
+    impl From<Fetch> for WithParam {
+      fn from(fetch: Fetch) -> Self {
+        WithParam::Store(fetch.0)
+      }
+    }
+    pub fn fetch() -> Fetch {
+      Fetch(super::super::store::UniqueArgs::new())
+    }
+    pub fn connect<T: From<Connect>>(
+      value: super::super::store::UniqueWhereParam
+    ) -> T {
+      Connect(value).into()
+    }
+    pub fn disconnect() -> SetParam {
+      SetParam::DisconnectStore
+    }
+    pub struct Connect(super::super::store::UniqueWhereParam);
+    impl From<Connect> for SetParam {
+      fn from(value: Connect) -> Self {
+        Self::ConnectStore(value.0)
+      }
+    }
+    pub enum Include {
+      Select(Vec<super::super::store::SelectParam>),
+      Include(Vec<super::super::store::IncludeParam>),
+      Fetch
+    }
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Store(self)
+      }
+    }
+    impl Include {
+      pub fn select(
+        nested_selections: Vec<super::super::store::SelectParam>
+      ) -> Self {
+        Self::Select(nested_selections)
+      }
+      pub fn include(
+        nested_selections: Vec<super::super::store::IncludeParam>
+      ) -> Self {
+        Self::Include(nested_selections)
+      }
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        let mut selection = ::prisma_client_rust::Selection::builder("store");
+        match self {
+          Self::Select(selections) => {
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(selections) => {
+            let mut nested_selections = super::super::store::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch => {
+            selection.nested_selections(super::super::store::_outputs());
+          }
+        }
+        selection.build()
+      }
+    }
+    pub enum Select {
+      Select(Vec<super::super::store::SelectParam>),
+      Include(Vec<super::super::store::IncludeParam>),
+      Fetch
+    }
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Store(self)
+      }
+    }
+    impl Select {
+      pub fn select(
+        nested_selections: Vec<super::super::store::SelectParam>
+      ) -> Self {
+        Self::Select(nested_selections)
+      }
+      pub fn include(
+        nested_selections: Vec<super::super::store::IncludeParam>
+      ) -> Self {
+        Self::Include(nested_selections)
+      }
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        let mut selection = ::prisma_client_rust::Selection::builder("store");
+        match self {
+          Self::Select(selections) => {
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(selections) => {
+            let mut nested_selections = super::super::store::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch => {
+            selection.nested_selections(super::super::store::_outputs());
+          }
+        }
+        selection.build()
+      }
+    }
+  }
+  pub mod total {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: Option<i32>) -> T {
+      Set(value).into()
+    }
+    pub fn equals(value: Option<i32>) -> WhereParam {
+      WhereParam::TotalEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::Total(direction)
+    }
+    pub fn in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::TotalInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::TotalNotInVec(value)
+    }
+    pub fn lt(value: i32) -> WhereParam {
+      WhereParam::TotalLt(value)
+    }
+    pub fn lte(value: i32) -> WhereParam {
+      WhereParam::TotalLte(value)
+    }
+    pub fn gt(value: i32) -> WhereParam {
+      WhereParam::TotalGt(value)
+    }
+    pub fn gte(value: i32) -> WhereParam {
+      WhereParam::TotalGte(value)
+    }
+    pub fn not(value: Option<i32>) -> WhereParam {
+      WhereParam::TotalNot(value)
+    }
+    pub fn increment(value: i32) -> SetParam {
+      SetParam::IncrementTotal(value)
+    }
+    pub fn decrement(value: i32) -> SetParam {
+      SetParam::DecrementTotal(value)
+    }
+    pub fn multiply(value: i32) -> SetParam {
+      SetParam::MultiplyTotal(value)
+    }
+    pub fn divide(value: i32) -> SetParam {
+      SetParam::DivideTotal(value)
+    }
+    pub struct Set(pub Option<i32>);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetTotal(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Total(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("total").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Total(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("total").build()
+      }
+    }
+  }
+  pub mod notes {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: String) -> T {
+      Set(value).into()
+    }
+    pub fn equals(value: String) -> WhereParam {
+      WhereParam::NotesEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::Notes(direction)
+    }
+    pub fn in_vec(value: Vec<String>) -> WhereParam {
+      WhereParam::NotesInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<String>) -> WhereParam {
+      WhereParam::NotesNotInVec(value)
+    }
+    pub fn lt(value: String) -> WhereParam {
+      WhereParam::NotesLt(value)
+    }
+    pub fn lte(value: String) -> WhereParam {
+      WhereParam::NotesLte(value)
+    }
+    pub fn gt(value: String) -> WhereParam {
+      WhereParam::NotesGt(value)
+    }
+    pub fn gte(value: String) -> WhereParam {
+      WhereParam::NotesGte(value)
+    }
+    pub fn contains(value: String) -> WhereParam {
+      WhereParam::NotesContains(value)
+    }
+    pub fn starts_with(value: String) -> WhereParam {
+      WhereParam::NotesStartsWith(value)
+    }
+    pub fn ends_with(value: String) -> WhereParam {
+      WhereParam::NotesEndsWith(value)
+    }
+    pub fn mode(value: QueryMode) -> WhereParam {
+      WhereParam::NotesMode(value)
+    }
+    pub fn not(value: String) -> WhereParam {
+      WhereParam::NotesNot(value)
+    }
+    pub struct Set(pub String);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetNotes(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Notes(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("notes").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Notes(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("notes").build()
+      }
+    }
+  }
+  pub mod foods {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn some(value: Vec<super::super::food::WhereParam>) -> WhereParam {
+      WhereParam::FoodsSome(value)
+    }
+    pub fn every(value: Vec<super::super::food::WhereParam>) -> WhereParam {
+      WhereParam::FoodsEvery(value)
+    }
+    pub fn none(value: Vec<super::super::food::WhereParam>) -> WhereParam {
+      WhereParam::FoodsNone(value)
+    }
+    pub struct Fetch(pub super::super::food::ManyArgs);
+    impl Fetch {
+      pub fn with(
+        mut self,
+        params: impl Into<super::super::food::WithParam>
+      ) -> Self {
+        self.0 = self.0.with(params.into());
+        self
+      }
+      pub fn order_by(
+        mut self,
+        param: super::super::food::OrderByParam
+      ) -> Self {
+        self.0 = self.0.order_by(param);
+        self
+      }
+      pub fn skip(mut self, value: i64) -> Self {
+        self.0 = self.0.skip(value);
+        self
+      }
+      pub fn take(mut self, value: i64) -> Self {
+        self.0 = self.0.take(value);
+        self
+      }
+      pub fn cursor(
+        mut self,
+        value: super::super::food::UniqueWhereParam
+      ) -> Self {
+        self.0 = self.0.cursor(value.into());
+        self
+      }
+    }
+    impl From<Fetch> for WithParam {
+      fn from(fetch: Fetch) -> Self {
+        WithParam::Foods(fetch.0)
+      }
+    }
+    pub fn fetch(params: Vec<super::super::food::WhereParam>) -> Fetch {
+      Fetch(super::super::food::ManyArgs::new(params))
+    }
+    pub fn connect<T: From<Connect>>(
+      params: Vec<super::super::food::UniqueWhereParam>
+    ) -> T {
+      Connect(params).into()
+    }
+    pub fn disconnect(
+      params: Vec<super::super::food::UniqueWhereParam>
+    ) -> SetParam {
+      SetParam::DisconnectFoods(params)
+    }
+    pub fn set(params: Vec<super::super::food::UniqueWhereParam>) -> SetParam {
+      SetParam::SetFoods(params)
+    }
+    pub struct Connect(pub Vec<super::super::food::UniqueWhereParam>);
+    impl From<Connect> for SetParam {
+      fn from(value: Connect) -> Self {
+        Self::ConnectFoods(value.0)
+      }
+    }
+    pub enum Include {
+      Select(
+        super::super::food::ManyArgs,
+        Vec<super::super::food::SelectParam>
+      ),
+      Include(
+        super::super::food::ManyArgs,
+        Vec<super::super::food::IncludeParam>
+      ),
+      Fetch(super::super::food::ManyArgs)
+    }
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Foods(self)
+      }
+    }
+    impl Include {
+      pub fn select(
+        args: super::super::food::ManyArgs,
+        nested_selections: Vec<super::super::food::SelectParam>
+      ) -> Self {
+        Self::Select(args, nested_selections)
+      }
+      pub fn include(
+        args: super::super::food::ManyArgs,
+        nested_selections: Vec<super::super::food::IncludeParam>
+      ) -> Self {
+        Self::Include(args, nested_selections)
+      }
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        let mut selection = ::prisma_client_rust::Selection::builder("foods");
+        match self {
+          Self::Select(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            let mut nested_selections = super::super::food::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch(args) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(super::super::food::_outputs());
+          }
+        }
+        selection.build()
+      }
+    }
+    pub enum Select {
+      Select(
+        super::super::food::ManyArgs,
+        Vec<super::super::food::SelectParam>
+      ),
+      Include(
+        super::super::food::ManyArgs,
+        Vec<super::super::food::IncludeParam>
+      ),
+      Fetch(super::super::food::ManyArgs)
+    }
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Foods(self)
+      }
+    }
+    impl Select {
+      pub fn select(
+        args: super::super::food::ManyArgs,
+        nested_selections: Vec<super::super::food::SelectParam>
+      ) -> Self {
+        Self::Select(args, nested_selections)
+      }
+      pub fn include(
+        args: super::super::food::ManyArgs,
+        nested_selections: Vec<super::super::food::IncludeParam>
+      ) -> Self {
+        Self::Include(args, nested_selections)
+      }
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        let mut selection = ::prisma_client_rust::Selection::builder("foods");
+        match self {
+          Self::Select(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(args, selections) => {
+            selection.set_arguments(args.to_graphql().0);
+            let mut nested_selections = super::super::food::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch(args) => {
+            selection.set_arguments(args.to_graphql().0);
+            selection.nested_selections(super::super::food::_outputs());
+          }
+        }
+        selection.build()
+      }
+    }
+  }
+  pub fn _outputs() -> Vec<::prisma_client_rust::Selection> {
+    ["id", "date", "store_id", "total", "notes"]
+      .into_iter()
+      .map(|o| {
+        let builder = ::prisma_client_rust::Selection::builder(o);
+        builder.build()
+      })
+      .collect()
+  }
+  pub fn create(
+    date: ::prisma_client_rust::chrono::DateTime<
+      ::prisma_client_rust::chrono::FixedOffset
+    >,
+    _params: Vec<SetParam>
+  ) -> (
+    ::prisma_client_rust::chrono::DateTime<
+      ::prisma_client_rust::chrono::FixedOffset
+    >,
+    Vec<SetParam>
+  ) {
+    (date, _params)
+  }
+  #[macro_export]
+  macro_rules ! _select_receipt { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: receipt :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: receipt :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Select { Select ($ crate :: prisma :: receipt :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: receipt :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: receipt :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Select ($ crate :: prisma :: receipt :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , date , store_id , store , total , notes , foods } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug)] pub struct Data { $ (pub $ field : $ crate :: prisma :: receipt :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (date) , stringify ! (store_id) , stringify ! (store) , stringify ! (total) , stringify ! (notes) , stringify ! (foods)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: receipt :: select ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: receipt :: select ! (@ field_serde_name ; $ field) , ", ") , +)) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: receipt :: select ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: receipt :: select ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: receipt :: select ! (@ field_serde_name ; $ field))) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "date" , "store_id" , "store" , "total" , "notes" , "foods"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: receipt :: select ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { i32 } ; (@ field_type ; date) => { :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > } ; (@ field_type ; store_id) => { Option < i32 > } ; (@ field_type ; store : $ selection_mode : ident { $ ($ selections : tt) + }) => { Option < store :: Data > } ; (@ field_type ; store) => { Option < $ crate :: prisma :: store :: Data > } ; (@ field_type ; total) => { Option < i32 > } ; (@ field_type ; notes) => { String } ; (@ field_type ; foods : $ selection_mode : ident { $ ($ selections : tt) + }) => { Vec < foods :: Data > } ; (@ field_type ; foods) => { Vec < $ crate :: prisma :: food :: Data > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot select field nonexistent field $ field on model "Receipt" , available fields are "id, date, store_id, store, total, notes, foods")) } ; (@ field_module ; store : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: store :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; foods : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: food :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < $ crate :: prisma :: receipt :: SelectParam > :: into ($ crate :: prisma :: receipt :: id :: Select) } ; (@ selection_field_to_selection_param ; date) => { Into :: < $ crate :: prisma :: receipt :: SelectParam > :: into ($ crate :: prisma :: receipt :: date :: Select) } ; (@ selection_field_to_selection_param ; store_id) => { Into :: < $ crate :: prisma :: receipt :: SelectParam > :: into ($ crate :: prisma :: receipt :: store_id :: Select) } ; (@ selection_field_to_selection_param ; store : $ selection_mode : ident { $ ($ selections : tt) + }) => { Into :: < $ crate :: prisma :: receipt :: SelectParam > :: into ($ crate :: prisma :: receipt :: store :: Select :: $ selection_mode ($ crate :: prisma :: store :: $ selection_mode ! (@ selections_to_select_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } ; (@ selection_field_to_selection_param ; store) => { Into :: < $ crate :: prisma :: receipt :: SelectParam > :: into ($ crate :: prisma :: receipt :: store :: Select :: Fetch) } ; (@ selection_field_to_selection_param ; total) => { Into :: < $ crate :: prisma :: receipt :: SelectParam > :: into ($ crate :: prisma :: receipt :: total :: Select) } ; (@ selection_field_to_selection_param ; notes) => { Into :: < $ crate :: prisma :: receipt :: SelectParam > :: into ($ crate :: prisma :: receipt :: notes :: Select) } ; (@ selection_field_to_selection_param ; foods $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? : $ selection_mode : ident { $ ($ selections : tt) + }) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: food :: ManyArgs :: new ($ crate :: prisma :: receipt :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: receipt :: SelectParam > :: into ($ crate :: prisma :: receipt :: foods :: Select :: $ selection_mode (args , $ crate :: prisma :: food :: $ selection_mode ! (@ selections_to_select_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } } ; (@ selection_field_to_selection_param ; foods $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ?) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: food :: ManyArgs :: new ($ crate :: prisma :: receipt :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: receipt :: SelectParam > :: into ($ crate :: prisma :: receipt :: foods :: Select :: Fetch (args)) } } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_select_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: receipt :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; date) => { "date" } ; (@ field_serde_name ; store_id) => { "store_id" } ; (@ field_serde_name ; store) => { "store" } ; (@ field_serde_name ; total) => { "total" } ; (@ field_serde_name ; notes) => { "notes" } ; (@ field_serde_name ; foods) => { "foods" } ; }
+  pub use _select_receipt as select;
+  pub enum SelectParam {
+    Id(id::Select),
+    Date(date::Select),
+    StoreId(store_id::Select),
+    Store(store::Select),
+    Total(total::Select),
+    Notes(notes::Select),
+    Foods(foods::Select)
+  }
+  impl SelectParam {
+    pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+      match self {
+        Self::Id(data) => data.to_selection(),
+        Self::Date(data) => data.to_selection(),
+        Self::StoreId(data) => data.to_selection(),
+        Self::Store(data) => data.to_selection(),
+        Self::Total(data) => data.to_selection(),
+        Self::Notes(data) => data.to_selection(),
+        Self::Foods(data) => data.to_selection()
+      }
+    }
+  }
+  #[macro_export]
+  macro_rules ! _include_receipt { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: receipt :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: receipt :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Include { let mut selections = $ crate :: prisma :: receipt :: _outputs () ; selections . extend ($ crate :: prisma :: receipt :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; Include (selections) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: receipt :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: receipt :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Include ({ let mut selections = $ crate :: prisma :: receipt :: _outputs () ; selections . extend ($ crate :: prisma :: receipt :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; selections }) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { store , foods } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug)] pub struct Data { pub id : i32 , pub date : :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > , pub store_id : Option < i32 > , pub total : Option < i32 > , pub notes : String , $ (pub $ field : $ crate :: prisma :: receipt :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (date) , stringify ! (store_id) , stringify ! (total) , stringify ! (notes)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . serialize_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; id) , & self . id) ? ; state . serialize_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; date) , & self . date) ? ; state . serialize_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; store_id) , & self . store_id) ? ; state . serialize_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; total) , & self . total) ? ; state . serialize_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; notes) , & self . notes) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , date , store_id , total , notes } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; $ field) , ", ") , + , $ crate :: prisma :: receipt :: include ! (@ field_serde_name ; id) , ", " , $ crate :: prisma :: receipt :: include ! (@ field_serde_name ; date) , ", " , $ crate :: prisma :: receipt :: include ! (@ field_serde_name ; store_id) , ", " , $ crate :: prisma :: receipt :: include ! (@ field_serde_name ; total) , ", " , $ crate :: prisma :: receipt :: include ! (@ field_serde_name ; notes) , ", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , $ crate :: prisma :: receipt :: include ! (@ field_serde_name ; id) => Ok (Field :: id) , $ crate :: prisma :: receipt :: include ! (@ field_serde_name ; date) => Ok (Field :: date) , $ crate :: prisma :: receipt :: include ! (@ field_serde_name ; store_id) => Ok (Field :: store_id) , $ crate :: prisma :: receipt :: include ! (@ field_serde_name ; total) => Ok (Field :: total) , $ crate :: prisma :: receipt :: include ! (@ field_serde_name ; notes) => Ok (Field :: notes) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut date = None ; let mut store_id = None ; let mut total = None ; let mut notes = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; id))) ; } id = Some (map . next_value () ?) ; } Field :: date => { if date . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; date))) ; } date = Some (map . next_value () ?) ; } Field :: store_id => { if store_id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; store_id))) ; } store_id = Some (map . next_value () ?) ; } Field :: total => { if total . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; total))) ; } total = Some (map . next_value () ?) ; } Field :: notes => { if notes . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; notes))) ; } notes = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; $ field))) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; id))) ? ; let date = date . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; date))) ? ; let store_id = store_id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; store_id))) ? ; let total = total . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; total))) ? ; let notes = notes . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: receipt :: include ! (@ field_serde_name ; notes))) ? ; Ok (Data { id , date , store_id , total , notes , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "date" , "store_id" , "store" , "total" , "notes" , "foods"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: receipt :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; store : $ selection_mode : ident { $ ($ selections : tt) + }) => { Option < store :: Data > } ; (@ field_type ; store) => { Option < $ crate :: prisma :: store :: Data > } ; (@ field_type ; foods : $ selection_mode : ident { $ ($ selections : tt) + }) => { Vec < foods :: Data > } ; (@ field_type ; foods) => { Vec < $ crate :: prisma :: food :: Data > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "Receipt" , available relations are "store, foods")) } ; (@ field_module ; store : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: store :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; foods : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: food :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; store : $ selection_mode : ident { $ ($ selections : tt) + }) => { Into :: < $ crate :: prisma :: receipt :: IncludeParam > :: into ($ crate :: prisma :: receipt :: store :: Include :: $ selection_mode ($ crate :: prisma :: store :: $ selection_mode ! (@ selections_to_include_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } ; (@ selection_field_to_selection_param ; store) => { Into :: < $ crate :: prisma :: receipt :: IncludeParam > :: into ($ crate :: prisma :: receipt :: store :: Include :: Fetch) } ; (@ selection_field_to_selection_param ; foods $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? : $ selection_mode : ident { $ ($ selections : tt) + }) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: food :: ManyArgs :: new ($ crate :: prisma :: receipt :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: receipt :: IncludeParam > :: into ($ crate :: prisma :: receipt :: foods :: Include :: $ selection_mode (args , $ crate :: prisma :: food :: $ selection_mode ! (@ selections_to_include_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } } ; (@ selection_field_to_selection_param ; foods $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ?) => { { # [allow (unused_mut)] let mut args = $ crate :: prisma :: food :: ManyArgs :: new ($ crate :: prisma :: receipt :: select ! (@ filters_to_args ; $ ($ ($ filters) +) ?)) ; $ ($ (args = args . $ arg ($ ($ arg_params) *) ;) *) ? Into :: < $ crate :: prisma :: receipt :: IncludeParam > :: into ($ crate :: prisma :: receipt :: foods :: Include :: Fetch (args)) } } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_include_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: receipt :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; date) => { "date" } ; (@ field_serde_name ; store_id) => { "store_id" } ; (@ field_serde_name ; store) => { "store" } ; (@ field_serde_name ; total) => { "total" } ; (@ field_serde_name ; notes) => { "notes" } ; (@ field_serde_name ; foods) => { "foods" } ; }
+  pub use _include_receipt as include;
+  pub enum IncludeParam {
+    Id(id::Include),
+    Date(date::Include),
+    StoreId(store_id::Include),
+    Store(store::Include),
+    Total(total::Include),
+    Notes(notes::Include),
+    Foods(foods::Include)
+  }
+  impl IncludeParam {
+    pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+      match self {
+        Self::Id(data) => data.to_selection(),
+        Self::Date(data) => data.to_selection(),
+        Self::StoreId(data) => data.to_selection(),
+        Self::Store(data) => data.to_selection(),
+        Self::Total(data) => data.to_selection(),
+        Self::Notes(data) => data.to_selection(),
+        Self::Foods(data) => data.to_selection()
+      }
+    }
+  }
+  #[derive(Debug, Clone, :: serde :: Serialize, :: serde :: Deserialize)]
+  pub struct Data {
+    #[serde(rename = "id")]
+    pub id: i32,
+    #[serde(rename = "date")]
+    pub date: ::prisma_client_rust::chrono::DateTime<
+      ::prisma_client_rust::chrono::FixedOffset
+    >,
+    #[serde(rename = "store_id")]
+    pub store_id: Option<i32>,
+    #[serde(rename = "store")]
+    #[serde(default, with = "::prisma_client_rust::serde::double_option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<Option<Box<super::store::Data>>>,
+    #[serde(rename = "total")]
+    pub total: Option<i32>,
+    #[serde(rename = "notes")]
+    pub notes: String,
+    #[serde(rename = "foods")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foods: Option<Vec<super::food::Data>>
+  }
+  impl Data {
+    pub fn store(
+      &self
+    ) -> Result<
+      Option<&super::store::Data>,
+      ::prisma_client_rust::RelationNotFetchedError
+    > {
+      self
+        .store
+        .as_ref()
+        .ok_or(::prisma_client_rust::RelationNotFetchedError::new(
+          stringify!(store)
+        ))
+        .map(|v| v.as_ref().map(|v| v.as_ref()))
+    }
+    pub fn foods(
+      &self
+    ) -> Result<
+      &Vec<super::food::Data>,
+      ::prisma_client_rust::RelationNotFetchedError
+    > {
+      self.foods.as_ref().ok_or(
+        ::prisma_client_rust::RelationNotFetchedError::new(stringify!(foods))
+      )
+    }
+  }
+  #[derive(Clone)]
+  pub enum WithParam {
+    Store(super::store::UniqueArgs),
+    Foods(super::food::ManyArgs)
+  }
+  impl Into<::prisma_client_rust::Selection> for WithParam {
+    fn into(self) -> ::prisma_client_rust::Selection {
+      match self {
+        Self::Store(args) => {
+          let mut selections = super::store::_outputs();
+          selections.extend(
+            args
+              .with_params
+              .into_iter()
+              .map(Into::<::prisma_client_rust::Selection>::into)
+          );
+          ::prisma_client_rust::Selection::builder("store")
+            .nested_selections(selections)
+            .build()
+        }
+        Self::Foods(args) => {
+          let (arguments, mut nested_selections) = args.to_graphql();
+          nested_selections.extend(super::food::_outputs());
+          ::prisma_client_rust::Selection::builder("foods")
+            .nested_selections(nested_selections)
+            .set_arguments(arguments)
+            .build()
+        }
+      }
+    }
+  }
+  #[derive(Clone)]
+  pub enum SetParam {
+    SetId(i32),
+    IncrementId(i32),
+    DecrementId(i32),
+    MultiplyId(i32),
+    DivideId(i32),
+    SetDate(
+      ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ),
+    SetStoreId(Option<i32>),
+    IncrementStoreId(i32),
+    DecrementStoreId(i32),
+    MultiplyStoreId(i32),
+    DivideStoreId(i32),
+    ConnectStore(super::store::UniqueWhereParam),
+    DisconnectStore,
+    SetTotal(Option<i32>),
+    IncrementTotal(i32),
+    DecrementTotal(i32),
+    MultiplyTotal(i32),
+    DivideTotal(i32),
+    SetNotes(String),
+    ConnectFoods(Vec<super::food::UniqueWhereParam>),
+    DisconnectFoods(Vec<super::food::UniqueWhereParam>),
+    SetFoods(Vec<super::food::UniqueWhereParam>)
+  }
+  impl Into<(String, ::prisma_client_rust::PrismaValue)> for SetParam {
+    fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+      match self {
+        SetParam::SetId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Int(value as i64)
+        ),
+        SetParam::IncrementId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "increment".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::DecrementId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "decrement".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::MultiplyId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "multiply".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::DivideId(value) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "divide".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::SetDate(value) => (
+          "date".to_string(),
+          ::prisma_client_rust::PrismaValue::DateTime(value)
+        ),
+        SetParam::SetStoreId(value) => (
+          "store_id".to_string(),
+          value
+            .map(|value| ::prisma_client_rust::PrismaValue::Int(value as i64))
+            .unwrap_or_else(|| ::prisma_client_rust::PrismaValue::Null)
+        ),
+        SetParam::IncrementStoreId(value) => (
+          "store_id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "increment".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::DecrementStoreId(value) => (
+          "store_id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "decrement".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::MultiplyStoreId(value) => (
+          "store_id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "multiply".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::DivideStoreId(value) => (
+          "store_id".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "divide".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::ConnectStore(where_param) => (
+          "store".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "connect".to_string(),
+            ::prisma_client_rust::PrismaValue::Object(
+              [where_param]
+                .into_iter()
+                .map(Into::<super::store::WhereParam>::into)
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                .collect()
+            )
+          )])
+        ),
+        SetParam::DisconnectStore => (
+          "store".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "disconnect".to_string(),
+            ::prisma_client_rust::PrismaValue::Boolean(true)
+          )])
+        ),
+        SetParam::SetTotal(value) => (
+          "total".to_string(),
+          value
+            .map(|value| ::prisma_client_rust::PrismaValue::Int(value as i64))
+            .unwrap_or_else(|| ::prisma_client_rust::PrismaValue::Null)
+        ),
+        SetParam::IncrementTotal(value) => (
+          "total".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "increment".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::DecrementTotal(value) => (
+          "total".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "decrement".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::MultiplyTotal(value) => (
+          "total".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "multiply".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::DivideTotal(value) => (
+          "total".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "divide".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        SetParam::SetNotes(value) => (
+          "notes".to_string(),
+          ::prisma_client_rust::PrismaValue::String(value)
+        ),
+        SetParam::ConnectFoods(where_params) => (
+          "foods".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "connect".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              where_params
+                .into_iter()
+                .map(Into::<super::food::WhereParam>::into)
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                .collect()
+            )
+          )])
+        ),
+        SetParam::DisconnectFoods(where_params) => (
+          "foods".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "disconnect".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              where_params
+                .into_iter()
+                .map(Into::<super::food::WhereParam>::into)
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                .collect()
+            )
+          )])
+        ),
+        SetParam::SetFoods(where_params) => (
+          "foods".to_string(),
+          ::prisma_client_rust::PrismaValue::Object(vec![(
+            "set".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              where_params
+                .into_iter()
+                .map(Into::<super::food::WhereParam>::into)
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(::prisma_client_rust::SerializedWhere::transform_equals)
+                .map(|v| ::prisma_client_rust::PrismaValue::Object(vec![v]))
+                .collect()
+            )
+          )])
+        )
+      }
+    }
+  }
+  #[derive(Clone)]
+  pub enum OrderByParam {
+    Id(::prisma_client_rust::Direction),
+    Date(::prisma_client_rust::Direction),
+    StoreId(::prisma_client_rust::Direction),
+    Total(::prisma_client_rust::Direction),
+    Notes(::prisma_client_rust::Direction)
+  }
+  impl Into<(String, ::prisma_client_rust::PrismaValue)> for OrderByParam {
+    fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+      match self {
+        Self::Id(direction) => (
+          "id".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        ),
+        Self::Date(direction) => (
+          "date".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        ),
+        Self::StoreId(direction) => (
+          "store_id".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        ),
+        Self::Total(direction) => (
+          "total".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        ),
+        Self::Notes(direction) => (
+          "notes".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        )
+      }
+    }
+  }
+  #[derive(Clone)]
+  pub enum WhereParam {
+    Not(Vec<WhereParam>),
+    Or(Vec<WhereParam>),
+    And(Vec<WhereParam>),
+    IdEquals(i32),
+    IdInVec(Vec<i32>),
+    IdNotInVec(Vec<i32>),
+    IdLt(i32),
+    IdLte(i32),
+    IdGt(i32),
+    IdGte(i32),
+    IdNot(i32),
+    DateEquals(
+      ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ),
+    DateInVec(
+      Vec<
+        ::prisma_client_rust::chrono::DateTime<
+          ::prisma_client_rust::chrono::FixedOffset
+        >
+      >
+    ),
+    DateNotInVec(
+      Vec<
+        ::prisma_client_rust::chrono::DateTime<
+          ::prisma_client_rust::chrono::FixedOffset
+        >
+      >
+    ),
+    DateLt(
+      ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ),
+    DateLte(
+      ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ),
+    DateGt(
+      ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ),
+    DateGte(
+      ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ),
+    DateNot(
+      ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ),
+    StoreIdEquals(Option<i32>),
+    StoreIdInVec(Vec<i32>),
+    StoreIdNotInVec(Vec<i32>),
+    StoreIdLt(i32),
+    StoreIdLte(i32),
+    StoreIdGt(i32),
+    StoreIdGte(i32),
+    StoreIdNot(Option<i32>),
+    StoreIs(Vec<super::store::WhereParam>),
+    StoreIsNot(Vec<super::store::WhereParam>),
+    TotalEquals(Option<i32>),
+    TotalInVec(Vec<i32>),
+    TotalNotInVec(Vec<i32>),
+    TotalLt(i32),
+    TotalLte(i32),
+    TotalGt(i32),
+    TotalGte(i32),
+    TotalNot(Option<i32>),
+    NotesEquals(String),
+    NotesInVec(Vec<String>),
+    NotesNotInVec(Vec<String>),
+    NotesLt(String),
+    NotesLte(String),
+    NotesGt(String),
+    NotesGte(String),
+    NotesContains(String),
+    NotesStartsWith(String),
+    NotesEndsWith(String),
+    NotesMode(QueryMode),
+    NotesNot(String),
+    FoodsSome(Vec<super::food::WhereParam>),
+    FoodsEvery(Vec<super::food::WhereParam>),
+    FoodsNone(Vec<super::food::WhereParam>)
+  }
+  impl Into<::prisma_client_rust::SerializedWhere> for WhereParam {
+    fn into(self) -> ::prisma_client_rust::SerializedWhere {
+      match self {
+        Self::Not(value) => ::prisma_client_rust::SerializedWhere::new(
+          "NOT",
+          ::prisma_client_rust::SerializedWhereValue::Object(
+            value
+              .into_iter()
+              .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+              .map(Into::into)
+              .collect()
+          )
+        ),
+        Self::Or(value) => ::prisma_client_rust::SerializedWhere::new(
+          "OR",
+          ::prisma_client_rust::SerializedWhereValue::List(
+            value
+              .into_iter()
+              .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+              .map(Into::into)
+              .map(|v| vec![v])
+              .map(::prisma_client_rust::PrismaValue::Object)
+              .collect()
+          )
+        ),
+        Self::And(value) => ::prisma_client_rust::SerializedWhere::new(
+          "AND",
+          ::prisma_client_rust::SerializedWhereValue::Object(
+            value
+              .into_iter()
+              .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+              .map(Into::into)
+              .collect()
+          )
+        ),
+        Self::IdEquals(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "equals".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "in".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              value
+                .into_iter()
+                .map(|v| ::prisma_client_rust::PrismaValue::Int(v as i64))
+                .collect()
+            )
+          )])
+        ),
+        Self::IdNotInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "notIn".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              value
+                .into_iter()
+                .map(|v| ::prisma_client_rust::PrismaValue::Int(v as i64))
+                .collect()
+            )
+          )])
+        ),
+        Self::IdLt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lt".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdLte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lte".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdGt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gt".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdGte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gte".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::IdNot(value) => ::prisma_client_rust::SerializedWhere::new(
+          "id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "not".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::DateEquals(value) => ::prisma_client_rust::SerializedWhere::new(
+          "date",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "equals".to_string(),
+            ::prisma_client_rust::PrismaValue::DateTime(value)
+          )])
+        ),
+        Self::DateInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+          "date",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "in".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              value
+                .into_iter()
+                .map(|v| ::prisma_client_rust::PrismaValue::DateTime(v))
+                .collect()
+            )
+          )])
+        ),
+        Self::DateNotInVec(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "date",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "notIn".to_string(),
+              ::prisma_client_rust::PrismaValue::List(
+                value
+                  .into_iter()
+                  .map(|v| ::prisma_client_rust::PrismaValue::DateTime(v))
+                  .collect()
+              )
+            )])
+          )
+        }
+        Self::DateLt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "date",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lt".to_string(),
+            ::prisma_client_rust::PrismaValue::DateTime(value)
+          )])
+        ),
+        Self::DateLte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "date",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lte".to_string(),
+            ::prisma_client_rust::PrismaValue::DateTime(value)
+          )])
+        ),
+        Self::DateGt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "date",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gt".to_string(),
+            ::prisma_client_rust::PrismaValue::DateTime(value)
+          )])
+        ),
+        Self::DateGte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "date",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gte".to_string(),
+            ::prisma_client_rust::PrismaValue::DateTime(value)
+          )])
+        ),
+        Self::DateNot(value) => ::prisma_client_rust::SerializedWhere::new(
+          "date",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "not".to_string(),
+            ::prisma_client_rust::PrismaValue::DateTime(value)
+          )])
+        ),
+        Self::StoreIdEquals(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "store_id",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "equals".to_string(),
+              value
+                .map(|value| {
+                  ::prisma_client_rust::PrismaValue::Int(value as i64)
+                })
+                .unwrap_or_else(|| ::prisma_client_rust::PrismaValue::Null)
+            )])
+          )
+        }
+        Self::StoreIdInVec(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "store_id",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "in".to_string(),
+              ::prisma_client_rust::PrismaValue::List(
+                value
+                  .into_iter()
+                  .map(|v| ::prisma_client_rust::PrismaValue::Int(v as i64))
+                  .collect()
+              )
+            )])
+          )
+        }
+        Self::StoreIdNotInVec(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "store_id",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "notIn".to_string(),
+              ::prisma_client_rust::PrismaValue::List(
+                value
+                  .into_iter()
+                  .map(|v| ::prisma_client_rust::PrismaValue::Int(v as i64))
+                  .collect()
+              )
+            )])
+          )
+        }
+        Self::StoreIdLt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "store_id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lt".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::StoreIdLte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "store_id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lte".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::StoreIdGt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "store_id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gt".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::StoreIdGte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "store_id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gte".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::StoreIdNot(value) => ::prisma_client_rust::SerializedWhere::new(
+          "store_id",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "not".to_string(),
+            value
+              .map(|value| ::prisma_client_rust::PrismaValue::Int(value as i64))
+              .unwrap_or_else(|| ::prisma_client_rust::PrismaValue::Null)
+          )])
+        ),
+        Self::StoreIs(value) => ::prisma_client_rust::SerializedWhere::new(
+          "store",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "is".to_string(),
+            ::prisma_client_rust::PrismaValue::Object(
+              value
+                .into_iter()
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(Into::into)
+                .collect()
+            )
+          )])
+        ),
+        Self::StoreIsNot(value) => ::prisma_client_rust::SerializedWhere::new(
+          "store",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "isNot".to_string(),
+            ::prisma_client_rust::PrismaValue::Object(
+              value
+                .into_iter()
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(Into::into)
+                .collect()
+            )
+          )])
+        ),
+        Self::TotalEquals(value) => ::prisma_client_rust::SerializedWhere::new(
+          "total",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "equals".to_string(),
+            value
+              .map(|value| ::prisma_client_rust::PrismaValue::Int(value as i64))
+              .unwrap_or_else(|| ::prisma_client_rust::PrismaValue::Null)
+          )])
+        ),
+        Self::TotalInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+          "total",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "in".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              value
+                .into_iter()
+                .map(|v| ::prisma_client_rust::PrismaValue::Int(v as i64))
+                .collect()
+            )
+          )])
+        ),
+        Self::TotalNotInVec(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "total",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "notIn".to_string(),
+              ::prisma_client_rust::PrismaValue::List(
+                value
+                  .into_iter()
+                  .map(|v| ::prisma_client_rust::PrismaValue::Int(v as i64))
+                  .collect()
+              )
+            )])
+          )
+        }
+        Self::TotalLt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "total",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lt".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::TotalLte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "total",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lte".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::TotalGt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "total",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gt".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::TotalGte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "total",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gte".to_string(),
+            ::prisma_client_rust::PrismaValue::Int(value as i64)
+          )])
+        ),
+        Self::TotalNot(value) => ::prisma_client_rust::SerializedWhere::new(
+          "total",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "not".to_string(),
+            value
+              .map(|value| ::prisma_client_rust::PrismaValue::Int(value as i64))
+              .unwrap_or_else(|| ::prisma_client_rust::PrismaValue::Null)
+          )])
+        ),
+        Self::NotesEquals(value) => ::prisma_client_rust::SerializedWhere::new(
+          "notes",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "equals".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NotesInVec(value) => ::prisma_client_rust::SerializedWhere::new(
+          "notes",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "in".to_string(),
+            ::prisma_client_rust::PrismaValue::List(
+              value
+                .into_iter()
+                .map(|v| ::prisma_client_rust::PrismaValue::String(v))
+                .collect()
+            )
+          )])
+        ),
+        Self::NotesNotInVec(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "notes",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "notIn".to_string(),
+              ::prisma_client_rust::PrismaValue::List(
+                value
+                  .into_iter()
+                  .map(|v| ::prisma_client_rust::PrismaValue::String(v))
+                  .collect()
+              )
+            )])
+          )
+        }
+        Self::NotesLt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "notes",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lt".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NotesLte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "notes",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "lte".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NotesGt(value) => ::prisma_client_rust::SerializedWhere::new(
+          "notes",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gt".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NotesGte(value) => ::prisma_client_rust::SerializedWhere::new(
+          "notes",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "gte".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::NotesContains(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "notes",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "contains".to_string(),
+              ::prisma_client_rust::PrismaValue::String(value)
+            )])
+          )
+        }
+        Self::NotesStartsWith(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "notes",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "startsWith".to_string(),
+              ::prisma_client_rust::PrismaValue::String(value)
+            )])
+          )
+        }
+        Self::NotesEndsWith(value) => {
+          ::prisma_client_rust::SerializedWhere::new(
+            "notes",
+            ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+              "endsWith".to_string(),
+              ::prisma_client_rust::PrismaValue::String(value)
+            )])
+          )
+        }
+        Self::NotesMode(value) => ::prisma_client_rust::SerializedWhere::new(
+          "notes",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "mode".to_string(),
+            ::prisma_client_rust::PrismaValue::Enum(value.to_string())
+          )])
+        ),
+        Self::NotesNot(value) => ::prisma_client_rust::SerializedWhere::new(
+          "notes",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "not".to_string(),
+            ::prisma_client_rust::PrismaValue::String(value)
+          )])
+        ),
+        Self::FoodsSome(value) => ::prisma_client_rust::SerializedWhere::new(
+          "foods",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "some".to_string(),
+            ::prisma_client_rust::PrismaValue::Object(
+              value
+                .into_iter()
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(Into::into)
+                .collect()
+            )
+          )])
+        ),
+        Self::FoodsEvery(value) => ::prisma_client_rust::SerializedWhere::new(
+          "foods",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "every".to_string(),
+            ::prisma_client_rust::PrismaValue::Object(
+              value
+                .into_iter()
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(Into::into)
+                .collect()
+            )
+          )])
+        ),
+        Self::FoodsNone(value) => ::prisma_client_rust::SerializedWhere::new(
+          "foods",
+          ::prisma_client_rust::SerializedWhereValue::Object(vec![(
+            "none".to_string(),
+            ::prisma_client_rust::PrismaValue::Object(
+              value
+                .into_iter()
+                .map(Into::<::prisma_client_rust::SerializedWhere>::into)
+                .map(Into::into)
+                .collect()
+            )
+          )])
+        )
+      }
+    }
+  }
+  #[derive(Clone)]
+  pub enum UniqueWhereParam {
+    IdEquals(i32)
+  }
+  impl From<UniqueWhereParam> for WhereParam {
+    fn from(value: UniqueWhereParam) -> Self {
+      match value {
+        UniqueWhereParam::IdEquals(value) => Self::IdEquals(value)
+      }
+    }
+  }
+  impl From<::prisma_client_rust::Operator<Self>> for WhereParam {
+    fn from(op: ::prisma_client_rust::Operator<Self>) -> Self {
+      match op {
+        ::prisma_client_rust::Operator::Not(value) => Self::Not(value),
+        ::prisma_client_rust::Operator::And(value) => Self::And(value),
+        ::prisma_client_rust::Operator::Or(value) => Self::Or(value)
+      }
+    }
+  }
+  pub type UniqueArgs = ::prisma_client_rust::UniqueArgs<WithParam>;
+  pub type ManyArgs = ::prisma_client_rust::ManyArgs<
+    WhereParam,
+    WithParam,
+    OrderByParam,
+    UniqueWhereParam
+  >;
+  pub type Count<'a> =
+    ::prisma_client_rust::Count<'a, WhereParam, OrderByParam, UniqueWhereParam>;
+  pub type Create<'a> =
+    ::prisma_client_rust::Create<'a, SetParam, WithParam, Data>;
+  pub type CreateMany<'a> = ::prisma_client_rust::CreateMany<'a, SetParam>;
+  pub type FindUnique<'a> =
+    ::prisma_client_rust::FindUnique<'a, WhereParam, WithParam, SetParam, Data>;
+  pub type FindMany<'a> = ::prisma_client_rust::FindMany<
+    'a,
+    WhereParam,
+    WithParam,
+    OrderByParam,
+    UniqueWhereParam,
+    SetParam,
+    Data
+  >;
+  pub type FindFirst<'a> = ::prisma_client_rust::FindFirst<
+    'a,
+    WhereParam,
+    WithParam,
+    OrderByParam,
+    UniqueWhereParam,
+    Data
+  >;
+  pub type Update<'a> =
+    ::prisma_client_rust::Update<'a, WhereParam, WithParam, SetParam, Data>;
+  pub type UpdateMany<'a> =
+    ::prisma_client_rust::UpdateMany<'a, WhereParam, SetParam>;
+  pub type Upsert<'a> =
+    ::prisma_client_rust::Upsert<'a, WhereParam, SetParam, WithParam, Data>;
+  pub type Delete<'a> =
+    ::prisma_client_rust::Delete<'a, WhereParam, WithParam, Data>;
+  pub type DeleteMany<'a> = ::prisma_client_rust::DeleteMany<'a, WhereParam>;
+  pub struct Actions<'a> {
+    pub client: &'a PrismaClient
+  }
+  impl<'a> Actions<'a> {
+    pub fn find_unique(self, _where: UniqueWhereParam) -> FindUnique<'a> {
+      FindUnique::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Receipt", _outputs()),
+        _where.into()
+      )
+    }
+    pub fn find_first(self, _where: Vec<WhereParam>) -> FindFirst<'a> {
+      FindFirst::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Receipt", _outputs()),
+        _where
+      )
+    }
+    pub fn find_many(self, _where: Vec<WhereParam>) -> FindMany<'a> {
+      FindMany::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Receipt", _outputs()),
+        _where
+      )
+    }
+    pub fn create(
+      self,
+      date: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >,
+      mut _params: Vec<SetParam>
+    ) -> Create<'a> {
+      _params.push(date::set(date));
+      Create::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Receipt", _outputs()),
+        _params
+      )
+    }
+    pub fn create_many(
+      self,
+      data: Vec<(
+        ::prisma_client_rust::chrono::DateTime<
+          ::prisma_client_rust::chrono::FixedOffset
+        >,
+        Vec<SetParam>
+      )>
+    ) -> CreateMany<'a> {
+      let data = data
+        .into_iter()
+        .map(|(date, mut _params)| {
+          _params.push(date::set(date));
+          _params
+        })
+        .collect();
+      CreateMany::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Receipt", _outputs()),
+        data
+      )
+    }
+    pub fn update(
+      self,
+      _where: UniqueWhereParam,
+      _params: Vec<SetParam>
+    ) -> Update<'a> {
+      Update::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Receipt", _outputs()),
+        _where.into(),
+        _params,
+        vec![]
+      )
+    }
+    pub fn update_many(
+      self,
+      _where: Vec<WhereParam>,
+      _params: Vec<SetParam>
+    ) -> UpdateMany<'a> {
+      UpdateMany::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Receipt", _outputs()),
+        _where,
+        _params
+      )
+    }
+    pub fn upsert(
+      self,
+      _where: UniqueWhereParam,
+      (date, mut _params): (
+        ::prisma_client_rust::chrono::DateTime<
+          ::prisma_client_rust::chrono::FixedOffset
+        >,
+        Vec<SetParam>
+      ),
+      _update: Vec<SetParam>
+    ) -> Upsert<'a> {
+      _params.push(date::set(date));
+      Upsert::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Receipt", _outputs()),
+        _where.into(),
+        _params,
+        _update
+      )
+    }
+    pub fn delete(self, _where: UniqueWhereParam) -> Delete<'a> {
+      Delete::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Receipt", _outputs()),
+        _where.into(),
+        vec![]
+      )
+    }
+    pub fn delete_many(self, _where: Vec<WhereParam>) -> DeleteMany<'a> {
+      DeleteMany::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Receipt", _outputs()),
+        _where.into()
+      )
+    }
+    pub fn count(self, _where: Vec<WhereParam>) -> Count<'a> {
+      Count::new(
+        self.client._new_query_context(),
+        ::prisma_client_rust::QueryInfo::new("Receipt", _outputs()),
+        vec![]
+      )
+    }
+  }
+}
+pub mod food {
+  use super::_prisma::*;
+  use super::*;
+  pub mod id {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: i32) -> T {
+      Set(value).into()
+    }
+    pub fn equals<T: From<UniqueWhereParam>>(value: i32) -> T {
+      UniqueWhereParam::IdEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::Id(direction)
+    }
+    pub fn in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::IdInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::IdNotInVec(value)
+    }
+    pub fn lt(value: i32) -> WhereParam {
+      WhereParam::IdLt(value)
+    }
+    pub fn lte(value: i32) -> WhereParam {
+      WhereParam::IdLte(value)
+    }
+    pub fn gt(value: i32) -> WhereParam {
+      WhereParam::IdGt(value)
+    }
+    pub fn gte(value: i32) -> WhereParam {
+      WhereParam::IdGte(value)
+    }
+    pub fn not(value: i32) -> WhereParam {
+      WhereParam::IdNot(value)
+    }
+    pub fn increment(value: i32) -> SetParam {
+      SetParam::IncrementId(value)
+    }
+    pub fn decrement(value: i32) -> SetParam {
+      SetParam::DecrementId(value)
+    }
+    pub fn multiply(value: i32) -> SetParam {
+      SetParam::MultiplyId(value)
+    }
+    pub fn divide(value: i32) -> SetParam {
+      SetParam::DivideId(value)
+    }
+    pub struct Set(pub i32);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetId(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Id(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("id").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Id(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("id").build()
+      }
+    }
+  }
+  pub mod food_type {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: String) -> T {
+      Set(value).into()
+    }
+    pub fn equals(value: String) -> WhereParam {
+      WhereParam::FoodTypeEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::FoodType(direction)
+    }
+    pub fn in_vec(value: Vec<String>) -> WhereParam {
+      WhereParam::FoodTypeInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<String>) -> WhereParam {
+      WhereParam::FoodTypeNotInVec(value)
+    }
+    pub fn lt(value: String) -> WhereParam {
+      WhereParam::FoodTypeLt(value)
+    }
+    pub fn lte(value: String) -> WhereParam {
+      WhereParam::FoodTypeLte(value)
+    }
+    pub fn gt(value: String) -> WhereParam {
+      WhereParam::FoodTypeGt(value)
+    }
+    pub fn gte(value: String) -> WhereParam {
+      WhereParam::FoodTypeGte(value)
+    }
+    pub fn contains(value: String) -> WhereParam {
+      WhereParam::FoodTypeContains(value)
+    }
+    pub fn starts_with(value: String) -> WhereParam {
+      WhereParam::FoodTypeStartsWith(value)
+    }
+    pub fn ends_with(value: String) -> WhereParam {
+      WhereParam::FoodTypeEndsWith(value)
+    }
+    pub fn mode(value: QueryMode) -> WhereParam {
+      WhereParam::FoodTypeMode(value)
+    }
+    pub fn not(value: String) -> WhereParam {
+      WhereParam::FoodTypeNot(value)
+    }
+    pub struct Set(pub String);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetFoodType(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::FoodType(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("food_type").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::FoodType(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("food_type").build()
+      }
+    }
+  }
+  pub mod category {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn is(value: Vec<super::super::category::WhereParam>) -> WhereParam {
+      WhereParam::CategoryIs(value)
+    }
+    pub fn is_not(
+      value: Vec<super::super::category::WhereParam>
+    ) -> WhereParam {
+      WhereParam::CategoryIsNot(value)
+    }
+    pub struct Fetch(pub super::super::category::UniqueArgs);
+    impl Fetch {
+      pub fn with(
+        mut self,
+        params: impl Into<super::super::category::WithParam>
+      ) -> Self {
+        self.0 = self.0.with(params.into());
+        self
+      }
+    }
+    impl From<Fetch> for WithParam {
+      fn from(fetch: Fetch) -> Self {
+        WithParam::Category(fetch.0)
+      }
+    }
+    pub fn fetch() -> Fetch {
+      Fetch(super::super::category::UniqueArgs::new())
+    }
+    pub fn connect<T: From<Connect>>(
+      value: super::super::category::UniqueWhereParam
+    ) -> T {
+      Connect(value).into()
+    }
+    pub struct Connect(super::super::category::UniqueWhereParam);
+    impl From<Connect> for SetParam {
+      fn from(value: Connect) -> Self {
+        Self::ConnectCategory(value.0)
+      }
+    }
+    pub enum Include {
+      Select(Vec<super::super::category::SelectParam>),
+      Include(Vec<super::super::category::IncludeParam>),
+      Fetch
+    }
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Category(self)
+      }
+    }
+    impl Include {
+      pub fn select(
+        nested_selections: Vec<super::super::category::SelectParam>
+      ) -> Self {
+        Self::Select(nested_selections)
+      }
+      pub fn include(
+        nested_selections: Vec<super::super::category::IncludeParam>
+      ) -> Self {
+        Self::Include(nested_selections)
+      }
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        let mut selection =
+          ::prisma_client_rust::Selection::builder("category");
+        match self {
+          Self::Select(selections) => {
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(selections) => {
+            let mut nested_selections = super::super::category::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch => {
+            selection.nested_selections(super::super::category::_outputs());
+          }
+        }
+        selection.build()
+      }
+    }
+    pub enum Select {
+      Select(Vec<super::super::category::SelectParam>),
+      Include(Vec<super::super::category::IncludeParam>),
+      Fetch
+    }
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Category(self)
+      }
+    }
+    impl Select {
+      pub fn select(
+        nested_selections: Vec<super::super::category::SelectParam>
+      ) -> Self {
+        Self::Select(nested_selections)
+      }
+      pub fn include(
+        nested_selections: Vec<super::super::category::IncludeParam>
+      ) -> Self {
+        Self::Include(nested_selections)
+      }
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        let mut selection =
+          ::prisma_client_rust::Selection::builder("category");
+        match self {
+          Self::Select(selections) => {
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(selections) => {
+            let mut nested_selections = super::super::category::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch => {
+            selection.nested_selections(super::super::category::_outputs());
+          }
+        }
+        selection.build()
+      }
+    }
+  }
+  pub mod name {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: String) -> T {
+      Set(value).into()
+    }
+    pub fn equals(value: String) -> WhereParam {
+      WhereParam::NameEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::Name(direction)
+    }
+    pub fn in_vec(value: Vec<String>) -> WhereParam {
+      WhereParam::NameInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<String>) -> WhereParam {
+      WhereParam::NameNotInVec(value)
+    }
+    pub fn lt(value: String) -> WhereParam {
+      WhereParam::NameLt(value)
+    }
+    pub fn lte(value: String) -> WhereParam {
+      WhereParam::NameLte(value)
+    }
+    pub fn gt(value: String) -> WhereParam {
+      WhereParam::NameGt(value)
+    }
+    pub fn gte(value: String) -> WhereParam {
+      WhereParam::NameGte(value)
+    }
+    pub fn contains(value: String) -> WhereParam {
+      WhereParam::NameContains(value)
+    }
+    pub fn starts_with(value: String) -> WhereParam {
+      WhereParam::NameStartsWith(value)
+    }
+    pub fn ends_with(value: String) -> WhereParam {
+      WhereParam::NameEndsWith(value)
+    }
+    pub fn mode(value: QueryMode) -> WhereParam {
+      WhereParam::NameMode(value)
+    }
+    pub fn not(value: String) -> WhereParam {
+      WhereParam::NameNot(value)
+    }
+    pub struct Set(pub String);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetName(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Name(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("name").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Name(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("name").build()
+      }
+    }
+  }
+  pub mod price {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: i32) -> T {
+      Set(value).into()
+    }
+    pub fn equals(value: i32) -> WhereParam {
+      WhereParam::PriceEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::Price(direction)
+    }
+    pub fn in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::PriceInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::PriceNotInVec(value)
+    }
+    pub fn lt(value: i32) -> WhereParam {
+      WhereParam::PriceLt(value)
+    }
+    pub fn lte(value: i32) -> WhereParam {
+      WhereParam::PriceLte(value)
+    }
+    pub fn gt(value: i32) -> WhereParam {
+      WhereParam::PriceGt(value)
+    }
+    pub fn gte(value: i32) -> WhereParam {
+      WhereParam::PriceGte(value)
+    }
+    pub fn not(value: i32) -> WhereParam {
+      WhereParam::PriceNot(value)
+    }
+    pub fn increment(value: i32) -> SetParam {
+      SetParam::IncrementPrice(value)
+    }
+    pub fn decrement(value: i32) -> SetParam {
+      SetParam::DecrementPrice(value)
+    }
+    pub fn multiply(value: i32) -> SetParam {
+      SetParam::MultiplyPrice(value)
+    }
+    pub fn divide(value: i32) -> SetParam {
+      SetParam::DividePrice(value)
+    }
+    pub struct Set(pub i32);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetPrice(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Price(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("price").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Price(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("price").build()
+      }
+    }
+  }
+  pub mod amount {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: f64) -> T {
+      Set(value).into()
+    }
+    pub fn equals(value: f64) -> WhereParam {
+      WhereParam::AmountEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::Amount(direction)
+    }
+    pub fn in_vec(value: Vec<f64>) -> WhereParam {
+      WhereParam::AmountInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<f64>) -> WhereParam {
+      WhereParam::AmountNotInVec(value)
+    }
+    pub fn lt(value: f64) -> WhereParam {
+      WhereParam::AmountLt(value)
+    }
+    pub fn lte(value: f64) -> WhereParam {
+      WhereParam::AmountLte(value)
+    }
+    pub fn gt(value: f64) -> WhereParam {
+      WhereParam::AmountGt(value)
+    }
+    pub fn gte(value: f64) -> WhereParam {
+      WhereParam::AmountGte(value)
+    }
+    pub fn not(value: f64) -> WhereParam {
+      WhereParam::AmountNot(value)
+    }
+    pub fn increment(value: f64) -> SetParam {
+      SetParam::IncrementAmount(value)
+    }
+    pub fn decrement(value: f64) -> SetParam {
+      SetParam::DecrementAmount(value)
+    }
+    pub fn multiply(value: f64) -> SetParam {
+      SetParam::MultiplyAmount(value)
+    }
+    pub fn divide(value: f64) -> SetParam {
+      SetParam::DivideAmount(value)
+    }
+    pub struct Set(pub f64);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetAmount(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Amount(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("amount").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Amount(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("amount").build()
+      }
+    }
+  }
+  pub mod unit {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: String) -> T {
+      Set(value).into()
+    }
+    pub fn equals(value: String) -> WhereParam {
+      WhereParam::UnitEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::Unit(direction)
+    }
+    pub fn in_vec(value: Vec<String>) -> WhereParam {
+      WhereParam::UnitInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<String>) -> WhereParam {
+      WhereParam::UnitNotInVec(value)
+    }
+    pub fn lt(value: String) -> WhereParam {
+      WhereParam::UnitLt(value)
+    }
+    pub fn lte(value: String) -> WhereParam {
+      WhereParam::UnitLte(value)
+    }
+    pub fn gt(value: String) -> WhereParam {
+      WhereParam::UnitGt(value)
+    }
+    pub fn gte(value: String) -> WhereParam {
+      WhereParam::UnitGte(value)
+    }
+    pub fn contains(value: String) -> WhereParam {
+      WhereParam::UnitContains(value)
+    }
+    pub fn starts_with(value: String) -> WhereParam {
+      WhereParam::UnitStartsWith(value)
+    }
+    pub fn ends_with(value: String) -> WhereParam {
+      WhereParam::UnitEndsWith(value)
+    }
+    pub fn mode(value: QueryMode) -> WhereParam {
+      WhereParam::UnitMode(value)
+    }
+    pub fn not(value: String) -> WhereParam {
+      WhereParam::UnitNot(value)
+    }
+    pub struct Set(pub String);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetUnit(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Unit(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("unit").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Unit(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("unit").build()
+      }
+    }
+  }
+  pub mod total {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: i32) -> T {
+      Set(value).into()
+    }
+    pub fn equals(value: i32) -> WhereParam {
+      WhereParam::TotalEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::Total(direction)
+    }
+    pub fn in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::TotalInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::TotalNotInVec(value)
+    }
+    pub fn lt(value: i32) -> WhereParam {
+      WhereParam::TotalLt(value)
+    }
+    pub fn lte(value: i32) -> WhereParam {
+      WhereParam::TotalLte(value)
+    }
+    pub fn gt(value: i32) -> WhereParam {
+      WhereParam::TotalGt(value)
+    }
+    pub fn gte(value: i32) -> WhereParam {
+      WhereParam::TotalGte(value)
+    }
+    pub fn not(value: i32) -> WhereParam {
+      WhereParam::TotalNot(value)
+    }
+    pub fn increment(value: i32) -> SetParam {
+      SetParam::IncrementTotal(value)
+    }
+    pub fn decrement(value: i32) -> SetParam {
+      SetParam::DecrementTotal(value)
+    }
+    pub fn multiply(value: i32) -> SetParam {
+      SetParam::MultiplyTotal(value)
+    }
+    pub fn divide(value: i32) -> SetParam {
+      SetParam::DivideTotal(value)
+    }
+    pub struct Set(pub i32);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetTotal(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Total(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("total").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Total(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("total").build()
+      }
+    }
+  }
+  pub mod date {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> T {
+      Set(value).into()
+    }
+    pub fn equals(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> WhereParam {
+      WhereParam::DateEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::Date(direction)
+    }
+    pub fn in_vec(
+      value: Vec<
+        ::prisma_client_rust::chrono::DateTime<
+          ::prisma_client_rust::chrono::FixedOffset
+        >
+      >
+    ) -> WhereParam {
+      WhereParam::DateInVec(value)
+    }
+    pub fn not_in_vec(
+      value: Vec<
+        ::prisma_client_rust::chrono::DateTime<
+          ::prisma_client_rust::chrono::FixedOffset
+        >
+      >
+    ) -> WhereParam {
+      WhereParam::DateNotInVec(value)
+    }
+    pub fn lt(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> WhereParam {
+      WhereParam::DateLt(value)
+    }
+    pub fn lte(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> WhereParam {
+      WhereParam::DateLte(value)
+    }
+    pub fn gt(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> WhereParam {
+      WhereParam::DateGt(value)
+    }
+    pub fn gte(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> WhereParam {
+      WhereParam::DateGte(value)
+    }
+    pub fn not(
+      value: ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    ) -> WhereParam {
+      WhereParam::DateNot(value)
+    }
+    pub struct Set(
+      pub  ::prisma_client_rust::chrono::DateTime<
+        ::prisma_client_rust::chrono::FixedOffset
+      >
+    );
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetDate(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::Date(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("date").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::Date(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("date").build()
+      }
+    }
+  }
+  pub mod store_id {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn set<T: From<Set>>(value: Option<i32>) -> T {
+      Set(value).into()
+    }
+    pub fn equals(value: Option<i32>) -> WhereParam {
+      WhereParam::StoreIdEquals(value).into()
+    }
+    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+      OrderByParam::StoreId(direction)
+    }
+    pub fn in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::StoreIdInVec(value)
+    }
+    pub fn not_in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::StoreIdNotInVec(value)
+    }
+    pub fn lt(value: i32) -> WhereParam {
+      WhereParam::StoreIdLt(value)
+    }
+    pub fn lte(value: i32) -> WhereParam {
+      WhereParam::StoreIdLte(value)
+    }
+    pub fn gt(value: i32) -> WhereParam {
+      WhereParam::StoreIdGt(value)
+    }
+    pub fn gte(value: i32) -> WhereParam {
+      WhereParam::StoreIdGte(value)
+    }
+    pub fn not(value: Option<i32>) -> WhereParam {
+      WhereParam::StoreIdNot(value)
+    }
+    pub fn increment(value: i32) -> SetParam {
+      SetParam::IncrementStoreId(value)
+    }
+    pub fn decrement(value: i32) -> SetParam {
+      SetParam::DecrementStoreId(value)
+    }
+    pub fn multiply(value: i32) -> SetParam {
+      SetParam::MultiplyStoreId(value)
+    }
+    pub fn divide(value: i32) -> SetParam {
+      SetParam::DivideStoreId(value)
+    }
+    pub struct Set(pub Option<i32>);
+    impl From<Set> for SetParam {
+      fn from(value: Set) -> Self {
+        Self::SetStoreId(value.0)
+      }
+    }
+    pub struct Include;
+    impl Into<super::IncludeParam> for Include {
+      fn into(self) -> super::IncludeParam {
+        super::IncludeParam::StoreId(self)
+      }
+    }
+    impl Include {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("store_id").build()
+      }
+    }
+    pub struct Select;
+    impl Into<super::SelectParam> for Select {
+      fn into(self) -> super::SelectParam {
+        super::SelectParam::StoreId(self)
+      }
+    }
+    impl Select {
+      pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+        ::prisma_client_rust::Selection::builder("store_id").build()
+      }
     }
-    pub fn not(value: f64) -> WhereParam {
-      WhereParam::AmountNot(value)
+  }
+  pub mod store {
+    use super::super::*;
+    use super::_prisma::*;
+    use super::{
+      OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
+    };
+    pub fn is(value: Vec<super::super::store::WhereParam>) -> WhereParam {
+      WhereParam::StoreIs(value)
+    }
+    pub fn is_not(value: Vec<super::super::store::WhereParam>) -> WhereParam {
+      WhereParam::StoreIsNot(value)
+    }
+    pub struct Fetch(pub super::super::store::UniqueArgs);
+    impl Fetch {
+      pub fn with(
+        mut self,
+        params: impl Into<super::super::store::WithParam>
+      ) -> Self {
+        self.0 = self.0.with(params.into());
+        self
+      }
     }
-    pub fn increment(value: f64) -> SetParam {
-      SetParam::IncrementAmount(value)
+    impl From<Fetch> for WithParam {
+      fn from(fetch: Fetch) -> Self {
+        WithParam::Store(fetch.0)
+      }
     }
-    pub fn decrement(value: f64) -> SetParam {
-      SetParam::DecrementAmount(value)
+    pub fn fetch() -> Fetch {
+      Fetch(super::super::store::UniqueArgs::new())
     }
-    pub fn multiply(value: f64) -> SetParam {
-      SetParam::MultiplyAmount(value)
+    pub fn connect<T: From<Connect>>(
+      value: super::super::store::UniqueWhereParam
+    ) -> T {
+      Connect(value).into()
     }
-    pub fn divide(value: f64) -> SetParam {
-      SetParam::DivideAmount(value)
+    pub fn disconnect() -> SetParam {
+      SetParam::DisconnectStore
     }
-    pub struct Set(pub f64);
-    impl From<Set> for SetParam {
-      fn from(value: Set) -> Self {
-        Self::SetAmount(value.0)
+    pub struct Connect(super::super::store::UniqueWhereParam);
+    impl From<Connect> for SetParam {
+      fn from(value: Connect) -> Self {
+        Self::ConnectStore(value.0)
       }
     }
-    pub struct Include;
+    pub enum Include {
+      Select(Vec<super::super::store::SelectParam>),
+      Include(Vec<super::super::store::IncludeParam>),
+      Fetch
+    }
     impl Into<super::IncludeParam> for Include {
       fn into(self) -> super::IncludeParam {
-        super::IncludeParam::Amount(self)
+        super::IncludeParam::Store(self)
       }
     }
     impl Include {
+      pub fn select(
+        nested_selections: Vec<super::super::store::SelectParam>
+      ) -> Self {
+        Self::Select(nested_selections)
+      }
+      pub fn include(
+        nested_selections: Vec<super::super::store::IncludeParam>
+      ) -> Self {
+        Self::Include(nested_selections)
+      }
       pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-        ::prisma_client_rust::Selection::builder("amount").build()
+        let mut selection = ::prisma_client_rust::Selection::builder("store");
+        match self {
+          Self::Select(selections) => {
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(selections) => {
+            let mut nested_selections = super::super::store::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch => {
+            selection.nested_selections(super::super::store::_outputs());
+          }
+        }
+        selection.build()
       }
     }
-    pub struct Select;
+    pub enum Select {
+      Select(Vec<super::super::store::SelectParam>),
+      Include(Vec<super::super::store::IncludeParam>),
+      Fetch
+    }
     impl Into<super::SelectParam> for Select {
       fn into(self) -> super::SelectParam {
-        super::SelectParam::Amount(self)
+        super::SelectParam::Store(self)
       }
     }
     impl Select {
+      pub fn select(
+        nested_selections: Vec<super::super::store::SelectParam>
+      ) -> Self {
+        Self::Select(nested_selections)
+      }
+      pub fn include(
+        nested_selections: Vec<super::super::store::IncludeParam>
+      ) -> Self {
+        Self::Include(nested_selections)
+      }
       pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-        ::prisma_client_rust::Selection::builder("amount").build()
+        let mut selection = ::prisma_client_rust::Selection::builder("store");
+        match self {
+          Self::Select(selections) => {
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(selections) => {
+            let mut nested_selections = super::super::store::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch => {
+            selection.nested_selections(super::super::store::_outputs());
+          }
+        }
+        selection.build()
       }
     }
   }
-  pub mod total {
+  pub mod receipt_id {
     use super::super::*;
     use super::_prisma::*;
     use super::{
       OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
     };
-    pub fn set<T: From<Set>>(value: f64) -> T {
+    pub fn set<T: From<Set>>(value: Option<i32>) -> T {
       Set(value).into()
     }
-    pub fn equals(value: f64) -> WhereParam {
-      WhereParam::TotalEquals(value).into()
+    pub fn equals(value: Option<i32>) -> WhereParam {
+      WhereParam::ReceiptIdEquals(value).into()
     }
     pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
-      OrderByParam::Total(direction)
+      OrderByParam::ReceiptId(direction)
     }
-    pub fn in_vec(value: Vec<f64>) -> WhereParam {
-      WhereParam::TotalInVec(value)
+    pub fn in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::ReceiptIdInVec(value)
     }
-    pub fn not_in_vec(value: Vec<f64>) -> WhereParam {
-      WhereParam::TotalNotInVec(value)
+    pub fn not_in_vec(value: Vec<i32>) -> WhereParam {
+      WhereParam::ReceiptIdNotInVec(value)
     }
-    pub fn lt(value: f64) -> WhereParam {
-      WhereParam::TotalLt(value)
+    pub fn lt(value: i32) -> WhereParam {
+      WhereParam::ReceiptIdLt(value)
     }
-    pub fn lte(value: f64) -> WhereParam {
-      WhereParam::TotalLte(value)
+    pub fn lte(value: i32) -> WhereParam {
+      WhereParam::ReceiptIdLte(value)
     }
-    pub fn gt(value: f64) -> WhereParam {
-      WhereParam::TotalGt(value)
+    pub fn gt(value: i32) -> WhereParam {
+      WhereParam::ReceiptIdGt(value)
     }
-    pub fn gte(value: f64) -> WhereParam {
-      WhereParam::TotalGte(value)
+    pub fn gte(value: i32) -> WhereParam {
+      WhereParam::ReceiptIdGte(value)
     }
-    pub fn not(value: f64) -> WhereParam {
-      WhereParam::TotalNot(value)
+    pub fn not(value: Option<i32>) -> WhereParam {
+      WhereParam::ReceiptIdNot(value)
     }
-    pub fn increment(value: f64) -> SetParam {
-      SetParam::IncrementTotal(value)
+    pub fn increment(value: i32) -> SetParam {
+      SetParam::IncrementReceiptId(value)
     }
-    pub fn decrement(value: f64) -> SetParam {
-      SetParam::DecrementTotal(value)
+    pub fn decrement(value: i32) -> SetParam {
+      SetParam::DecrementReceiptId(value)
     }
-    pub fn multiply(value: f64) -> SetParam {
-      SetParam::MultiplyTotal(value)
+    pub fn multiply(value: i32) -> SetParam {
+      SetParam::MultiplyReceiptId(value)
     }
-    pub fn divide(value: f64) -> SetParam {
-      SetParam::DivideTotal(value)
+    pub fn divide(value: i32) -> SetParam {
+      SetParam::DivideReceiptId(value)
     }
-    pub struct Set(pub f64);
+    pub struct Set(pub Option<i32>);
     impl From<Set> for SetParam {
       fn from(value: Set) -> Self {
-        Self::SetTotal(value.0)
+        Self::SetReceiptId(value.0)
       }
     }
     pub struct Include;
     impl Into<super::IncludeParam> for Include {
       fn into(self) -> super::IncludeParam {
-        super::IncludeParam::Total(self)
+        super::IncludeParam::ReceiptId(self)
       }
     }
     impl Include {
       pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-        ::prisma_client_rust::Selection::builder("total").build()
+        ::prisma_client_rust::Selection::builder("receipt_id").build()
       }
     }
     pub struct Select;
     impl Into<super::SelectParam> for Select {
       fn into(self) -> super::SelectParam {
-        super::SelectParam::Total(self)
+        super::SelectParam::ReceiptId(self)
       }
     }
     impl Select {
       pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-        ::prisma_client_rust::Selection::builder("total").build()
+        ::prisma_client_rust::Selection::builder("receipt_id").build()
       }
     }
   }
-  pub mod date {
+  pub mod receipt {
     use super::super::*;
     use super::_prisma::*;
     use super::{
       OrderByParam, SetParam, UniqueWhereParam, WhereParam, WithParam
     };
-    pub fn set<T: From<Set>>(
-      value: ::prisma_client_rust::chrono::DateTime<
-        ::prisma_client_rust::chrono::FixedOffset
-      >
-    ) -> T {
-      Set(value).into()
-    }
-    pub fn equals(
-      value: ::prisma_client_rust::chrono::DateTime<
-        ::prisma_client_rust::chrono::FixedOffset
-      >
-    ) -> WhereParam {
-      WhereParam::DateEquals(value).into()
-    }
-    pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
-      OrderByParam::Date(direction)
-    }
-    pub fn in_vec(
-      value: Vec<
-        ::prisma_client_rust::chrono::DateTime<
-          ::prisma_client_rust::chrono::FixedOffset
-        >
-      >
-    ) -> WhereParam {
-      WhereParam::DateInVec(value)
-    }
-    pub fn not_in_vec(
-      value: Vec<
-        ::prisma_client_rust::chrono::DateTime<
-          ::prisma_client_rust::chrono::FixedOffset
-        >
-      >
-    ) -> WhereParam {
-      WhereParam::DateNotInVec(value)
-    }
-    pub fn lt(
-      value: ::prisma_client_rust::chrono::DateTime<
-        ::prisma_client_rust::chrono::FixedOffset
-      >
-    ) -> WhereParam {
-      WhereParam::DateLt(value)
+    pub fn is(value: Vec<super::super::receipt::WhereParam>) -> WhereParam {
+      WhereParam::ReceiptIs(value)
+    }
+    pub fn is_not(value: Vec<super::super::receipt::WhereParam>) -> WhereParam {
+      WhereParam::ReceiptIsNot(value)
+    }
+    pub struct Fetch(pub super::super::receipt::UniqueArgs);
+    impl Fetch {
+      pub fn with(
+        mut self,
+        params: impl Into<super::super::receipt::WithParam>
+      ) -> Self {
+        self.0 = self.0.with(params.into());
+        self
+      }
     }
-    pub fn lte(
-      value: ::prisma_client_rust::chrono::DateTime<
-        ::prisma_client_rust::chrono::FixedOffset
-      >
-    ) -> WhereParam {
-      WhereParam::DateLte(value)
+    impl From<Fetch> for WithParam {
+      fn from(fetch: Fetch) -> Self {
+        WithParam::Receipt(fetch.0)
+      }
     }
-    pub fn gt(
-      value: ::prisma_client_rust::chrono::DateTime<
-        ::prisma_client_rust::chrono::FixedOffset
-      >
-    ) -> WhereParam {
-      WhereParam::DateGt(value)
+    pub fn fetch() -> Fetch {
+      Fetch(super::super::receipt::UniqueArgs::new())
     }
-    pub fn gte(
-      value: ::prisma_client_rust::chrono::DateTime<
-        ::prisma_client_rust::chrono::FixedOffset
-      >
-    ) -> WhereParam {
-      WhereParam::DateGte(value)
+    pub fn connect<T: From<Connect>>(
+      value: super::super::receipt::UniqueWhereParam
+    ) -> T {
+      Connect(value).into()
     }
-    pub fn not(
-      value: ::prisma_client_rust::chrono::DateTime<
-        ::prisma_client_rust::chrono::FixedOffset
-      >
-    ) -> WhereParam {
-      WhereParam::DateNot(value)
+    pub fn disconnect() -> SetParam {
+      SetParam::DisconnectReceipt
     }
-    pub struct Set(
-      pub  ::prisma_client_rust::chrono::DateTime<
-        ::prisma_client_rust::chrono::FixedOffset
-      >
-    );
-    impl From<Set> for SetParam {
-      fn from(value: Set) -> Self {
-        Self::SetDate(value.0)
+    pub struct Connect(super::super::receipt::UniqueWhereParam);
+    impl From<Connect> for SetParam {
+      fn from(value: Connect) -> Self {
+        Self::ConnectReceipt(value.0)
       }
     }
-    pub struct Include;
+    pub enum Include {
+      Select(Vec<super::super::receipt::SelectParam>),
+      Include(Vec<super::super::receipt::IncludeParam>),
+      Fetch
+    }
     impl Into<super::IncludeParam> for Include {
       fn into(self) -> super::IncludeParam {
-        super::IncludeParam::Date(self)
+        super::IncludeParam::Receipt(self)
       }
     }
     impl Include {
+      pub fn select(
+        nested_selections: Vec<super::super::receipt::SelectParam>
+      ) -> Self {
+        Self::Select(nested_selections)
+      }
+      pub fn include(
+        nested_selections: Vec<super::super::receipt::IncludeParam>
+      ) -> Self {
+        Self::Include(nested_selections)
+      }
       pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-        ::prisma_client_rust::Selection::builder("date").build()
+        let mut selection = ::prisma_client_rust::Selection::builder("receipt");
+        match self {
+          Self::Select(selections) => {
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(selections) => {
+            let mut nested_selections = super::super::receipt::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch => {
+            selection.nested_selections(super::super::receipt::_outputs());
+          }
+        }
+        selection.build()
       }
     }
-    pub struct Select;
+    pub enum Select {
+      Select(Vec<super::super::receipt::SelectParam>),
+      Include(Vec<super::super::receipt::IncludeParam>),
+      Fetch
+    }
     impl Into<super::SelectParam> for Select {
       fn into(self) -> super::SelectParam {
-        super::SelectParam::Date(self)
+        super::SelectParam::Receipt(self)
       }
     }
     impl Select {
+      pub fn select(
+        nested_selections: Vec<super::super::receipt::SelectParam>
+      ) -> Self {
+        Self::Select(nested_selections)
+      }
+      pub fn include(
+        nested_selections: Vec<super::super::receipt::IncludeParam>
+      ) -> Self {
+        Self::Include(nested_selections)
+      }
       pub fn to_selection(self) -> ::prisma_client_rust::Selection {
-        ::prisma_client_rust::Selection::builder("date").build()
+        let mut selection = ::prisma_client_rust::Selection::builder("receipt");
+        match self {
+          Self::Select(selections) => {
+            selection.nested_selections(
+              selections.into_iter().map(|s| s.to_selection()).collect()
+            );
+          }
+          Self::Include(selections) => {
+            let mut nested_selections = super::super::receipt::_outputs();
+            nested_selections
+              .extend(selections.into_iter().map(|s| s.to_selection()));
+            selection.nested_selections(nested_selections);
+          }
+          Self::Fetch => {
+            selection.nested_selections(super::super::receipt::_outputs());
+          }
+        }
+        selection.build()
       }
     }
   }
@@ -641,8 +5745,11 @@ pub mod food {
       "name",
       "price",
       "amount",
+      "unit",
       "total",
-      "date"
+      "date",
+      "store_id",
+      "receipt_id"
     ]
     .into_iter()
     .map(|o| {
@@ -652,75 +5759,99 @@ pub mod food {
     .collect()
   }
   pub fn create(
-    food_type: String,
+    category: super::category::UniqueWhereParam,
     name: String,
-    price: f64,
+    price: i32,
     amount: f64,
-    total: f64,
+    total: i32,
     date: ::prisma_client_rust::chrono::DateTime<
       ::prisma_client_rust::chrono::FixedOffset
     >,
     _params: Vec<SetParam>
   ) -> (
+    super::category::UniqueWhereParam,
     String,
-    String,
-    f64,
-    f64,
+    i32,
     f64,
+    i32,
     ::prisma_client_rust::chrono::DateTime<
       ::prisma_client_rust::chrono::FixedOffset
     >,
     Vec<SetParam>
   ) {
-    (food_type, name, price, amount, total, date, _params)
+    (category, name, price, amount, total, date, _params)
   }
   #[macro_export]
-  macro_rules ! _select_food { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: food :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: food :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Select { Select ($ crate :: prisma :: food :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: food :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: food :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Select ($ crate :: prisma :: food :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , food_type , name , price , amount , total , date } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug)] pub struct Data { $ (pub $ field : $ crate :: prisma :: food :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (food_type) , stringify ! (name) , stringify ! (price) , stringify ! (amount) , stringify ! (total) , stringify ! (date)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: food :: select ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: food :: select ! (@ field_serde_name ; $ field) , ", ") , +)) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: food :: select ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: select ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: select ! (@ field_serde_name ; $ field))) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "food_type" , "name" , "price" , "amount" , "total" , "date"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: food :: select ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { i32 } ; (@ field_type ; food_type) => { String } ; (@ field_type ; name) => { String } ; (@ field_type ; price) => { f64 } ; (@ field_type ; amount) => { f64 } ; (@ field_type ; total) => { f64 } ; (@ field_type ; date) => { :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot select field nonexistent field $ field on model "Food" , available fields are "id, food_type, name, price, amount, total, date")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: id :: Select) } ; (@ selection_field_to_selection_param ; food_type) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: food_type :: Select) } ; (@ selection_field_to_selection_param ; name) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: name :: Select) } ; (@ selection_field_to_selection_param ; price) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: price :: Select) } ; (@ selection_field_to_selection_param ; amount) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: amount :: Select) } ; (@ selection_field_to_selection_param ; total) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: total :: Select) } ; (@ selection_field_to_selection_param ; date) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: date :: Select) } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_select_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: food :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; food_type) => { "food_type" } ; (@ field_serde_name ; name) => { "name" } ; (@ field_serde_name ; price) => { "price" } ; (@ field_serde_name ; amount) => { "amount" } ; (@ field_serde_name ; total) => { "total" } ; (@ field_serde_name ; date) => { "date" } ; }
+  macro_rules ! _select_food { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: food :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: food :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Select { Select ($ crate :: prisma :: food :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: food :: select ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Select (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: select :: SelectType for Select { type Data = Data ; type ModelData = $ crate :: prisma :: food :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Select ($ crate :: prisma :: food :: select ! (@ selections_to_select_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect ()) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , food_type , category , name , price , amount , unit , total , date , store_id , store , receipt_id , receipt } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug)] pub struct Data { $ (pub $ field : $ crate :: prisma :: food :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (food_type) , stringify ! (category) , stringify ! (name) , stringify ! (price) , stringify ! (amount) , stringify ! (unit) , stringify ! (total) , stringify ! (date) , stringify ! (store_id) , stringify ! (store) , stringify ! (receipt_id) , stringify ! (receipt)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: food :: select ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: food :: select ! (@ field_serde_name ; $ field) , ", ") , +)) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: food :: select ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: select ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: select ! (@ field_serde_name ; $ field))) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "food_type" , "category" , "name" , "price" , "amount" , "unit" , "total" , "date" , "store_id" , "store" , "receipt_id" , "receipt"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: food :: select ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { i32 } ; (@ field_type ; food_type) => { String } ; (@ field_type ; category : $ selection_mode : ident { $ ($ selections : tt) + }) => { category :: Data } ; (@ field_type ; category) => { $ crate :: prisma :: category :: Data } ; (@ field_type ; name) => { String } ; (@ field_type ; price) => { i32 } ; (@ field_type ; amount) => { f64 } ; (@ field_type ; unit) => { String } ; (@ field_type ; total) => { i32 } ; (@ field_type ; date) => { :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > } ; (@ field_type ; store_id) => { Option < i32 > } ; (@ field_type ; store : $ selection_mode : ident { $ ($ selections : tt) + }) => { Option < store :: Data > } ; (@ field_type ; store) => { Option < $ crate :: prisma :: store :: Data > } ; (@ field_type ; receipt_id) => { Option < i32 > } ; (@ field_type ; receipt : $ selection_mode : ident { $ ($ selections : tt) + }) => { Option < receipt :: Data > } ; (@ field_type ; receipt) => { Option < $ crate :: prisma :: receipt :: Data > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot select field nonexistent field $ field on model "Food" , available fields are "id, food_type, category, name, price, amount, unit, total, date, store_id, store, receipt_id, receipt")) } ; (@ field_module ; category : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: category :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; store : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: store :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; receipt : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: receipt :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: id :: Select) } ; (@ selection_field_to_selection_param ; food_type) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: food_type :: Select) } ; (@ selection_field_to_selection_param ; category : $ selection_mode : ident { $ ($ selections : tt) + }) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: category :: Select :: $ selection_mode ($ crate :: prisma :: category :: $ selection_mode ! (@ selections_to_select_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } ; (@ selection_field_to_selection_param ; category) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: category :: Select :: Fetch) } ; (@ selection_field_to_selection_param ; name) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: name :: Select) } ; (@ selection_field_to_selection_param ; price) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: price :: Select) } ; (@ selection_field_to_selection_param ; amount) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: amount :: Select) } ; (@ selection_field_to_selection_param ; unit) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: unit :: Select) } ; (@ selection_field_to_selection_param ; total) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: total :: Select) } ; (@ selection_field_to_selection_param ; date) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: date :: Select) } ; (@ selection_field_to_selection_param ; store_id) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: store_id :: Select) } ; (@ selection_field_to_selection_param ; store : $ selection_mode : ident { $ ($ selections : tt) + }) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: store :: Select :: $ selection_mode ($ crate :: prisma :: store :: $ selection_mode ! (@ selections_to_select_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } ; (@ selection_field_to_selection_param ; store) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: store :: Select :: Fetch) } ; (@ selection_field_to_selection_param ; receipt_id) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: receipt_id :: Select) } ; (@ selection_field_to_selection_param ; receipt : $ selection_mode : ident { $ ($ selections : tt) + }) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: receipt :: Select :: $ selection_mode ($ crate :: prisma :: receipt :: $ selection_mode ! (@ selections_to_select_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } ; (@ selection_field_to_selection_param ; receipt) => { Into :: < $ crate :: prisma :: food :: SelectParam > :: into ($ crate :: prisma :: food :: receipt :: Select :: Fetch) } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_select_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: food :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; food_type) => { "food_type" } ; (@ field_serde_name ; category) => { "category" } ; (@ field_serde_name ; name) => { "name" } ; (@ field_serde_name ; price) => { "price" } ; (@ field_serde_name ; amount) => { "amount" } ; (@ field_serde_name ; unit) => { "unit" } ; (@ field_serde_name ; total) => { "total" } ; (@ field_serde_name ; date) => { "date" } ; (@ field_serde_name ; store_id) => { "store_id" } ; (@ field_serde_name ; store) => { "store" } ; (@ field_serde_name ; receipt_id) => { "receipt_id" } ; (@ field_serde_name ; receipt) => { "receipt" } ; }
   pub use _select_food as select;
   pub enum SelectParam {
     Id(id::Select),
     FoodType(food_type::Select),
+    Category(category::Select),
     Name(name::Select),
     Price(price::Select),
     Amount(amount::Select),
+    Unit(unit::Select),
     Total(total::Select),
-    Date(date::Select)
+    Date(date::Select),
+    StoreId(store_id::Select),
+    Store(store::Select),
+    ReceiptId(receipt_id::Select),
+    Receipt(receipt::Select)
   }
   impl SelectParam {
     pub fn to_selection(self) -> ::prisma_client_rust::Selection {
       match self {
         Self::Id(data) => data.to_selection(),
         Self::FoodType(data) => data.to_selection(),
+        Self::Category(data) => data.to_selection(),
         Self::Name(data) => data.to_selection(),
         Self::Price(data) => data.to_selection(),
         Self::Amount(data) => data.to_selection(),
+        Self::Unit(data) => data.to_selection(),
         Self::Total(data) => data.to_selection(),
-        Self::Date(data) => data.to_selection()
+        Self::Date(data) => data.to_selection(),
+        Self::StoreId(data) => data.to_selection(),
+        Self::Store(data) => data.to_selection(),
+        Self::ReceiptId(data) => data.to_selection(),
+        Self::Receipt(data) => data.to_selection()
       }
     }
   }
   #[macro_export]
-  macro_rules ! _include_food { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: food :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: food :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Include { let mut selections = $ crate :: prisma :: food :: _outputs () ; selections . extend ($ crate :: prisma :: food :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; Include (selections) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: food :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: food :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Include ({ let mut selections = $ crate :: prisma :: food :: _outputs () ; selections . extend ($ crate :: prisma :: food :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; selections }) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug)] pub struct Data { pub id : i32 , pub food_type : String , pub name : String , pub price : f64 , pub amount : f64 , pub total : f64 , pub date : :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > , $ (pub $ field : $ crate :: prisma :: food :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (food_type) , stringify ! (name) , stringify ! (price) , stringify ! (amount) , stringify ! (total) , stringify ! (date)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; id) , & self . id) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; food_type) , & self . food_type) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; name) , & self . name) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; price) , & self . price) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; amount) , & self . amount) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; total) , & self . total) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; date) , & self . date) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , food_type , name , price , amount , total , date } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: food :: include ! (@ field_serde_name ; $ field) , ", ") , + , $ crate :: prisma :: food :: include ! (@ field_serde_name ; id) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; food_type) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; name) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; price) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; amount) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; total) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; date) , ", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: food :: include ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , $ crate :: prisma :: food :: include ! (@ field_serde_name ; id) => Ok (Field :: id) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; food_type) => Ok (Field :: food_type) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; name) => Ok (Field :: name) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; price) => Ok (Field :: price) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; amount) => Ok (Field :: amount) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; total) => Ok (Field :: total) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; date) => Ok (Field :: date) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut food_type = None ; let mut name = None ; let mut price = None ; let mut amount = None ; let mut total = None ; let mut date = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; id))) ; } id = Some (map . next_value () ?) ; } Field :: food_type => { if food_type . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; food_type))) ; } food_type = Some (map . next_value () ?) ; } Field :: name => { if name . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; name))) ; } name = Some (map . next_value () ?) ; } Field :: price => { if price . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; price))) ; } price = Some (map . next_value () ?) ; } Field :: amount => { if amount . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; amount))) ; } amount = Some (map . next_value () ?) ; } Field :: total => { if total . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; total))) ; } total = Some (map . next_value () ?) ; } Field :: date => { if date . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; date))) ; } date = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; $ field))) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; id))) ? ; let food_type = food_type . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; food_type))) ? ; let name = name . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; name))) ? ; let price = price . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; price))) ? ; let amount = amount . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; amount))) ? ; let total = total . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; total))) ? ; let date = date . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; date))) ? ; Ok (Data { id , food_type , name , price , amount , total , date , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "food_type" , "name" , "price" , "amount" , "total" , "date"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: food :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "Food" , available relations are "")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_include_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: food :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; food_type) => { "food_type" } ; (@ field_serde_name ; name) => { "name" } ; (@ field_serde_name ; price) => { "price" } ; (@ field_serde_name ; amount) => { "amount" } ; (@ field_serde_name ; total) => { "total" } ; (@ field_serde_name ; date) => { "date" } ; }
+  macro_rules ! _include_food { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { $ crate :: prisma :: food :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: food :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } use super :: * ; pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Include { let mut selections = $ crate :: prisma :: food :: _outputs () ; selections . extend ($ crate :: prisma :: food :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; Include (selections) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { $ crate :: prisma :: food :: include ! (@ definitions ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Include (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: include :: IncludeType for Include { type Data = Data ; type ModelData = $ crate :: prisma :: food :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Include ({ let mut selections = $ crate :: prisma :: food :: _outputs () ; selections . extend ($ crate :: prisma :: food :: include ! (@ selections_to_include_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ())) ; selections }) } } ; (@ definitions ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { category , store , receipt } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug)] pub struct Data { pub id : i32 , pub food_type : String , pub name : String , pub price : i32 , pub amount : f64 , pub unit : String , pub total : i32 , pub date : :: prisma_client_rust :: chrono :: DateTime < :: prisma_client_rust :: chrono :: FixedOffset , > , pub store_id : Option < i32 > , pub receipt_id : Option < i32 > , $ (pub $ field : $ crate :: prisma :: food :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (food_type) , stringify ! (name) , stringify ! (price) , stringify ! (amount) , stringify ! (unit) , stringify ! (total) , stringify ! (date) , stringify ! (store_id) , stringify ! (receipt_id)] . len ()) ? ; $ (state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; $ field) , & self . $ field) ? ;) * state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; id) , & self . id) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; food_type) , & self . food_type) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; name) , & self . name) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; price) , & self . price) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; amount) , & self . amount) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; unit) , & self . unit) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; total) , & self . total) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; date) , & self . date) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; store_id) , & self . store_id) ? ; state . serialize_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; receipt_id) , & self . receipt_id) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , food_type , name , price , amount , unit , total , date , store_id , receipt_id } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (concat ! ($ ($ crate :: prisma :: food :: include ! (@ field_serde_name ; $ field) , ", ") , + , $ crate :: prisma :: food :: include ! (@ field_serde_name ; id) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; food_type) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; name) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; price) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; amount) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; unit) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; total) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; date) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; store_id) , ", " , $ crate :: prisma :: food :: include ! (@ field_serde_name ; receipt_id) , ", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ ($ crate :: prisma :: food :: include ! (@ field_serde_name ; $ field) => Ok (Field :: $ field)) , * , $ crate :: prisma :: food :: include ! (@ field_serde_name ; id) => Ok (Field :: id) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; food_type) => Ok (Field :: food_type) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; name) => Ok (Field :: name) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; price) => Ok (Field :: price) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; amount) => Ok (Field :: amount) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; unit) => Ok (Field :: unit) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; total) => Ok (Field :: total) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; date) => Ok (Field :: date) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; store_id) => Ok (Field :: store_id) , $ crate :: prisma :: food :: include ! (@ field_serde_name ; receipt_id) => Ok (Field :: receipt_id) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut food_type = None ; let mut name = None ; let mut price = None ; let mut amount = None ; let mut unit = None ; let mut total = None ; let mut date = None ; let mut store_id = None ; let mut receipt_id = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; id))) ; } id = Some (map . next_value () ?) ; } Field :: food_type => { if food_type . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; food_type))) ; } food_type = Some (map . next_value () ?) ; } Field :: name => { if name . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; name))) ; } name = Some (map . next_value () ?) ; } Field :: price => { if price . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; price))) ; } price = Some (map . next_value () ?) ; } Field :: amount => { if amount . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; amount))) ; } amount = Some (map . next_value () ?) ; } Field :: unit => { if unit . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; unit))) ; } unit = Some (map . next_value () ?) ; } Field :: total => { if total . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; total))) ; } total = Some (map . next_value () ?) ; } Field :: date => { if date . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; date))) ; } date = Some (map . next_value () ?) ; } Field :: store_id => { if store_id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; store_id))) ; } store_id = Some (map . next_value () ?) ; } Field :: receipt_id => { if receipt_id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; receipt_id))) ; } receipt_id = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; $ field))) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; $ field))) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; id))) ? ; let food_type = food_type . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; food_type))) ? ; let name = name . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; name))) ? ; let price = price . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; price))) ? ; let amount = amount . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; amount))) ? ; let unit = unit . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; unit))) ? ; let total = total . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; total))) ? ; let date = date . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; date))) ? ; let store_id = store_id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; store_id))) ? ; let receipt_id = receipt_id . ok_or_else (|| serde :: de :: Error :: missing_field ($ crate :: prisma :: food :: include ! (@ field_serde_name ; receipt_id))) ? ; Ok (Data { id , food_type , name , price , amount , unit , total , date , store_id , receipt_id , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "food_type" , "category" , "name" , "price" , "amount" , "unit" , "total" , "date" , "store_id" , "store" , "receipt_id" , "receipt"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { $ crate :: prisma :: food :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; category : $ selection_mode : ident { $ ($ selections : tt) + }) => { category :: Data } ; (@ field_type ; category) => { $ crate :: prisma :: category :: Data } ; (@ field_type ; store : $ selection_mode : ident { $ ($ selections : tt) + }) => { Option < store :: Data > } ; (@ field_type ; store) => { Option < $ crate :: prisma :: store :: Data > } ; (@ field_type ; receipt : $ selection_mode : ident { $ ($ selections : tt) + }) => { Option < receipt :: Data > } ; (@ field_type ; receipt) => { Option < $ crate :: prisma :: receipt :: Data > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "Food" , available relations are "category, store, receipt")) } ; (@ field_module ; category : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: category :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; store : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: store :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; receipt : $ selection_mode : ident { $ ($ selections : tt) + }) => { $ crate :: prisma :: receipt :: $ selection_mode ! (@ definitions ; $ ($ selections) +) ; } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; category : $ selection_mode : ident { $ ($ selections : tt) + }) => { Into :: < $ crate :: prisma :: food :: IncludeParam > :: into ($ crate :: prisma :: food :: category :: Include :: $ selection_mode ($ crate :: prisma :: category :: $ selection_mode ! (@ selections_to_include_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } ; (@ selection_field_to_selection_param ; category) => { Into :: < $ crate :: prisma :: food :: IncludeParam > :: into ($ crate :: prisma :: food :: category :: Include :: Fetch) } ; (@ selection_field_to_selection_param ; store : $ selection_mode : ident { $ ($ selections : tt) + }) => { Into :: < $ crate :: prisma :: food :: IncludeParam > :: into ($ crate :: prisma :: food :: store :: Include :: $ selection_mode ($ crate :: prisma :: store :: $ selection_mode ! (@ selections_to_include_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } ; (@ selection_field_to_selection_param ; store) => { Into :: < $ crate :: prisma :: food :: IncludeParam > :: into ($ crate :: prisma :: food :: store :: Include :: Fetch) } ; (@ selection_field_to_selection_param ; receipt : $ selection_mode : ident { $ ($ selections : tt) + }) => { Into :: < $ crate :: prisma :: food :: IncludeParam > :: into ($ crate :: prisma :: food :: receipt :: Include :: $ selection_mode ($ crate :: prisma :: receipt :: $ selection_mode ! (@ selections_to_include_params ; : $ selection_mode { $ ($ selections) + }) . into_iter () . collect ())) } ; (@ selection_field_to_selection_param ; receipt) => { Into :: < $ crate :: prisma :: food :: IncludeParam > :: into ($ crate :: prisma :: food :: receipt :: Include :: Fetch) } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_include_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ ($ crate :: prisma :: food :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; food_type) => { "food_type" } ; (@ field_serde_name ; category) => { "category" } ; (@ field_serde_name ; name) => { "name" } ; (@ field_serde_name ; price) => { "price" } ; (@ field_serde_name ; amount) => { "amount" } ; (@ field_serde_name ; unit) => { "unit" } ; (@ field_serde_name ; total) => { "total" } ; (@ field_serde_name ; date) => { "date" } ; (@ field_serde_name ; store_id) => { "store_id" } ; (@ field_serde_name ; store) => { "store" } ; (@ field_serde_name ; receipt_id) => { "receipt_id" } ; (@ field_serde_name ; receipt) => { "receipt" } ; }
   pub use _include_food as include;
   pub enum IncludeParam {
     Id(id::Include),
     FoodType(food_type::Include),
+    Category(category::Include),
     Name(name::Include),
     Price(price::Include),
     Amount(amount::Include),
+    Unit(unit::Include),
     Total(total::Include),
-    Date(date::Include)
+    Date(date::Include),
+    StoreId(store_id::Include),
+    Store(store::Include),
+    ReceiptId(receipt_id::Include),
+    Receipt(receipt::Include)
   }
   impl IncludeParam {
     pub fn to_selection(self) -> ::prisma_client_rust::Selection {
       match self {
         Self::Id(data) => data.to_selection(),
         Self::FoodType(data) => data.to_selection(),
+        Self::Category(data) => data.to_selection(),
         Self::Name(data) => data.to_selection(),
         Self::Price(data) => data.to_selection(),
         Self::Amount(data) => data.to_selection(),
+        Self::Unit(data) => data.to_selection(),
         Self::Total(data) => data.to_selection(),
-        Self::Date(data) => data.to_selection()
+        Self::Date(data) => data.to_selection(),
+        Self::StoreId(data) => data.to_selection(),
+        Self::Store(data) => data.to_selection(),
+        Self::ReceiptId(data) => data.to_selection(),
+        Self::Receipt(data) => data.to_selection()
       }
     }
   }
@@ -730,25 +5861,126 @@ pub mod food {
     pub id: i32,
     #[serde(rename = "food_type")]
     pub food_type: String,
+    #[serde(rename = "category")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<Box<super::category::Data>>,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "price")]
-    pub price: f64,
+    pub price: i32,
     #[serde(rename = "amount")]
     pub amount: f64,
+    #[serde(rename = "unit")]
+    pub unit: String,
     #[serde(rename = "total")]
-    pub total: f64,
+    pub total: i32,
     #[serde(rename = "date")]
     pub date: ::prisma_client_rust::chrono::DateTime<
       ::prisma_client_rust::chrono::FixedOffset
-    >
+    >,
+    #[serde(rename = "store_id")]
+    pub store_id: Option<i32>,
+    #[serde(rename = "store")]
+    #[serde(default, with = "::prisma_client_rust::serde::double_option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<Option<Box<super::store::Data>>>,
+    #[serde(rename = "receipt_id")]
+    pub receipt_id: Option<i32>,
+    #[serde(rename = "receipt")]
+    #[serde(default, with = "::prisma_client_rust::serde::double_option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt: Option<Option<Box<super::receipt::Data>>>
+  }
+  impl Data {
+    pub fn category(
+      &self
+    ) -> Result<
+      &super::category::Data,
+      ::prisma_client_rust::RelationNotFetchedError
+    > {
+      self
+        .category
+        .as_ref()
+        .ok_or(::prisma_client_rust::RelationNotFetchedError::new(
+          stringify!(category)
+        ))
+        .map(|v| v.as_ref())
+    }
+    pub fn store(
+      &self
+    ) -> Result<
+      Option<&super::store::Data>,
+      ::prisma_client_rust::RelationNotFetchedError
+    > {
+      self
+        .store
+        .as_ref()
+        .ok_or(::prisma_client_rust::RelationNotFetchedError::new(
+          stringify!(store)
+        ))
+        .map(|v| v.as_ref().map(|v| v.as_ref()))
+    }
+    pub fn receipt(
+      &self
+    ) -> Result<
+      Option<&super::receipt::Data>,
+      ::prisma_client_rust::RelationNotFetchedError
+    > {
+      self
+        .receipt
+        .as_ref()
+        .ok_or(::prisma_client_rust::RelationNotFetchedError::new(
+          stringify!(receipt)
+        ))
+        .map(|v| v.as_ref().map(|v| v.as_ref()))
+    }
   }
-  impl Data {}
   #[derive(Clone)]
-  pub enum WithParam {}
+  pub enum WithParam {
+    Category(super::category::UniqueArgs),
+    Store(super::store::UniqueArgs),
+    Receipt(super::receipt::UniqueArgs)
+  }
   impl Into<::prisma_client_rust::Selection> for WithParam {
     fn into(self) -> ::prisma_client_rust::Selection {
-      match self {}
+      match self {
+        Self::Category(args) => {
+          let mut selections = super::category::_outputs();
+          selections.extend(
+            args
+              .with_params
+              .into_iter()
+              .map(Into::<::prisma_client_rust::Selection>::into)
+          );
+          ::prisma_client_rust::Selection::builder("category")
+            .nested_selections(selections)
+            .build()
+        }
+        Self::Store(args) => {
+          let mut selections = super::store::_outputs();
+          selections.extend(
+            args
+              .with_params
+              .into_iter()
+              .map(Into::<::prisma_client_rust::Selection>::into)
+          );
+          ::prisma_client_rust::Selection::builder("store")
+            .nested_selections(selections)
+            .build()
+        }
+        Self::Receipt(args) => {
+          let mut selections = super::receipt::_outputs();
+          selections.extend(
+            args
+              .with_params
+              .into_iter()
+              .map(Into::<::prisma_client_rust::Selection>::into)
+          );
+          ::prisma_client_rust::Selection::builder("receipt")
+            .nested_selections(selections)
+            .build()
+        }
+      }
     }
   }
   #[derive(Clone)]
@@ -759,31 +5991,47 @@ pub mod food {
     MultiplyId(i32),
     DivideId(i32),
     SetFoodType(String),
+    ConnectCategory(super::category::UniqueWhereParam),
     SetName(String),
-    SetPrice(f64),
-    IncrementPrice(f64),
-    DecrementPrice(f64),
-    MultiplyPrice(f64),
-    DividePrice(f64),
+    SetPrice(i32),
+    IncrementPrice(i32),
+    DecrementPrice(i32),
+    MultiplyPrice(i32),
+    DividePrice(i32),
     SetAmount(f64),
     IncrementAmount(f64),
     DecrementAmount(f64),
     MultiplyAmount(f64),
     DivideAmount(f64),
-    SetTotal(f64),
-    IncrementTotal(f64),
-    DecrementTotal(f64),
-    MultiplyTotal(f64),
-    DivideTotal(f64),
+    SetUnit(String),
+    SetTotal(i32),
+    IncrementTotal(i32),
+    DecrementTotal(i32),
+    MultiplyTotal(i32),
+    DivideTotal(i32),
     SetDate(
       ::prisma_client_rust::chrono::DateTime<
         ::prisma_client_rust::chrono::FixedOffset
       >
-    )
+    ),
+    SetStoreId(Option<i32>),
+    IncrementStoreId(i32),
+    DecrementStoreId(i32),
+    MultiplyStoreId(i32),
+    DivideStoreId(i32),
+    ConnectStore(super::store::UniqueWhereParam),
+    DisconnectStore,
+    SetReceiptId(Option<i32>),
+    IncrementReceiptId(i32),
+    DecrementReceiptId(i32),
+    MultiplyReceiptId(i32),
+    DivideReceiptId(i32),
+    ConnectReceipt(super::receipt::UniqueWhereParam),
+    DisconnectReceipt
   }
   impl Into<(String, ::prisma_client_rust::PrismaValue)> for SetParam {
     fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
-      match self { SetParam :: SetId (value) => ("id" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64)) , SetParam :: IncrementId (value) => ("id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DecrementId (value) => ("id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: MultiplyId (value) => ("id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DivideId (value) => ("id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: SetFoodType (value) => ("food_type" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetName (value) => ("name" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetPrice (value) => ("price" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ())) , SetParam :: IncrementPrice (value) => ("price" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DecrementPrice (value) => ("price" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: MultiplyPrice (value) => ("price" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DividePrice (value) => ("price" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: SetAmount (value) => ("amount" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ())) , SetParam :: IncrementAmount (value) => ("amount" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DecrementAmount (value) => ("amount" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: MultiplyAmount (value) => ("amount" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DivideAmount (value) => ("amount" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: SetTotal (value) => ("total" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ())) , SetParam :: IncrementTotal (value) => ("total" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DecrementTotal (value) => ("total" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: MultiplyTotal (value) => ("total" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DivideTotal (value) => ("total" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: SetDate (value) => ("date" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value)) }
+      match self { SetParam :: SetId (value) => ("id" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64)) , SetParam :: IncrementId (value) => ("id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DecrementId (value) => ("id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: MultiplyId (value) => ("id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DivideId (value) => ("id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: SetFoodType (value) => ("food_type" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: ConnectCategory (where_param) => ("category" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("connect" . to_string () , :: prisma_client_rust :: PrismaValue :: Object ([where_param] . into_iter () . map (Into :: < super :: category :: WhereParam > :: into) . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , SetParam :: SetName (value) => ("name" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetPrice (value) => ("price" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64)) , SetParam :: IncrementPrice (value) => ("price" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DecrementPrice (value) => ("price" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: MultiplyPrice (value) => ("price" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DividePrice (value) => ("price" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: SetAmount (value) => ("amount" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ())) , SetParam :: IncrementAmount (value) => ("amount" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DecrementAmount (value) => ("amount" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: MultiplyAmount (value) => ("amount" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: DivideAmount (value) => ("amount" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , SetParam :: SetUnit (value) => ("unit" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value)) , SetParam :: SetTotal (value) => ("total" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64)) , SetParam :: IncrementTotal (value) => ("total" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DecrementTotal (value) => ("total" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: MultiplyTotal (value) => ("total" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DivideTotal (value) => ("total" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: SetDate (value) => ("date" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value)) , SetParam :: SetStoreId (value) => ("store_id" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Int (value as i64)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: IncrementStoreId (value) => ("store_id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DecrementStoreId (value) => ("store_id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: MultiplyStoreId (value) => ("store_id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DivideStoreId (value) => ("store_id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: ConnectStore (where_param) => ("store" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("connect" . to_string () , :: prisma_client_rust :: PrismaValue :: Object ([where_param] . into_iter () . map (Into :: < super :: store :: WhereParam > :: into) . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , SetParam :: DisconnectStore => ("store" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("disconnect" . to_string () , :: prisma_client_rust :: PrismaValue :: Boolean (true))])) , SetParam :: SetReceiptId (value) => ("receipt_id" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Int (value as i64)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null)) , SetParam :: IncrementReceiptId (value) => ("receipt_id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("increment" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DecrementReceiptId (value) => ("receipt_id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("decrement" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: MultiplyReceiptId (value) => ("receipt_id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("multiply" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: DivideReceiptId (value) => ("receipt_id" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("divide" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , SetParam :: ConnectReceipt (where_param) => ("receipt" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("connect" . to_string () , :: prisma_client_rust :: PrismaValue :: Object ([where_param] . into_iter () . map (Into :: < super :: receipt :: WhereParam > :: into) . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (:: prisma_client_rust :: SerializedWhere :: transform_equals) . collect ()))])) , SetParam :: DisconnectReceipt => ("receipt" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (vec ! [("disconnect" . to_string () , :: prisma_client_rust :: PrismaValue :: Boolean (true))])) }
     }
   }
   #[derive(Clone)]
@@ -793,8 +6041,11 @@ pub mod food {
     Name(::prisma_client_rust::Direction),
     Price(::prisma_client_rust::Direction),
     Amount(::prisma_client_rust::Direction),
+    Unit(::prisma_client_rust::Direction),
     Total(::prisma_client_rust::Direction),
-    Date(::prisma_client_rust::Direction)
+    Date(::prisma_client_rust::Direction),
+    StoreId(::prisma_client_rust::Direction),
+    ReceiptId(::prisma_client_rust::Direction)
   }
   impl Into<(String, ::prisma_client_rust::PrismaValue)> for OrderByParam {
     fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
@@ -819,6 +6070,10 @@ pub mod food {
           "amount".to_string(),
           ::prisma_client_rust::PrismaValue::String(direction.to_string())
         ),
+        Self::Unit(direction) => (
+          "unit".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        ),
         Self::Total(direction) => (
           "total".to_string(),
           ::prisma_client_rust::PrismaValue::String(direction.to_string())
@@ -826,6 +6081,14 @@ pub mod food {
         Self::Date(direction) => (
           "date".to_string(),
           ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        ),
+        Self::StoreId(direction) => (
+          "store_id".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
+        ),
+        Self::ReceiptId(direction) => (
+          "receipt_id".to_string(),
+          ::prisma_client_rust::PrismaValue::String(direction.to_string())
         )
       }
     }
@@ -855,6 +6118,8 @@ pub mod food {
     FoodTypeEndsWith(String),
     FoodTypeMode(QueryMode),
     FoodTypeNot(String),
+    CategoryIs(Vec<super::category::WhereParam>),
+    CategoryIsNot(Vec<super::category::WhereParam>),
     NameEquals(String),
     NameInVec(Vec<String>),
     NameNotInVec(Vec<String>),
@@ -867,14 +6132,14 @@ pub mod food {
     NameEndsWith(String),
     NameMode(QueryMode),
     NameNot(String),
-    PriceEquals(f64),
-    PriceInVec(Vec<f64>),
-    PriceNotInVec(Vec<f64>),
-    PriceLt(f64),
-    PriceLte(f64),
-    PriceGt(f64),
-    PriceGte(f64),
-    PriceNot(f64),
+    PriceEquals(i32),
+    PriceInVec(Vec<i32>),
+    PriceNotInVec(Vec<i32>),
+    PriceLt(i32),
+    PriceLte(i32),
+    PriceGt(i32),
+    PriceGte(i32),
+    PriceNot(i32),
     AmountEquals(f64),
     AmountInVec(Vec<f64>),
     AmountNotInVec(Vec<f64>),
@@ -883,14 +6148,26 @@ pub mod food {
     AmountGt(f64),
     AmountGte(f64),
     AmountNot(f64),
-    TotalEquals(f64),
-    TotalInVec(Vec<f64>),
-    TotalNotInVec(Vec<f64>),
-    TotalLt(f64),
-    TotalLte(f64),
-    TotalGt(f64),
-    TotalGte(f64),
-    TotalNot(f64),
+    UnitEquals(String),
+    UnitInVec(Vec<String>),
+    UnitNotInVec(Vec<String>),
+    UnitLt(String),
+    UnitLte(String),
+    UnitGt(String),
+    UnitGte(String),
+    UnitContains(String),
+    UnitStartsWith(String),
+    UnitEndsWith(String),
+    UnitMode(QueryMode),
+    UnitNot(String),
+    TotalEquals(i32),
+    TotalInVec(Vec<i32>),
+    TotalNotInVec(Vec<i32>),
+    TotalLt(i32),
+    TotalLte(i32),
+    TotalGt(i32),
+    TotalGte(i32),
+    TotalNot(i32),
     DateEquals(
       ::prisma_client_rust::chrono::DateTime<
         ::prisma_client_rust::chrono::FixedOffset
@@ -934,11 +6211,31 @@ pub mod food {
       ::prisma_client_rust::chrono::DateTime<
         ::prisma_client_rust::chrono::FixedOffset
       >
-    )
+    ),
+    StoreIdEquals(Option<i32>),
+    StoreIdInVec(Vec<i32>),
+    StoreIdNotInVec(Vec<i32>),
+    StoreIdLt(i32),
+    StoreIdLte(i32),
+    StoreIdGt(i32),
+    StoreIdGte(i32),
+    StoreIdNot(Option<i32>),
+    StoreIs(Vec<super::store::WhereParam>),
+    StoreIsNot(Vec<super::store::WhereParam>),
+    ReceiptIdEquals(Option<i32>),
+    ReceiptIdInVec(Vec<i32>),
+    ReceiptIdNotInVec(Vec<i32>),
+    ReceiptIdLt(i32),
+    ReceiptIdLte(i32),
+    ReceiptIdGt(i32),
+    ReceiptIdGte(i32),
+    ReceiptIdNot(Option<i32>),
+    ReceiptIs(Vec<super::receipt::WhereParam>),
+    ReceiptIsNot(Vec<super::receipt::WhereParam>)
   }
   impl Into<::prisma_client_rust::SerializedWhere> for WhereParam {
     fn into(self) -> ::prisma_client_rust::SerializedWhere {
-      match self { Self :: Not (value) => :: prisma_client_rust :: SerializedWhere :: new ("NOT" , :: prisma_client_rust :: SerializedWhereValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()) ,) , Self :: Or (value) => :: prisma_client_rust :: SerializedWhere :: new ("OR" , :: prisma_client_rust :: SerializedWhereValue :: List (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . map (| v | vec ! [v]) . map (:: prisma_client_rust :: PrismaValue :: Object) . collect ()) ,) , Self :: And (value) => :: prisma_client_rust :: SerializedWhere :: new ("AND" , :: prisma_client_rust :: SerializedWhereValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()) ,) , Self :: IdEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IdInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: IdNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: IdLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IdLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IdGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IdGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IdNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: FoodTypeEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: FoodTypeNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: FoodTypeLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeMode (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("mode" . to_string () , :: prisma_client_rust :: PrismaValue :: Enum (value . to_string ()))])) , Self :: FoodTypeNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: NameNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: NameLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameMode (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("mode" . to_string () , :: prisma_client_rust :: PrismaValue :: Enum (value . to_string ()))])) , Self :: NameNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: PriceEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: PriceInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: PriceNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: PriceLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: PriceLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: PriceGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: PriceGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: PriceNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: AmountEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: AmountInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: AmountNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: AmountLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: AmountLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: AmountGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: AmountGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: AmountNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: TotalEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: TotalInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: TotalNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: TotalLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: TotalLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: TotalGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: TotalGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: TotalNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: DateEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: DateInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: DateTime (v)) . collect ()))])) , Self :: DateNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: DateTime (v)) . collect ()))])) , Self :: DateLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: DateLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: DateGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: DateGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: DateNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) }
+      match self { Self :: Not (value) => :: prisma_client_rust :: SerializedWhere :: new ("NOT" , :: prisma_client_rust :: SerializedWhereValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()) ,) , Self :: Or (value) => :: prisma_client_rust :: SerializedWhere :: new ("OR" , :: prisma_client_rust :: SerializedWhereValue :: List (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . map (| v | vec ! [v]) . map (:: prisma_client_rust :: PrismaValue :: Object) . collect ()) ,) , Self :: And (value) => :: prisma_client_rust :: SerializedWhere :: new ("AND" , :: prisma_client_rust :: SerializedWhereValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()) ,) , Self :: IdEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IdInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: IdNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: IdLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IdLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IdGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IdGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: IdNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: FoodTypeEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: FoodTypeNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: FoodTypeLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: FoodTypeMode (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("mode" . to_string () , :: prisma_client_rust :: PrismaValue :: Enum (value . to_string ()))])) , Self :: FoodTypeNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("food_type" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: CategoryIs (value) => :: prisma_client_rust :: SerializedWhere :: new ("category" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("is" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()))])) , Self :: CategoryIsNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("category" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("isNot" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()))])) , Self :: NameEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: NameNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: NameLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: NameMode (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("mode" . to_string () , :: prisma_client_rust :: PrismaValue :: Enum (value . to_string ()))])) , Self :: NameNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("name" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: PriceEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: PriceInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: PriceNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: PriceLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: PriceLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: PriceGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: PriceGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: PriceNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("price" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: AmountEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: AmountInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: AmountNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (v) . unwrap () . normalized ())) . collect ()))])) , Self :: AmountLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: AmountLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: AmountGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: AmountGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: AmountNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("amount" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Float (< :: prisma_client_rust :: bigdecimal :: BigDecimal as :: prisma_client_rust :: bigdecimal :: FromPrimitive > :: from_f64 (value) . unwrap () . normalized ()))])) , Self :: UnitEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("unit" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: UnitInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("unit" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: UnitNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("unit" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: String (v)) . collect ()))])) , Self :: UnitLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("unit" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: UnitLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("unit" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: UnitGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("unit" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: UnitGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("unit" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: UnitContains (value) => :: prisma_client_rust :: SerializedWhere :: new ("unit" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("contains" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: UnitStartsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("unit" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("startsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: UnitEndsWith (value) => :: prisma_client_rust :: SerializedWhere :: new ("unit" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("endsWith" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: UnitMode (value) => :: prisma_client_rust :: SerializedWhere :: new ("unit" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("mode" . to_string () , :: prisma_client_rust :: PrismaValue :: Enum (value . to_string ()))])) , Self :: UnitNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("unit" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: String (value))])) , Self :: TotalEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: TotalInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: TotalNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: TotalLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: TotalLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: TotalGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: TotalGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: TotalNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("total" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: DateEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: DateInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: DateTime (v)) . collect ()))])) , Self :: DateNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: DateTime (v)) . collect ()))])) , Self :: DateLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: DateLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: DateGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: DateGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: DateNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("date" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , :: prisma_client_rust :: PrismaValue :: DateTime (value))])) , Self :: StoreIdEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("store_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Int (value as i64)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: StoreIdInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("store_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: StoreIdNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("store_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: StoreIdLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("store_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: StoreIdLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("store_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: StoreIdGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("store_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: StoreIdGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("store_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: StoreIdNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("store_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Int (value as i64)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: StoreIs (value) => :: prisma_client_rust :: SerializedWhere :: new ("store" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("is" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()))])) , Self :: StoreIsNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("store" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("isNot" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()))])) , Self :: ReceiptIdEquals (value) => :: prisma_client_rust :: SerializedWhere :: new ("receipt_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("equals" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Int (value as i64)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: ReceiptIdInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("receipt_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("in" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: ReceiptIdNotInVec (value) => :: prisma_client_rust :: SerializedWhere :: new ("receipt_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("notIn" . to_string () , :: prisma_client_rust :: PrismaValue :: List (value . into_iter () . map (| v | :: prisma_client_rust :: PrismaValue :: Int (v as i64)) . collect ()))])) , Self :: ReceiptIdLt (value) => :: prisma_client_rust :: SerializedWhere :: new ("receipt_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ReceiptIdLte (value) => :: prisma_client_rust :: SerializedWhere :: new ("receipt_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("lte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ReceiptIdGt (value) => :: prisma_client_rust :: SerializedWhere :: new ("receipt_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gt" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ReceiptIdGte (value) => :: prisma_client_rust :: SerializedWhere :: new ("receipt_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("gte" . to_string () , :: prisma_client_rust :: PrismaValue :: Int (value as i64))])) , Self :: ReceiptIdNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("receipt_id" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("not" . to_string () , value . map (| value | :: prisma_client_rust :: PrismaValue :: Int (value as i64)) . unwrap_or_else (|| :: prisma_client_rust :: PrismaValue :: Null))])) , Self :: ReceiptIs (value) => :: prisma_client_rust :: SerializedWhere :: new ("receipt" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("is" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()))])) , Self :: ReceiptIsNot (value) => :: prisma_client_rust :: SerializedWhere :: new ("receipt" , :: prisma_client_rust :: SerializedWhereValue :: Object (vec ! [("isNot" . to_string () , :: prisma_client_rust :: PrismaValue :: Object (value . into_iter () . map (Into :: < :: prisma_client_rust :: SerializedWhere > :: into) . map (Into :: into) . collect ()))])) }
     }
   }
   #[derive(Clone)]
@@ -1028,17 +6325,17 @@ pub mod food {
     }
     pub fn create(
       self,
-      food_type: String,
+      category: super::category::UniqueWhereParam,
       name: String,
-      price: f64,
+      price: i32,
       amount: f64,
-      total: f64,
+      total: i32,
       date: ::prisma_client_rust::chrono::DateTime<
         ::prisma_client_rust::chrono::FixedOffset
       >,
       mut _params: Vec<SetParam>
     ) -> Create<'a> {
-      _params.push(food_type::set(food_type));
+      _params.push(category::connect(category));
       _params.push(name::set(name));
       _params.push(price::set(price));
       _params.push(amount::set(amount));
@@ -1053,11 +6350,11 @@ pub mod food {
     pub fn create_many(
       self,
       data: Vec<(
+        super::category::UniqueWhereParam,
         String,
-        String,
-        f64,
-        f64,
+        i32,
         f64,
+        i32,
         ::prisma_client_rust::chrono::DateTime<
           ::prisma_client_rust::chrono::FixedOffset
         >,
@@ -1067,8 +6364,8 @@ pub mod food {
       let data = data
         .into_iter()
         .map(
-          |(food_type, name, price, amount, total, date, mut _params)| {
-            _params.push(food_type::set(food_type));
+          |(category, name, price, amount, total, date, mut _params)| {
+            _params.push(category::connect(category));
             _params.push(name::set(name));
             _params.push(price::set(price));
             _params.push(amount::set(amount));
@@ -1112,12 +6409,12 @@ pub mod food {
     pub fn upsert(
       self,
       _where: UniqueWhereParam,
-      (food_type, name, price, amount, total, date, mut _params): (
+      (category, name, price, amount, total, date, mut _params): (
+        super::category::UniqueWhereParam,
         String,
-        String,
-        f64,
-        f64,
+        i32,
         f64,
+        i32,
         ::prisma_client_rust::chrono::DateTime<
           ::prisma_client_rust::chrono::FixedOffset
         >,
@@ -1125,7 +6422,7 @@ pub mod food {
       ),
       _update: Vec<SetParam>
     ) -> Upsert<'a> {
-      _params.push(food_type::set(food_type));
+      _params.push(category::connect(category));
       _params.push(name::set(name));
       _params.push(price::set(price));
       _params.push(amount::set(amount));
@@ -1227,6 +6524,15 @@ pub mod _prisma {
       ::prisma_client_rust::batch(queries, &self.executor, &self.query_schema)
         .await
     }
+    pub fn category(&self) -> super::category::Actions {
+      super::category::Actions { client: &self }
+    }
+    pub fn store(&self) -> super::store::Actions {
+      super::store::Actions { client: &self }
+    }
+    pub fn receipt(&self) -> super::receipt::Actions {
+      super::receipt::Actions { client: &self }
+    }
     pub fn food(&self) -> super::food::Actions {
       super::food::Actions { client: &self }
     }
@@ -1234,6 +6540,72 @@ pub mod _prisma {
   #[derive(
     Debug, Clone, Copy, :: serde :: Serialize, :: serde :: Deserialize,
   )]
+  pub enum CategoryScalarFieldEnum {
+    #[serde(rename = "id")]
+    Id,
+    #[serde(rename = "name")]
+    Name,
+    #[serde(rename = "position")]
+    Position,
+    #[serde(rename = "archived")]
+    Archived
+  }
+  impl ToString for CategoryScalarFieldEnum {
+    fn to_string(&self) -> String {
+      match self {
+        Self::Id => "id".to_string(),
+        Self::Name => "name".to_string(),
+        Self::Position => "position".to_string(),
+        Self::Archived => "archived".to_string()
+      }
+    }
+  }
+  #[derive(
+    Debug, Clone, Copy, :: serde :: Serialize, :: serde :: Deserialize,
+  )]
+  pub enum StoreScalarFieldEnum {
+    #[serde(rename = "id")]
+    Id,
+    #[serde(rename = "name")]
+    Name
+  }
+  impl ToString for StoreScalarFieldEnum {
+    fn to_string(&self) -> String {
+      match self {
+        Self::Id => "id".to_string(),
+        Self::Name => "name".to_string()
+      }
+    }
+  }
+  #[derive(
+    Debug, Clone, Copy, :: serde :: Serialize, :: serde :: Deserialize,
+  )]
+  pub enum ReceiptScalarFieldEnum {
+    #[serde(rename = "id")]
+    Id,
+    #[serde(rename = "date")]
+    Date,
+    #[serde(rename = "store_id")]
+    StoreId,
+    #[serde(rename = "total")]
+    Total,
+    #[serde(rename = "notes")]
+    Notes
+  }
+  impl ToString for ReceiptScalarFieldEnum {
+    fn to_string(&self) -> String {
+      match self {
+        Self::Id => "id".to_string(),
+        Self::Date => "date".to_string(),
+        Self::StoreId => "store_id".to_string(),
+        Self::Total => "total".to_string(),
+        Self::Notes => "notes".to_string()
+      }
+    }
+  }
+  #[derive(
+    Debug, Clone, Copy, :: serde :: Serialize, :: serde :: Deserialize,
+  )]
   pub enum FoodScalarFieldEnum {
     #[serde(rename = "id")]
     Id,
@@ -1245,10 +6617,16 @@ pub mod _prisma {
     Price,
     #[serde(rename = "amount")]
     Amount,
+    #[serde(rename = "unit")]
+    Unit,
     #[serde(rename = "total")]
     Total,
     #[serde(rename = "date")]
-    Date
+    Date,
+    #[serde(rename = "store_id")]
+    StoreId,
+    #[serde(rename = "receipt_id")]
+    ReceiptId
   }
   impl ToString for FoodScalarFieldEnum {
     fn to_string(&self) -> String {
@@ -1258,8 +6636,11 @@ pub mod _prisma {
         Self::Name => "name".to_string(),
         Self::Price => "price".to_string(),
         Self::Amount => "amount".to_string(),
+        Self::Unit => "unit".to_string(),
         Self::Total => "total".to_string(),
-        Self::Date => "date".to_string()
+        Self::Date => "date".to_string(),
+        Self::StoreId => "store_id".to_string(),
+        Self::ReceiptId => "receipt_id".to_string()
       }
     }
   }
@@ -0,0 +1,217 @@
+use std::{
+  fs::{self, OpenOptions},
+  io::{self, BufRead, BufReader, Write},
+  path::PathBuf,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex
+  }
+};
+
+use prisma_client_rust::chrono::Local;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+
+use crate::{config::data_file, item::FoodItem, store::NewReceipt};
+
+/// Name of the queue file inside the data directory
+const FILE_NAME: &str = "commit_queue.jsonl";
+
+/// A basket that couldn't be committed, waiting for the database to be back
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedCommit {
+  /// When it was queued, tells commits apart once some of them are synced
+  pub id: i64,
+  pub receipt: NewReceipt,
+  pub food_items: Vec<FoodItem>,
+  /// Why the database refused it, it's put aside until it's retried
+  #[serde(default)]
+  pub refused: Option<String>
+}
+
+/// Commits kept on disk as JSON lines, oldest first,
+/// so nothing typed in is lost while the database is unreachable
+#[derive(Debug)]
+pub struct CommitQueue {
+  path: PathBuf,
+  // reading and rewriting the file happens from several tasks
+  lock: Mutex<()>,
+  // purchases in the file, so the status doesn't have to read it every time
+  waiting: AtomicUsize,
+  // baskets put aside since the database refused them
+  refused: AtomicUsize,
+  pushed: Notify
+}
+
+impl CommitQueue {
+  /// Use the queue file at `path`, whatever is in there already is kept
+  pub fn open(path: PathBuf) -> Self {
+    let queue = Self {
+      path,
+      lock: Mutex::new(()),
+      waiting: AtomicUsize::new(0),
+      refused: AtomicUsize::new(0),
+      pushed: Notify::new()
+    };
+
+    if let Ok(commits) = queue.load() {
+      queue.count(&commits);
+    }
+
+    queue
+  }
+
+  /// The queue file in the data directory of the user,
+  /// `None` if the system doesn't have one
+  pub fn default_path() -> Option<PathBuf> {
+    data_file(FILE_NAME)
+  }
+
+  /// Add a basket to the end of the queue
+  pub fn push(
+    &self,
+    receipt: NewReceipt,
+    food_items: Vec<FoodItem>
+  ) -> io::Result<()> {
+    let _lock = self.lock.lock().unwrap();
+
+    let commit = QueuedCommit {
+      id: Local::now().timestamp_nanos(),
+      receipt,
+      food_items,
+      refused: None
+    };
+
+    if let Some(dir) = self.path.parent() {
+      fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(&self.path)?;
+
+    writeln!(file, "{}", serde_json::to_string(&commit)?)?;
+    file.sync_all()?;
+
+    self
+      .waiting
+      .fetch_add(commit.food_items.len(), Ordering::SeqCst);
+    self.pushed.notify_one();
+
+    Ok(())
+  }
+
+  /// Every queued basket, oldest first. A missing file is an empty queue
+  pub fn load(&self) -> io::Result<Vec<QueuedCommit>> {
+    let file = match fs::File::open(&self.path) {
+      Ok(file) => file,
+      Err(error) if error.kind() == io::ErrorKind::NotFound => {
+        return Ok(vec![])
+      }
+      Err(error) => return Err(error)
+    };
+
+    let mut commits = vec![];
+
+    for line in BufReader::new(file).lines() {
+      let line = line?;
+
+      if line.trim().is_empty() {
+        continue;
+      }
+
+      commits.push(serde_json::from_str(&line)?);
+    }
+
+    Ok(commits)
+  }
+
+  /// Drop the basket with `id`, once the database accepted it
+  /// or the user gave up on it
+  pub fn remove(&self, id: i64) -> io::Result<()> {
+    self.rewrite(|commits| commits.retain(|commit| commit.id != id))
+  }
+
+  /// Put the basket with `id` aside, the sync skips it from now on
+  pub fn set_refused(&self, id: i64, reason: String) -> io::Result<()> {
+    self.rewrite(|commits| {
+      for commit in commits.iter_mut().filter(|commit| commit.id == id) {
+        commit.refused = Some(reason.clone());
+      }
+    })
+  }
+
+  /// Give a refused basket another go, e.g. once its category exists
+  pub fn retry(&self, id: i64) -> io::Result<()> {
+    self.rewrite(|commits| {
+      for commit in commits.iter_mut().filter(|commit| commit.id == id) {
+        commit.refused = None;
+      }
+    })?;
+
+    self.pushed.notify_one();
+
+    Ok(())
+  }
+
+  /// Change the queued baskets with `f` and write them back.
+  /// The file is read again first, so baskets queued meanwhile are kept
+  fn rewrite<F>(&self, f: F) -> io::Result<()>
+  where
+    F: FnOnce(&mut Vec<QueuedCommit>)
+  {
+    let _lock = self.lock.lock().unwrap();
+
+    let mut commits = self.load()?;
+
+    f(&mut commits);
+
+    // written next to the queue and renamed, so a crash can't cut it in half
+    let temp_path = self.path.with_extension("jsonl.tmp");
+    let mut file = fs::File::create(&temp_path)?;
+
+    for commit in commits.iter() {
+      writeln!(file, "{}", serde_json::to_string(commit)?)?;
+    }
+
+    file.sync_all()?;
+    fs::rename(&temp_path, &self.path)?;
+
+    self.count(&commits);
+
+    Ok(())
+  }
+
+  /// Keep the counters in line with `commits`
+  fn count(&self, commits: &[QueuedCommit]) {
+    let waiting = commits
+      .iter()
+      .filter(|commit| commit.refused.is_none())
+      .map(|commit| commit.food_items.len())
+      .sum();
+    let refused = commits
+      .iter()
+      .filter(|commit| commit.refused.is_some())
+      .count();
+
+    self.waiting.store(waiting, Ordering::SeqCst);
+    self.refused.store(refused, Ordering::SeqCst);
+  }
+
+  /// How many purchases are still waiting to be committed,
+  /// the refused baskets aren't counted
+  pub fn waiting(&self) -> usize {
+    self.waiting.load(Ordering::SeqCst)
+  }
+
+  /// How many baskets the database refused
+  pub fn refused(&self) -> usize {
+    self.refused.load(Ordering::SeqCst)
+  }
+
+  /// Wait until something is pushed to the queue or retried
+  pub async fn pushed(&self) {
+    self.pushed.notified().await
+  }
+}
@@ -0,0 +1,7 @@
+mod commit_queue;
+mod queue_view;
+mod sync;
+
+pub use commit_queue::CommitQueue;
+pub use queue_view::create_queue_view;
+pub use sync::{start_sync, sync_status, update_sync_status};
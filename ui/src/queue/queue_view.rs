@@ -0,0 +1,171 @@
+use std::{io, sync::Arc};
+
+use cursive::{
+  view::{Nameable, Resizable, Scrollable},
+  views::{Dialog, SelectView, TextView},
+  Cursive
+};
+
+use super::{
+  commit_queue::QueuedCommit, sync_status, update_sync_status, CommitQueue
+};
+use crate::{buttons::can_leave_basket, model::Model};
+
+/// Format a queued basket into a single line of the `SelectView`
+fn format_commit(commit: &QueuedCommit, date_format: &str) -> String {
+  let line = format!(
+    "{} | {} items",
+    commit.receipt.date.format(date_format),
+    commit.food_items.len()
+  );
+
+  match &commit.refused {
+    Some(reason) => format!("{} | refused: {}", line, reason),
+    None => format!("{} | waiting", line)
+  }
+}
+
+/// Open the baskets in the queue in place of the currently open dialog.
+/// The ones the database refused can be retried or dropped
+pub fn create_queue_view(s: &mut Cursive, model: Arc<Model>) {
+  if model.queue().is_none() {
+    return s.add_layer(Dialog::info(
+      "There's no data directory, so nothing is ever queued"
+    ));
+  }
+
+  // the basket may be open below
+  if !can_leave_basket(s) {
+    return;
+  }
+
+  // Pop previous layer
+  s.pop_layer();
+
+  let submit_model = Model::clone(&model);
+
+  s.add_layer(
+    Dialog::around(
+      SelectView::<QueuedCommit>::new()
+        .on_submit(move |s, commit| {
+          commit_actions(s, Model::clone(&submit_model), commit)
+        })
+        .with_name("queued_commits")
+        .scrollable()
+        .full_screen()
+    )
+    .title("Queued commits")
+    .button("Back", |s| {
+      s.pop_layer();
+    })
+  );
+
+  load_commits(s, &model);
+}
+
+/// Fill the list with whatever is in the queue file right now
+fn load_commits(s: &mut Cursive, model: &Model) {
+  let queue = match model.queue() {
+    Some(queue) => queue,
+    None => return
+  };
+
+  let commits = match queue.load() {
+    Ok(commits) => commits,
+    Err(error) => {
+      return s.add_layer(Dialog::info(format!(
+        "Failed to read the queue:\n{}",
+        error
+      )));
+    }
+  };
+
+  let date_format = model.rules().date_parser().display_format();
+
+  s.call_on_name(
+    "queued_commits",
+    |select_view: &mut SelectView<QueuedCommit>| {
+      select_view.clear();
+
+      for commit in commits {
+        select_view.add_item(format_commit(&commit, date_format), commit);
+      }
+    }
+  );
+
+  // the basket may be open below, its status is out of date now
+  update_sync_status(s, sync_status(queue));
+}
+
+/// Ask what should happen with a basket selected in the queue
+fn commit_actions(s: &mut Cursive, model: Arc<Model>, commit: &QueuedCommit) {
+  let retry_model = Model::clone(&model);
+  let id = commit.id;
+
+  let mut dialog = Dialog::around(TextView::new(format!(
+    "What should happen with the basket of {}?",
+    commit
+      .receipt
+      .date
+      .format(model.rules().date_parser().display_format())
+  )))
+  .title("Queued commit");
+
+  // a waiting basket is retried anyway
+  if commit.refused.is_some() {
+    dialog.add_button("Retry", move |s| {
+      s.pop_layer();
+
+      change_queue(s, &retry_model, |queue| queue.retry(id));
+    });
+  }
+
+  dialog.add_button("Drop", move |s| {
+    s.pop_layer();
+
+    drop_commit(s, Model::clone(&model), id);
+  });
+
+  dialog.add_button("Cancel", |s| {
+    s.pop_layer();
+  });
+
+  s.add_layer(dialog);
+}
+
+/// Take the basket with `id` out of the queue for good, after asking
+fn drop_commit(s: &mut Cursive, model: Arc<Model>, id: i64) {
+  s.add_layer(
+    Dialog::around(TextView::new(
+      "Drop the basket? Its items won't be committed"
+    ))
+    .button("Confirm", move |s| {
+      s.pop_layer();
+
+      change_queue(s, &model, |queue| queue.remove(id));
+    })
+    .button("Cancel", |s| {
+      s.pop_layer();
+    })
+  );
+}
+
+/// Run `change` on the queue, then show what it looks like afterwards
+fn change_queue<F>(s: &mut Cursive, model: &Model, change: F)
+where
+  F: FnOnce(&CommitQueue) -> io::Result<()>
+{
+  let result = match model.queue() {
+    Some(queue) => change(queue),
+    None => return
+  };
+
+  if let Err(error) = result {
+    s.add_layer(Dialog::info(format!(
+      "Failed to change the queue:\n{}",
+      error
+    )));
+  }
+
+  load_commits(s, model);
+}
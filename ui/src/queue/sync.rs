@@ -0,0 +1,130 @@
+use std::{sync::Arc, time::Duration};
+
+use cursive::{
+  views::{Dialog, TextView},
+  Cursive
+};
+
+use super::CommitQueue;
+use crate::model::Model;
+
+/// How long to wait after the first failed retry
+const MIN_DELAY: Duration = Duration::from_secs(5);
+
+/// Retries never wait longer than this
+const MAX_DELAY: Duration = Duration::from_secs(300);
+
+/// What the status indicator says about the purchases in `queue`
+pub fn sync_status(queue: &CommitQueue) -> String {
+  let waiting = match queue.waiting() {
+    0 => None,
+    1 => Some(String::from("1 purchase waiting to sync")),
+    n => Some(format!("{} purchases waiting to sync", n))
+  };
+
+  let refused = match queue.refused() {
+    0 => None,
+    1 => Some(String::from("1 queued basket was refused")),
+    n => Some(format!("{} queued baskets were refused", n))
+  };
+
+  match (waiting, refused) {
+    (Some(waiting), Some(refused)) => {
+      format!("{}, {} (History > Queued commits)", waiting, refused)
+    }
+    (None, Some(refused)) => {
+      format!("{} (History > Queued commits)", refused)
+    }
+    (Some(waiting), None) => waiting,
+    (None, None) => String::new()
+  }
+}
+
+/// Put the `status` of the queue on the status indicator
+pub fn update_sync_status(s: &mut Cursive, status: String) {
+  s.call_on_name("sync_status", |text_view: &mut TextView| {
+    text_view.set_content(status);
+  });
+}
+
+/// Commit the queued baskets in a tokio task, oldest first.
+/// Every failed attempt doubles the wait before the next one,
+/// an empty queue waits until something is pushed to it
+pub fn start_sync(model: Arc<Model>) {
+  if model.queue().is_none() {
+    return;
+  }
+
+  tokio::spawn(async move {
+    let mut delay = MIN_DELAY;
+
+    loop {
+      let queue = match model.queue() {
+        Some(queue) => queue,
+        None => return
+      };
+
+      if queue.waiting() == 0 {
+        queue.pushed().await;
+      }
+
+      match sync_queue(&model).await {
+        Ok(()) => delay = MIN_DELAY,
+        Err(_) => {
+          tokio::time::sleep(delay).await;
+
+          delay = (delay * 2).min(MAX_DELAY);
+        }
+      }
+    }
+  });
+}
+
+/// Commit every queued basket, oldest first. A basket the database refuses
+/// is put aside, only a database that can't be reached stops the sync
+async fn sync_queue(model: &Arc<Model>) -> Result<(), String> {
+  let queue = match model.queue() {
+    Some(queue) => queue,
+    None => return Ok(())
+  };
+
+  let commits = queue.load().map_err(|err| err.to_string())?;
+
+  for commit in commits {
+    // put aside until the user retries it
+    if commit.refused.is_some() {
+      continue;
+    }
+
+    let result = model
+      .store()
+      .create_receipt(commit.receipt, commit.food_items)
+      .await;
+
+    match result {
+      Ok(_) => queue.remove(commit.id).map_err(|err| err.to_string())?,
+      Err(error) if error.is_offline() => return Err(error.to_string()),
+      Err(error) => {
+        let reason = error.to_string();
+
+        queue
+          .set_refused(commit.id, reason.clone())
+          .map_err(|err| err.to_string())?;
+
+        model.use_cb_sink(move |s| {
+          s.add_layer(Dialog::info(format!(
+            "The database refused a queued basket, \
+             it's kept in History > Queued commits:\n{}",
+            reason
+          )));
+        });
+      }
+    }
+
+    let status = sync_status(queue);
+
+    model.use_cb_sink(move |s| update_sync_status(s, status));
+  }
+
+  Ok(())
+}
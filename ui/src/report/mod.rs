@@ -0,0 +1,6 @@
+mod monthly_report;
+mod store_report;
+mod unit_prices;
+
+pub use monthly_report::{create_monthly_report, monthly_report};
+pub use store_report::{create_store_report, store_report};
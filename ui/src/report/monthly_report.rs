@@ -0,0 +1,211 @@
+use std::sync::Arc;
+
+use cursive::{
+  view::{Nameable, Resizable, Scrollable},
+  views::{Dialog, DummyView, EditView, LinearLayout, TextView},
+  Cursive
+};
+use prisma_client_rust::chrono::{Datelike, Local};
+
+use super::unit_prices::render_unit_prices;
+use crate::{
+  buttons::can_leave_basket,
+  model::Model,
+  money::Money,
+  store::{MonthlySpending, PurchaseFilter}
+};
+
+const MONTHS: [&str; 12] = [
+  "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov",
+  "Dec"
+];
+
+/// Open the monthly spending report in place of the currently open dialog.
+/// The report for the current year is loaded right away
+pub fn create_monthly_report(s: &mut Cursive, model: Arc<Model>) {
+  // the basket may be open below
+  if !can_leave_basket(s) {
+    return;
+  }
+
+  // Pop previous layer
+  s.pop_layer();
+
+  let year = Local::now().year();
+  let submit_model = Model::clone(&model);
+  let show_model = Model::clone(&model);
+
+  s.add_layer(
+    Dialog::around(
+      LinearLayout::vertical()
+        .child(
+          LinearLayout::horizontal()
+            .child(TextView::new("Year: "))
+            .child(
+              EditView::new()
+                .content(year.to_string())
+                .on_submit(move |s, content| {
+                  show_report(s, Model::clone(&submit_model), content)
+                })
+                .with_name("report_year")
+                .fixed_width(6)
+            )
+        )
+        .child(DummyView)
+        .child(
+          TextView::new("Loading...")
+            .with_name("report_table")
+            .scrollable()
+            .full_screen()
+        )
+    )
+    .title("Monthly spending")
+    .button("Show", move |s| {
+      let content = s
+        .call_on_name("report_year", |edit_view: &mut EditView| {
+          edit_view.get_content()
+        })
+        .unwrap_or_default();
+
+      show_report(s, Model::clone(&show_model), &content)
+    })
+    .button("Back", |s| {
+      s.pop_layer();
+    })
+  );
+
+  load_report(model, year);
+}
+
+/// Parse the year typed by the user, then load the report for it
+fn show_report(s: &mut Cursive, model: Arc<Model>, content: &str) {
+  match content.trim().parse::<i32>() {
+    Ok(year) => load_report(model, year),
+    Err(_) => {
+      s.add_layer(Dialog::info("Year field does not have a valid format"))
+    }
+  }
+}
+
+/// Run the aggregation and lay it out as a table,
+/// followed by what each product cost per unit that year
+pub async fn monthly_report(
+  model: &Model,
+  year: i32
+) -> Result<String, String> {
+  let rows = model
+    .store()
+    .monthly_spending(year)
+    .await
+    .map_err(|err| err.to_string())?;
+
+  // categories in the menu get a column even when nothing was bought from them
+  let categories = match model.store().list_categories(false).await {
+    Ok(categories) => categories
+      .into_iter()
+      .map(|category| category.name)
+      .collect::<Vec<_>>(),
+    Err(_) => vec![]
+  };
+
+  let purchases = model
+    .store()
+    .list(&PurchaseFilter::year(year))
+    .await
+    .map_err(|err| err.to_string())?;
+
+  Ok(format!(
+    "{}\n\n{}",
+    render_report(year, categories, &rows),
+    render_unit_prices(&purchases)
+  ))
+}
+
+/// Load the report in a tokio task, then put the table on screen
+fn load_report(model: Arc<Model>, year: i32) {
+  tokio::spawn(async move {
+    let table = match monthly_report(&model, year).await {
+      Ok(table) => table,
+      Err(error) => {
+        let message = format!("Failed to load the report:\n{}", error);
+
+        return model.use_cb_sink(move |s| {
+          s.add_layer(Dialog::info(message));
+        });
+      }
+    };
+
+    model.use_cb_sink(move |s| {
+      s.call_on_name("report_table", |text_view: &mut TextView| {
+        text_view.set_content(table);
+      });
+    });
+  });
+}
+
+/// Lay out the rows as a table: a line per month, a column per category,
+/// with the totals in the last column and on the last line
+fn render_report(
+  year: i32,
+  mut categories: Vec<String>,
+  rows: &[MonthlySpending]
+) -> String {
+  // archived categories still get their own column when they have spending
+  for row in rows {
+    if !categories.contains(&row.food_type) {
+      categories.push(row.food_type.clone());
+    }
+  }
+
+  let mut spending =
+    vec![vec![Money::default(); categories.len()]; MONTHS.len()];
+
+  for row in rows {
+    let column = categories
+      .iter()
+      .position(|category| *category == row.food_type)
+      .unwrap();
+
+    spending[(row.month - 1) as usize][column] += row.total;
+  }
+
+  let width = categories
+    .iter()
+    .map(|category| category.len())
+    .max()
+    .unwrap_or_default()
+    .max(10);
+
+  let mut table = format!("{:<6}", year);
+
+  for category in categories.iter().map(String::as_str).chain(["Total"]) {
+    table.push_str(&format!("{:>width$}", category, width = width + 1));
+  }
+
+  let mut column_totals = vec![Money::default(); categories.len()];
+
+  for (month, totals) in MONTHS.iter().zip(&spending) {
+    table.push_str(&format!("\n{:<6}", month));
+
+    for (column, total) in totals.iter().enumerate() {
+      column_totals[column] += *total;
+      table.push_str(&format!("{:>width$}", total, width = width + 1));
+    }
+
+    let month_total = totals.iter().sum::<Money>();
+
+    table.push_str(&format!("{:>width$}", month_total, width = width + 1));
+  }
+
+  table.push_str(&format!("\n{:<6}", "Total"));
+
+  for total in column_totals.iter() {
+    table.push_str(&format!("{:>width$}", total, width = width + 1));
+  }
+
+  let year_total = column_totals.iter().sum::<Money>();
+
+  table.push_str(&format!("{:>width$}", year_total, width = width + 1));
+
+  table
+}
@@ -0,0 +1,255 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use cursive::{
+  view::{Nameable, Resizable, Scrollable},
+  views::{Dialog, DummyView, EditView, LinearLayout, TextView},
+  Cursive
+};
+use prisma_client_rust::chrono::{Datelike, Local};
+
+use crate::{
+  buttons::can_leave_basket,
+  model::Model,
+  money::Money,
+  store::{Purchase, PurchaseFilter}
+};
+
+/// What purchases without a store are listed as
+const NO_STORE: &str = "No store";
+
+/// Open the spending by store report in place of the currently open dialog.
+/// The report for the current year is loaded right away
+pub fn create_store_report(s: &mut Cursive, model: Arc<Model>) {
+  // the basket may be open below
+  if !can_leave_basket(s) {
+    return;
+  }
+
+  // Pop previous layer
+  s.pop_layer();
+
+  let year = Local::now().year();
+  let show_model = Model::clone(&model);
+
+  s.add_layer(
+    Dialog::around(
+      LinearLayout::vertical()
+        .child(
+          LinearLayout::horizontal()
+            .child(TextView::new("Year: "))
+            .child(
+              EditView::new()
+                .content(year.to_string())
+                .with_name("store_report_year")
+                .fixed_width(6)
+            )
+            .child(DummyView)
+            .child(TextView::new("Product: "))
+            .child(
+              EditView::new()
+                .with_name("store_report_product")
+                .fixed_width(24)
+            )
+        )
+        .child(DummyView)
+        .child(
+          TextView::new("Loading...")
+            .with_name("store_report_table")
+            .scrollable()
+            .full_screen()
+        )
+    )
+    .title("Spending by store")
+    .button("Show", move |s| show_report(s, Model::clone(&show_model)))
+    .button("Back", |s| {
+      s.pop_layer();
+    })
+  );
+
+  load_report(model, year, String::new());
+}
+
+/// Read the year and product typed by the user, then load the report for them
+fn show_report(s: &mut Cursive, model: Arc<Model>) {
+  let mut content = |name: &str| {
+    s.call_on_name(name, |edit_view: &mut EditView| {
+      edit_view.get_content().trim().to_string()
+    })
+    .unwrap_or_default()
+  };
+
+  let year = content("store_report_year");
+  let product = content("store_report_product");
+
+  match year.parse::<i32>() {
+    Ok(year) => load_report(model, year, product),
+    Err(_) => {
+      s.add_layer(Dialog::info("Year field does not have a valid format"))
+    }
+  }
+}
+
+/// Spending per store in `year`, followed by where each product is cheapest.
+/// With a `product` name, every store that sold it is ranked instead
+pub async fn store_report(
+  model: &Model,
+  year: i32,
+  product: &str
+) -> Result<String, String> {
+  let purchases = model
+    .store()
+    .list(&PurchaseFilter::year(year))
+    .await
+    .map_err(|err| err.to_string())?;
+
+  let cheapest = if product.is_empty() {
+    render_cheapest(&purchases)
+  } else {
+    render_ranking(&purchases, product)
+  };
+
+  Ok(format!(
+    "{}\n\n{}",
+    render_spending(year, &purchases),
+    cheapest
+  ))
+}
+
+/// Load the report in a tokio task, then put it on screen
+fn load_report(model: Arc<Model>, year: i32, product: String) {
+  tokio::spawn(async move {
+    let table = match store_report(&model, year, &product).await {
+      Ok(table) => table,
+      Err(error) => {
+        let message = format!("Failed to load the report:\n{}", error);
+
+        return model.use_cb_sink(move |s| {
+          s.add_layer(Dialog::info(message));
+        });
+      }
+    };
+
+    model.use_cb_sink(move |s| {
+      s.call_on_name("store_report_table", |text_view: &mut TextView| {
+        text_view.set_content(table);
+      });
+    });
+  });
+}
+
+/// The name of the store a purchase was made in
+fn shop_name(purchase: &Purchase) -> &str {
+  purchase
+    .shop
+    .as_ref()
+    .map_or(NO_STORE, |shop| shop.name.as_str())
+}
+
+/// Total spent in every store, biggest first
+fn render_spending(year: i32, purchases: &[Purchase]) -> String {
+  let mut totals = BTreeMap::<&str, Money>::new();
+
+  for purchase in purchases {
+    *totals.entry(shop_name(purchase)).or_default() += purchase.total;
+  }
+
+  let mut totals = totals.into_iter().collect::<Vec<_>>();
+
+  totals.sort_by(|a, b| b.1.cmp(&a.1));
+
+  let mut table = format!("Spending by store in {}", year);
+
+  for (name, total) in totals.iter() {
+    table.push_str(&format!("\n{:<24} {:>10}", name, total));
+  }
+
+  let total = totals.iter().map(|(_, total)| total).sum::<Money>();
+
+  table.push_str(&format!("\n{:<24} {:>10}", "Total", total));
+
+  table
+}
+
+/// A product told apart by its name and by what its unit measures
+type Product = (String, &'static str);
+
+/// Price per unit of every product in every store.
+/// Purchases without a store can't be compared, so they're left out
+fn unit_prices(
+  purchases: &[Purchase]
+) -> BTreeMap<Product, Vec<(&str, Money)>> {
+  let mut sums = BTreeMap::<(String, &str, &str), (Money, f64)>::new();
+
+  for purchase in purchases {
+    let shop = match &purchase.shop {
+      Some(shop) => shop.name.as_str(),
+      None => continue
+    };
+
+    let (base, _) = purchase.unit.base();
+
+    let amount = match purchase.unit.convert(purchase.amount, base) {
+      Some(amount) => amount,
+      None => continue
+    };
+
+    let key = (purchase.name.trim().to_lowercase(), base.as_str(), shop);
+    let (total, sum) = sums.entry(key).or_default();
+
+    *total += purchase.total;
+    *sum += amount;
+  }
+
+  let mut prices = BTreeMap::<Product, Vec<(&str, Money)>>::new();
+
+  for ((name, unit, shop), (total, amount)) in sums {
+    if let Some(price) = total.divided_by(amount) {
+      prices.entry((name, unit)).or_default().push((shop, price));
+    }
+  }
+
+  for shops in prices.values_mut() {
+    shops.sort_by_key(|(_, price)| *price);
+  }
+
+  prices
+}
+
+/// The store with the lowest price per unit, for every product
+fn render_cheapest(purchases: &[Purchase]) -> String {
+  let mut table = String::from("Cheapest store per product");
+
+  for ((name, unit), shops) in unit_prices(purchases) {
+    if let Some((shop, price)) = shops.first() {
+      table.push_str(&format!(
+        "\n{:<24} {:<16} {:>10}/{}",
+        name, shop, price, unit
+      ));
+    }
+  }
+
+  table
+}
+
+/// Every store that sold `product`, cheapest first
+fn render_ranking(purchases: &[Purchase], product: &str) -> String {
+  let product = product.trim().to_lowercase();
+  let mut table = format!("Stores selling '{}', cheapest first", product);
+
+  let prices = unit_prices(purchases)
+    .into_iter()
+    .filter(|((name, _), _)| *name == product)
+    .collect::<Vec<_>>();
+
+  if prices.is_empty() {
+    table.push_str("\nNo purchases with a store");
+  }
+
+  for ((_, unit), shops) in prices {
+    for (shop, price) in shops {
+      table.push_str(&format!("\n{:<24} {:>10}/{}", shop, price, unit));
+    }
+  }
+
+  table
+}
@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+
+use crate::{item::Unit, money::Money, store::Purchase};
+
+/// The average price per kg, l, piece or pack of every product,
+/// so purchases in different units of the same product can be compared
+pub fn render_unit_prices(purchases: &[Purchase]) -> String {
+  // products are told apart by name, and by what their unit measures
+  let mut products = BTreeMap::<(String, &str), (Money, f64, Unit)>::new();
+
+  for purchase in purchases {
+    let (base, _) = purchase.unit.base();
+
+    let amount = match purchase.unit.convert(purchase.amount, base) {
+      Some(amount) => amount,
+      None => continue
+    };
+
+    let key = (purchase.name.trim().to_lowercase(), base.as_str());
+    let (total, sum, _) =
+      products.entry(key).or_insert((Money::default(), 0.0, base));
+
+    *total += purchase.total;
+    *sum += amount;
+  }
+
+  let mut table = String::from("Price per unit");
+
+  for ((name, _), (total, amount, unit)) in products.iter() {
+    if let Some(price) = total.divided_by(*amount) {
+      table.push_str(&format!("\n{:<24} {:>10}/{}", name, price, unit));
+    }
+  }
+
+  table
+}
@@ -0,0 +1,671 @@
+use std::{collections::BTreeMap, sync::Mutex};
+
+use async_trait::async_trait;
+use prisma_client_rust::chrono::Datelike;
+
+use super::{
+  Category, MonthlySpending, NewReceipt, Purchase, PurchaseFilter,
+  PurchaseStore, Receipt, Shop, StoreError
+};
+use crate::{
+  item::{Field, FoodItem},
+  money::Money
+};
+
+#[derive(Debug, Default)]
+struct MemoryState {
+  purchases: Vec<Purchase>,
+  categories: Vec<Category>,
+  shops: Vec<Shop>,
+  receipts: Vec<Receipt>,
+  next_purchase_id: i32,
+  next_category_id: i32,
+  next_shop_id: i32,
+  next_receipt_id: i32
+}
+
+impl MemoryState {
+  /// Refuse names the database would refuse, the same as its foreign key
+  fn check_category(&self, name: &str) -> Result<(), StoreError> {
+    if self.categories.iter().any(|category| category.name == name) {
+      Ok(())
+    } else {
+      Err(StoreError::Invalid {
+        field: Field::Category,
+        value: name.to_string(),
+        reason: String::from("does not exist")
+      })
+    }
+  }
+
+  /// Refuse names that are already taken, the same as the unique index
+  fn check_unique(&self, id: i32, name: &str) -> Result<(), StoreError> {
+    let taken = self
+      .categories
+      .iter()
+      .any(|category| category.id != id && category.name == name);
+
+    if taken {
+      Err(StoreError::Invalid {
+        field: Field::Category,
+        value: name.to_string(),
+        reason: String::from("already exists")
+      })
+    } else {
+      Ok(())
+    }
+  }
+
+  /// The store a purchase links to, refused like the foreign key would
+  fn shop(&self, id: Option<i32>) -> Result<Option<Shop>, StoreError> {
+    let id = match id {
+      Some(id) => id,
+      None => return Ok(None)
+    };
+
+    self
+      .shops
+      .iter()
+      .find(|shop| shop.id == id)
+      .cloned()
+      .map(Some)
+      .ok_or(StoreError::NotFound { what: "store", id })
+  }
+
+  /// Check every item before anything is kept, like a transaction would.
+  /// The error names the first item that was refused
+  fn check_items(&self, food_items: &[FoodItem]) -> Result<(), StoreError> {
+    for (index, food_item) in food_items.iter().enumerate() {
+      self
+        .check_category(food_item.table_name())
+        .and_then(|_| self.shop(food_item.shop_id()))
+        .map_err(|error| error.for_item(index, food_item))?;
+    }
+
+    Ok(())
+  }
+
+  /// Keep every item, linked to the receipt with `receipt_id`
+  fn push_items(
+    &mut self,
+    food_items: Vec<FoodItem>,
+    receipt_id: Option<i32>
+  ) -> Result<Vec<Purchase>, StoreError> {
+    let mut purchases = Vec::with_capacity(food_items.len());
+
+    for food_item in food_items {
+      self.next_purchase_id += 1;
+
+      let shop = self.shop(food_item.shop_id())?;
+      let purchase =
+        to_purchase(self.next_purchase_id, food_item, shop, receipt_id);
+
+      self.purchases.push(purchase.clone());
+      purchases.push(purchase);
+    }
+
+    Ok(purchases)
+  }
+
+  fn category_mut(&mut self, id: i32) -> Result<&mut Category, StoreError> {
+    self
+      .categories
+      .iter_mut()
+      .find(|category| category.id == id)
+      .ok_or(StoreError::NotFound {
+        what: "category",
+        id
+      })
+  }
+
+  fn purchase_idx(&self, id: i32) -> Result<usize, StoreError> {
+    self
+      .purchases
+      .iter()
+      .position(|purchase| purchase.id == id)
+      .ok_or(StoreError::NotFound { what: "record", id })
+  }
+}
+
+/// Turn a `FoodItem` into a `Purchase` with the given `id`
+fn to_purchase(
+  id: i32,
+  food_item: FoodItem,
+  shop: Option<Shop>,
+  receipt_id: Option<i32>
+) -> Purchase {
+  let (category, name, price, amount, unit, total, date) = food_item.to_parts();
+
+  Purchase {
+    id,
+    category,
+    name,
+    price,
+    amount,
+    unit,
+    total,
+    date,
+    shop,
+    receipt_id
+  }
+}
+
+/// Keeps everything in memory, nothing survives a restart.
+/// Useful to try out the UI without a database
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+  state: Mutex<MemoryState>
+}
+
+impl MemoryStore {
+  /// A store that starts out with the given categories, in that order
+  pub fn with_categories(names: &[&str]) -> Self {
+    let store = Self::default();
+
+    {
+      let mut state = store.state.lock().unwrap();
+
+      for name in names {
+        state.next_category_id += 1;
+
+        let category = Category {
+          id: state.next_category_id,
+          name: name.to_string(),
+          position: state.categories.len() as i32,
+          archived: false
+        };
+
+        state.categories.push(category);
+      }
+    }
+
+    store
+  }
+}
+
+#[async_trait]
+impl PurchaseStore for MemoryStore {
+  async fn create(
+    &self,
+    food_items: Vec<FoodItem>
+  ) -> Result<Vec<Purchase>, StoreError> {
+    let mut state = self.state.lock().unwrap();
+
+    state.check_items(&food_items)?;
+    state.push_items(food_items, None)
+  }
+
+  async fn create_receipt(
+    &self,
+    receipt: NewReceipt,
+    food_items: Vec<FoodItem>
+  ) -> Result<(Receipt, Vec<Purchase>), StoreError> {
+    let mut state = self.state.lock().unwrap();
+
+    let shop = state.shop(receipt.shop_id)?;
+
+    state.check_items(&food_items)?;
+    state.next_receipt_id += 1;
+
+    let receipt = Receipt {
+      id: state.next_receipt_id,
+      date: receipt.date,
+      shop,
+      total: receipt.total,
+      notes: receipt.notes
+    };
+
+    state.receipts.push(receipt.clone());
+
+    let purchases = state.push_items(food_items, Some(receipt.id))?;
+
+    Ok((receipt, purchases))
+  }
+
+  async fn receipt(
+    &self,
+    id: i32
+  ) -> Result<(Receipt, Vec<Purchase>), StoreError> {
+    let state = self.state.lock().unwrap();
+
+    let receipt = state
+      .receipts
+      .iter()
+      .find(|receipt| receipt.id == id)
+      .cloned()
+      .ok_or(StoreError::NotFound {
+        what: "receipt",
+        id
+      })?;
+
+    // pushed in the order they were added, so they're sorted by id already
+    let purchases = state
+      .purchases
+      .iter()
+      .filter(|purchase| purchase.receipt_id == Some(id))
+      .cloned()
+      .collect();
+
+    Ok((receipt, purchases))
+  }
+
+  async fn list(
+    &self,
+    filter: &PurchaseFilter
+  ) -> Result<Vec<Purchase>, StoreError> {
+    let state = self.state.lock().unwrap();
+
+    let mut purchases = state
+      .purchases
+      .iter()
+      .filter(|purchase| filter.matches(purchase))
+      .cloned()
+      .collect::<Vec<_>>();
+
+    purchases.sort_by_key(|purchase| (purchase.date, purchase.id));
+
+    Ok(purchases)
+  }
+
+  async fn list_page(
+    &self,
+    page: i64,
+    page_size: i64
+  ) -> Result<Vec<Purchase>, StoreError> {
+    let state = self.state.lock().unwrap();
+
+    let mut purchases = state.purchases.clone();
+
+    purchases.sort_by_key(|purchase| (purchase.date, purchase.id));
+    purchases.reverse();
+
+    Ok(
+      purchases
+        .into_iter()
+        .skip((page * page_size) as usize)
+        .take(page_size as usize)
+        .collect()
+    )
+  }
+
+  async fn count(&self) -> Result<i64, StoreError> {
+    Ok(self.state.lock().unwrap().purchases.len() as i64)
+  }
+
+  async fn update(
+    &self,
+    id: i32,
+    food_item: FoodItem
+  ) -> Result<Purchase, StoreError> {
+    let mut state = self.state.lock().unwrap();
+
+    state.check_category(food_item.table_name())?;
+
+    let shop = state.shop(food_item.shop_id())?;
+    let idx = state.purchase_idx(id)?;
+    let receipt_id = state.purchases[idx].receipt_id;
+
+    state.purchases[idx] = to_purchase(id, food_item, shop, receipt_id);
+
+    Ok(state.purchases[idx].clone())
+  }
+
+  async fn delete(&self, id: i32) -> Result<Purchase, StoreError> {
+    let mut state = self.state.lock().unwrap();
+
+    let idx = state.purchase_idx(id)?;
+
+    Ok(state.purchases.remove(idx))
+  }
+
+  async fn monthly_spending(
+    &self,
+    year: i32
+  ) -> Result<Vec<MonthlySpending>, StoreError> {
+    let state = self.state.lock().unwrap();
+
+    // ordered by month, then by category, like the SQL version
+    let mut totals = BTreeMap::<(i32, String), Money>::new();
+
+    for purchase in state.purchases.iter() {
+      if purchase.date.year() != year {
+        continue;
+      }
+
+      let key = (purchase.date.month() as i32, purchase.category.clone());
+
+      *totals.entry(key).or_default() += purchase.total;
+    }
+
+    Ok(
+      totals
+        .into_iter()
+        .map(|((month, food_type), total)| MonthlySpending {
+          month,
+          food_type,
+          total
+        })
+        .collect()
+    )
+  }
+
+  async fn list_categories(
+    &self,
+    include_archived: bool
+  ) -> Result<Vec<Category>, StoreError> {
+    let state = self.state.lock().unwrap();
+
+    let mut categories = state
+      .categories
+      .iter()
+      .filter(|category| include_archived || !category.archived)
+      .cloned()
+      .collect::<Vec<_>>();
+
+    categories.sort_by_key(|category| (category.position, category.id));
+
+    Ok(categories)
+  }
+
+  async fn create_category(
+    &self,
+    name: String,
+    position: i32
+  ) -> Result<Category, StoreError> {
+    let mut state = self.state.lock().unwrap();
+
+    state.check_unique(0, &name)?;
+    state.next_category_id += 1;
+
+    let category = Category {
+      id: state.next_category_id,
+      name,
+      position,
+      archived: false
+    };
+
+    state.categories.push(category.clone());
+
+    Ok(category)
+  }
+
+  async fn rename_category(
+    &self,
+    id: i32,
+    name: String
+  ) -> Result<Category, StoreError> {
+    let mut state = self.state.lock().unwrap();
+
+    state.check_unique(id, &name)?;
+
+    let category = state.category_mut(id)?;
+    let old_name = std::mem::replace(&mut category.name, name.clone());
+    let category = category.clone();
+
+    // purchases follow along, like the cascading foreign key
+    for purchase in state.purchases.iter_mut() {
+      if purchase.category == old_name {
+        purchase.category = name.clone();
+      }
+    }
+
+    Ok(category)
+  }
+
+  async fn set_category_archived(
+    &self,
+    id: i32,
+    archived: bool
+  ) -> Result<Category, StoreError> {
+    let mut state = self.state.lock().unwrap();
+
+    let category = state.category_mut(id)?;
+
+    category.archived = archived;
+
+    Ok(category.clone())
+  }
+
+  async fn reorder_categories(
+    &self,
+    ids: Vec<i32>
+  ) -> Result<Vec<Category>, StoreError> {
+    let mut state = self.state.lock().unwrap();
+
+    // every id has to exist before anything is moved
+    for id in ids.iter() {
+      state.category_mut(*id)?;
+    }
+
+    let mut categories = Vec::with_capacity(ids.len());
+
+    for (position, id) in ids.into_iter().enumerate() {
+      let category = state.category_mut(id)?;
+
+      category.position = position as i32;
+      categories.push(category.clone());
+    }
+
+    Ok(categories)
+  }
+
+  async fn list_shops(&self) -> Result<Vec<Shop>, StoreError> {
+    let mut shops = self.state.lock().unwrap().shops.clone();
+
+    shops.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(shops)
+  }
+
+  async fn create_shop(&self, name: String) -> Result<Shop, StoreError> {
+    let mut state = self.state.lock().unwrap();
+
+    if state.shops.iter().any(|shop| shop.name == name) {
+      return Err(StoreError::Invalid {
+        field: Field::Store,
+        value: name,
+        reason: String::from("already exists")
+      });
+    }
+
+    state.next_shop_id += 1;
+
+    let shop = Shop {
+      id: state.next_shop_id,
+      name
+    };
+
+    state.shops.push(shop.clone());
+
+    Ok(shop)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use prisma_client_rust::chrono::{Datelike, NaiveDate};
+
+  use super::MemoryStore;
+  use crate::{
+    item::{FoodItem, Unit},
+    money::Money,
+    store::{NewReceipt, Purchase, PurchaseFilter, PurchaseStore, StoreError}
+  };
+
+  /// A single piece bought on a day of October 2022
+  fn food_item(category: &str, name: &str, cents: i64, day: u32) -> FoodItem {
+    let mut food_item = FoodItem::new();
+
+    food_item
+      .set_table_name(category)
+      .set_name(name)
+      .set_price(Money::from_cents(cents))
+      .set_amount(1.0)
+      .set_unit(Unit::Pieces)
+      .set_purchase_date(NaiveDate::from_ymd(2022, 10, day))
+      .calc_total();
+
+    food_item
+  }
+
+  fn store() -> MemoryStore {
+    MemoryStore::with_categories(&["Meat", "Fruit"])
+  }
+
+  fn days(purchases: &[Purchase]) -> Vec<u32> {
+    purchases
+      .iter()
+      .map(|purchase| purchase.date.day())
+      .collect()
+  }
+
+  #[tokio::test]
+  async fn keeps_committed_items() {
+    let store = store();
+
+    let purchases = store
+      .create(vec![
+        food_item("Meat", "Steak", 1299, 1),
+        food_item("Fruit", "Apple", 49, 2),
+      ])
+      .await
+      .unwrap();
+
+    assert_eq!(purchases.len(), 2);
+    assert_eq!(store.count().await.unwrap(), 2);
+
+    let filter = PurchaseFilter {
+      category: Some(String::from("Fruit")),
+      ..PurchaseFilter::default()
+    };
+    let fruit = store.list(&filter).await.unwrap();
+
+    assert_eq!(fruit.len(), 1);
+    assert_eq!(fruit[0].name, "Apple");
+  }
+
+  #[tokio::test]
+  async fn refuses_the_whole_basket_for_a_single_item() {
+    let store = store();
+
+    let error = store
+      .create(vec![
+        food_item("Meat", "Steak", 1299, 1),
+        food_item("Candy", "Gum", 99, 1),
+      ])
+      .await
+      .unwrap_err();
+
+    match error {
+      StoreError::Item { index, name, .. } => {
+        assert_eq!(index, 1);
+        assert_eq!(name, "Gum");
+      }
+      error => panic!("unexpected error: {}", error)
+    }
+
+    assert_eq!(store.count().await.unwrap(), 0);
+  }
+
+  #[tokio::test]
+  async fn links_a_receipt_to_its_items() {
+    let store = store();
+
+    let receipt = NewReceipt {
+      date: NaiveDate::from_ymd(2022, 10, 2),
+      shop_id: None,
+      total: Some(Money::from_cents(1348)),
+      notes: String::from("weekly")
+    };
+
+    let (receipt, _) = store
+      .create_receipt(
+        receipt,
+        vec![
+          food_item("Meat", "Steak", 1299, 2),
+          food_item("Fruit", "Apple", 49, 2),
+        ]
+      )
+      .await
+      .unwrap();
+
+    let (stored, purchases) = store.receipt(receipt.id).await.unwrap();
+    let sum = purchases
+      .iter()
+      .map(|purchase| purchase.total)
+      .sum::<Money>();
+
+    assert_eq!(stored.notes, "weekly");
+    assert_eq!(Some(sum), stored.total);
+  }
+
+  #[tokio::test]
+  async fn pages_newest_first() {
+    let store = store();
+
+    let food_items = (1..=5)
+      .map(|day| food_item("Fruit", "Apple", 49, day))
+      .collect();
+
+    store.create(food_items).await.unwrap();
+
+    assert_eq!(days(&store.list_page(0, 2).await.unwrap()), vec![5, 4]);
+    assert_eq!(days(&store.list_page(2, 2).await.unwrap()), vec![1]);
+    assert!(store.list_page(3, 2).await.unwrap().is_empty());
+  }
+
+  #[tokio::test]
+  async fn updates_and_deletes_by_id() {
+    let store = store();
+
+    let purchases = store
+      .create(vec![food_item("Meat", "Steak", 1299, 1)])
+      .await
+      .unwrap();
+    let id = purchases[0].id;
+
+    let updated = store
+      .update(id, food_item("Fruit", "Pear", 59, 3))
+      .await
+      .unwrap();
+
+    assert_eq!(updated.id, id);
+    assert_eq!(updated.category, "Fruit");
+    assert_eq!(updated.total, Money::from_cents(59));
+
+    let unknown = store.update(id, food_item("Candy", "Gum", 99, 3)).await;
+
+    assert!(matches!(unknown, Err(StoreError::Invalid { .. })));
+
+    store.delete(id).await.unwrap();
+
+    assert_eq!(store.count().await.unwrap(), 0);
+    assert!(matches!(
+      store.delete(id).await,
+      Err(StoreError::NotFound { .. })
+    ));
+  }
+
+  #[tokio::test]
+  async fn sums_spending_per_month_and_category() {
+    let store = store();
+
+    store
+      .create(vec![
+        food_item("Fruit", "Apple", 49, 1),
+        food_item("Fruit", "Pear", 59, 2),
+        food_item("Meat", "Steak", 1299, 3),
+      ])
+      .await
+      .unwrap();
+
+    let spending = store.monthly_spending(2022).await.unwrap();
+    let rows = spending
+      .iter()
+      .map(|row| (row.month, row.food_type.as_str(), row.total.cents()))
+      .collect::<Vec<_>>();
+
+    assert_eq!(rows, vec![(10, "Fruit", 108), (10, "Meat", 1299)]);
+    assert!(store.monthly_spending(2021).await.unwrap().is_empty());
+  }
+}
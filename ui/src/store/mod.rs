@@ -0,0 +1,17 @@
+mod memory_store;
+mod monthly_spending;
+mod prisma_store;
+mod purchase;
+mod purchase_filter;
+mod purchase_store;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+pub use memory_store::MemoryStore;
+pub use monthly_spending::MonthlySpending;
+pub use prisma_store::PrismaStore;
+pub use purchase::{Category, NewReceipt, Purchase, Receipt, Shop};
+pub use purchase_filter::PurchaseFilter;
+pub use purchase_store::{PurchaseStore, StoreError};
+#[cfg(feature = "sqlite")]
+pub use sqlite::create_tables;
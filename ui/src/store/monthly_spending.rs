@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+use crate::money::Money;
+
+/// One row of the monthly spending report,
+/// the sum of `total` for a category in a given month
+#[derive(Debug, Deserialize)]
+pub struct MonthlySpending {
+  pub month: i32,
+  pub food_type: String,
+  pub total: Money
+}
@@ -0,0 +1,532 @@
+use async_trait::async_trait;
+use prisma_client_rust::{
+  chrono::{DateTime, FixedOffset, NaiveDate, TimeZone},
+  raw, Direction, PrismaValue
+};
+
+use super::{
+  Category, MonthlySpending, NewReceipt, Purchase, PurchaseFilter,
+  PurchaseStore, Receipt, Shop, StoreError
+};
+use crate::{
+  item::{Field, FoodItem},
+  money::Money,
+  prisma::{
+    category,
+    food::{self, WhereParam},
+    receipt, store, PrismaClient
+  }
+};
+
+impl From<food::Data> for Purchase {
+  fn from(record: food::Data) -> Self {
+    Self {
+      id: record.id,
+      category: record.food_type,
+      name: record.name,
+      price: Money::from_cents(record.price as i64),
+      amount: record.amount,
+      // anything unknown was written by hand, count it in pieces
+      unit: record.unit.parse().unwrap_or_default(),
+      total: Money::from_cents(record.total as i64),
+      date: record.date.naive_local().date(),
+      // only there when the query fetched it with `food::store::fetch`
+      shop: record.store.flatten().map(|shop| Shop::from(*shop)),
+      receipt_id: record.receipt_id
+    }
+  }
+}
+
+impl From<receipt::Data> for Receipt {
+  fn from(receipt: receipt::Data) -> Self {
+    Self {
+      id: receipt.id,
+      date: receipt.date.naive_local().date(),
+      // only there when the query fetched it with `receipt::store::fetch`
+      shop: receipt.store.flatten().map(|shop| Shop::from(*shop)),
+      total: receipt.total.map(|cents| Money::from_cents(cents as i64)),
+      notes: receipt.notes
+    }
+  }
+}
+
+impl From<store::Data> for Shop {
+  fn from(shop: store::Data) -> Self {
+    Self {
+      id: shop.id,
+      name: shop.name
+    }
+  }
+}
+
+impl From<category::Data> for Category {
+  fn from(category: category::Data) -> Self {
+    Self {
+      id: category.id,
+      name: category.name,
+      position: category.position,
+      archived: category.archived
+    }
+  }
+}
+
+/// Prisma only takes dates with a time and an offset. The `Date` column
+/// drops both again, so midnight in UTC is as good as any
+fn to_date_time(date: NaiveDate) -> DateTime<FixedOffset> {
+  FixedOffset::east(0).from_utc_datetime(&date.and_hms(0, 0, 0))
+}
+
+/// Cents for an `Int` column, `FoodItem::check_columns` made sure they fit
+fn to_cents(money: Money) -> i32 {
+  money.cents() as i32
+}
+
+/// Turn the filter into the `WhereParam`s of a `find_many`
+fn where_params(filter: &PurchaseFilter) -> Vec<WhereParam> {
+  let mut params = vec![];
+
+  if let Some(from) = filter.from {
+    params.push(food::date::gte(to_date_time(from)));
+  }
+
+  if let Some(to) = filter.to {
+    params.push(food::date::lte(to_date_time(to)));
+  }
+
+  if let Some(category) = &filter.category {
+    params.push(food::food_type::equals(category.clone()));
+  }
+
+  params
+}
+
+/// Keeps the purchases in the database `prisma/schema.prisma` describes
+pub struct PrismaStore {
+  prisma: PrismaClient
+}
+
+impl PrismaStore {
+  pub fn new(prisma: PrismaClient) -> Self {
+    Self { prisma }
+  }
+
+  /// Find the first item with a category that doesn't exist.
+  /// The foreign key refuses it as well, but only for the batch as a whole,
+  /// so the error couldn't tell which item it was
+  async fn check_categories(
+    &self,
+    food_items: &[FoodItem]
+  ) -> Result<(), StoreError> {
+    let mut names = food_items
+      .iter()
+      .map(|food_item| food_item.table_name().to_string())
+      .collect::<Vec<_>>();
+
+    names.sort();
+    names.dedup();
+
+    let known = self
+      .prisma
+      .category()
+      .find_many(vec![category::name::in_vec(names)])
+      .exec()
+      .await?;
+
+    for (index, food_item) in food_items.iter().enumerate() {
+      let name = food_item.table_name();
+
+      if !known.iter().any(|category| category.name == name) {
+        let error = StoreError::Invalid {
+          field: Field::Category,
+          value: name.to_string(),
+          reason: String::from("does not exist")
+        };
+
+        return Err(error.for_item(index, food_item));
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Creates a record that will need to be executed in an async context
+  /// Basically, call `.exec()` on the result of this fn
+  fn create_item(
+    &self,
+    food_item: FoodItem,
+    receipt_id: Option<i32>
+  ) -> food::Create {
+    let shop_id = food_item.shop_id();
+    let (table_name, name, price, amount, unit, total, purchase_date) =
+      food_item.to_parts();
+
+    let mut params = vec![food::unit::set(unit.to_string())];
+
+    if let Some(id) = shop_id {
+      params.push(food::store::connect(store::id::equals(id)));
+    }
+
+    if let Some(id) = receipt_id {
+      params.push(food::receipt::connect(receipt::id::equals(id)));
+    }
+
+    self
+      .prisma
+      .food()
+      .create(
+        category::name::equals(table_name),
+        name,
+        to_cents(price),
+        amount,
+        to_cents(total),
+        to_date_time(purchase_date),
+        params
+      )
+      .with(food::store::fetch())
+  }
+}
+
+#[async_trait]
+impl PurchaseStore for PrismaStore {
+  async fn create(
+    &self,
+    food_items: Vec<FoodItem>
+  ) -> Result<Vec<Purchase>, StoreError> {
+    self.check_categories(&food_items).await?;
+
+    let creates = food_items
+      .into_iter()
+      .map(|food_item| self.create_item(food_item, None))
+      .collect::<Vec<_>>();
+
+    let records = self.prisma._batch(creates).await?;
+
+    Ok(records.into_iter().map(Purchase::from).collect())
+  }
+
+  async fn create_receipt(
+    &self,
+    receipt: NewReceipt,
+    food_items: Vec<FoodItem>
+  ) -> Result<(Receipt, Vec<Purchase>), StoreError> {
+    self.check_categories(&food_items).await?;
+
+    let mut params = vec![
+      receipt::total::set(receipt.total.map(to_cents)),
+      receipt::notes::set(receipt.notes),
+    ];
+
+    if let Some(id) = receipt.shop_id {
+      params.push(receipt::store::connect(store::id::equals(id)));
+    }
+
+    // the items have to point at the receipt in the same transaction,
+    // so its id is picked here. Every receipt is made this way, so the
+    // sequence behind `autoincrement()` is never needed. Two commits
+    // picking the same id can't both win, the other one fails as a whole
+    let id = self
+      .prisma
+      .receipt()
+      .find_first(vec![])
+      .order_by(receipt::id::order(Direction::Desc))
+      .exec()
+      .await?
+      .map_or(1, |last| last.id + 1);
+
+    params.push(receipt::id::set(id));
+
+    let create = self
+      .prisma
+      .receipt()
+      .create(to_date_time(receipt.date), params)
+      .with(receipt::store::fetch());
+
+    let creates = food_items
+      .into_iter()
+      .map(|food_item| self.create_item(food_item, Some(id)))
+      .collect::<Vec<_>>();
+
+    // a single transaction, if anything fails nothing is left behind
+    let (record, records) = self.prisma._batch((create, creates)).await?;
+
+    Ok((
+      Receipt::from(record),
+      records.into_iter().map(Purchase::from).collect()
+    ))
+  }
+
+  async fn receipt(
+    &self,
+    id: i32
+  ) -> Result<(Receipt, Vec<Purchase>), StoreError> {
+    let mut record = self
+      .prisma
+      .receipt()
+      .find_unique(receipt::id::equals(id))
+      .with(receipt::store::fetch())
+      .with(
+        receipt::foods::fetch(vec![])
+          .with(food::store::fetch())
+          .order_by(food::id::order(Direction::Asc))
+      )
+      .exec()
+      .await?
+      .ok_or(StoreError::NotFound {
+        what: "receipt",
+        id
+      })?;
+
+    let purchases = record
+      .foods
+      .take()
+      .unwrap_or_default()
+      .into_iter()
+      .map(Purchase::from)
+      .collect();
+
+    Ok((Receipt::from(record), purchases))
+  }
+
+  async fn list(
+    &self,
+    filter: &PurchaseFilter
+  ) -> Result<Vec<Purchase>, StoreError> {
+    let records = self
+      .prisma
+      .food()
+      .find_many(where_params(filter))
+      .with(food::store::fetch())
+      .order_by(food::date::order(Direction::Asc))
+      .order_by(food::id::order(Direction::Asc))
+      .exec()
+      .await?;
+
+    Ok(records.into_iter().map(Purchase::from).collect())
+  }
+
+  async fn list_page(
+    &self,
+    page: i64,
+    page_size: i64
+  ) -> Result<Vec<Purchase>, StoreError> {
+    let records = self
+      .prisma
+      .food()
+      .find_many(vec![])
+      .with(food::store::fetch())
+      .order_by(food::date::order(Direction::Desc))
+      .order_by(food::id::order(Direction::Desc))
+      .skip(page * page_size)
+      .take(page_size)
+      .exec()
+      .await?;
+
+    Ok(records.into_iter().map(Purchase::from).collect())
+  }
+
+  async fn count(&self) -> Result<i64, StoreError> {
+    Ok(self.prisma.food().count(vec![]).exec().await?)
+  }
+
+  async fn update(
+    &self,
+    id: i32,
+    food_item: FoodItem
+  ) -> Result<Purchase, StoreError> {
+    let shop = match food_item.shop_id() {
+      Some(id) => food::store::connect(store::id::equals(id)),
+      None => food::store::disconnect()
+    };
+
+    let (table_name, name, price, amount, unit, total, purchase_date) =
+      food_item.to_parts();
+
+    let record = self
+      .prisma
+      .food()
+      .update(
+        food::id::equals(id),
+        vec![
+          food::category::connect(category::name::equals(table_name)),
+          food::name::set(name),
+          food::price::set(to_cents(price)),
+          food::amount::set(amount),
+          food::unit::set(unit.to_string()),
+          food::total::set(to_cents(total)),
+          food::date::set(to_date_time(purchase_date)),
+          shop,
+        ]
+      )
+      .with(food::store::fetch())
+      .exec()
+      .await?;
+
+    Ok(Purchase::from(record))
+  }
+
+  async fn delete(&self, id: i32) -> Result<Purchase, StoreError> {
+    let record = self
+      .prisma
+      .food()
+      .delete(food::id::equals(id))
+      .exec()
+      .await?;
+
+    Ok(Purchase::from(record))
+  }
+
+  #[cfg(not(feature = "sqlite"))]
+  async fn monthly_spending(
+    &self,
+    year: i32
+  ) -> Result<Vec<MonthlySpending>, StoreError> {
+    Ok(
+      self
+        .prisma
+        ._query_raw(raw!(
+          "SELECT CAST(EXTRACT(MONTH FROM date) AS INTEGER) AS month, \
+           food_type, CAST(SUM(total) AS INTEGER) AS total \
+           FROM \"Food\" \
+           WHERE EXTRACT(YEAR FROM date) = {} \
+           GROUP BY month, food_type \
+           ORDER BY month, food_type",
+          PrismaValue::Int(year as i64)
+        ))
+        .exec()
+        .await?
+    )
+  }
+
+  // SQLite keeps `DateTime` as milliseconds since the epoch
+  #[cfg(feature = "sqlite")]
+  async fn monthly_spending(
+    &self,
+    year: i32
+  ) -> Result<Vec<MonthlySpending>, StoreError> {
+    Ok(
+      self
+        .prisma
+        ._query_raw(raw!(
+          "SELECT CAST(strftime('%m', date / 1000, 'unixepoch') AS INTEGER) \
+           AS month, food_type, CAST(SUM(total) AS INTEGER) AS total \
+           FROM \"Food\" \
+           WHERE CAST(strftime('%Y', date / 1000, 'unixepoch') AS INTEGER) \
+           = {} \
+           GROUP BY month, food_type \
+           ORDER BY month, food_type",
+          PrismaValue::Int(year as i64)
+        ))
+        .exec()
+        .await?
+    )
+  }
+
+  async fn list_categories(
+    &self,
+    include_archived: bool
+  ) -> Result<Vec<Category>, StoreError> {
+    let filter = if include_archived {
+      vec![]
+    } else {
+      vec![category::archived::equals(false)]
+    };
+
+    let categories = self
+      .prisma
+      .category()
+      .find_many(filter)
+      .order_by(category::position::order(Direction::Asc))
+      .order_by(category::id::order(Direction::Asc))
+      .exec()
+      .await?;
+
+    Ok(categories.into_iter().map(Category::from).collect())
+  }
+
+  async fn create_category(
+    &self,
+    name: String,
+    position: i32
+  ) -> Result<Category, StoreError> {
+    let category = self
+      .prisma
+      .category()
+      .create(name, vec![category::position::set(position)])
+      .exec()
+      .await?;
+
+    Ok(Category::from(category))
+  }
+
+  // committed records follow along, since the foreign key cascades on update
+  async fn rename_category(
+    &self,
+    id: i32,
+    name: String
+  ) -> Result<Category, StoreError> {
+    let category = self
+      .prisma
+      .category()
+      .update(category::id::equals(id), vec![category::name::set(name)])
+      .exec()
+      .await?;
+
+    Ok(Category::from(category))
+  }
+
+  async fn set_category_archived(
+    &self,
+    id: i32,
+    archived: bool
+  ) -> Result<Category, StoreError> {
+    let category = self
+      .prisma
+      .category()
+      .update(
+        category::id::equals(id),
+        vec![category::archived::set(archived)]
+      )
+      .exec()
+      .await?;
+
+    Ok(Category::from(category))
+  }
+
+  async fn reorder_categories(
+    &self,
+    ids: Vec<i32>
+  ) -> Result<Vec<Category>, StoreError> {
+    let updates = ids
+      .into_iter()
+      .enumerate()
+      .map(|(position, id)| {
+        self.prisma.category().update(
+          category::id::equals(id),
+          vec![category::position::set(position as i32)]
+        )
+      })
+      .collect::<Vec<_>>();
+
+    let categories = self.prisma._batch(updates).await?;
+
+    Ok(categories.into_iter().map(Category::from).collect())
+  }
+
+  async fn list_shops(&self) -> Result<Vec<Shop>, StoreError> {
+    let shops = self
+      .prisma
+      .store()
+      .find_many(vec![])
+      .order_by(store::name::order(Direction::Asc))
+      .exec()
+      .await?;
+
+    Ok(shops.into_iter().map(Shop::from).collect())
+  }
+
+  async fn create_shop(&self, name: String) -> Result<Shop, StoreError> {
+    let shop = self.prisma.store().create(name, vec![]).exec().await?;
+
+    Ok(Shop::from(shop))
+  }
+}
@@ -0,0 +1,64 @@
+use prisma_client_rust::chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::{item::Unit, money::Money};
+
+/// A committed record, no matter where it's stored
+#[derive(Debug, Clone)]
+pub struct Purchase {
+  pub id: i32,
+  pub category: String,
+  pub name: String,
+  pub price: Money,
+  pub amount: f64,
+  pub unit: Unit,
+  pub total: Money,
+  pub date: NaiveDate,
+  pub shop: Option<Shop>,
+  /// The shopping trip it was committed with, imported records have none
+  pub receipt_id: Option<i32>
+}
+
+impl Purchase {
+  /// Price per kg, l, piece or pack
+  pub fn unit_price(&self) -> Option<(Money, Unit)> {
+    self.unit.unit_price(self.total, self.amount)
+  }
+}
+
+/// A category of the `Food` menu, `position` is its place in the menu
+#[derive(Debug, Clone)]
+pub struct Category {
+  pub id: i32,
+  pub name: String,
+  pub position: i32,
+  pub archived: bool
+}
+
+/// A row of the `Store` table. Called a shop in here,
+/// so it isn't mixed up with the `PurchaseStore` the rows are kept in
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shop {
+  pub id: i32,
+  pub name: String
+}
+
+/// A single shopping trip, every item committed together links to it
+#[derive(Debug, Clone)]
+pub struct Receipt {
+  pub id: i32,
+  pub date: NaiveDate,
+  pub shop: Option<Shop>,
+  /// What the receipt says was paid, if it was typed in
+  pub total: Option<Money>,
+  pub notes: String
+}
+
+/// What's known about a shopping trip before it's stored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewReceipt {
+  pub date: NaiveDate,
+  pub shop_id: Option<i32>,
+  pub total: Option<Money>,
+  pub notes: String
+}
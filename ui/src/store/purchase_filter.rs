@@ -0,0 +1,66 @@
+use prisma_client_rust::chrono::NaiveDate;
+
+use super::Purchase;
+use crate::date::DateParser;
+
+/// Which records to list, every bound is optional
+#[derive(Debug, Default)]
+pub struct PurchaseFilter {
+  pub from: Option<NaiveDate>,
+  pub to: Option<NaiveDate>,
+  pub category: Option<String>
+}
+
+/// Parse an optional date the same way as the menu items.
+/// An empty string means there's no bound
+fn parse_bound(
+  label: &str,
+  date: &str,
+  parser: &DateParser
+) -> Result<Option<NaiveDate>, String> {
+  let date = date.trim();
+
+  if date.is_empty() {
+    return Ok(None);
+  }
+
+  parser.parse(date).map(Some).map_err(|reason| {
+    format!("{} date doesn't have a valid format:\n{}", label, reason)
+  })
+}
+
+impl PurchaseFilter {
+  /// Build a filter from the dates as typed in (inclusive on both ends),
+  /// `None` as the category lists every category
+  pub fn new(
+    from: &str,
+    to: &str,
+    category: Option<String>,
+    parser: &DateParser
+  ) -> Result<Self, String> {
+    Ok(Self {
+      from: parse_bound("From", from, parser)?,
+      to: parse_bound("To", to, parser)?,
+      category
+    })
+  }
+
+  /// Every purchase made in `year`, no matter the category
+  pub fn year(year: i32) -> Self {
+    Self {
+      from: NaiveDate::from_ymd_opt(year, 1, 1),
+      to: NaiveDate::from_ymd_opt(year, 12, 31),
+      category: None
+    }
+  }
+
+  /// Check a single purchase against the filter
+  pub fn matches(&self, purchase: &Purchase) -> bool {
+    self.from.map_or(true, |from| purchase.date >= from)
+      && self.to.map_or(true, |to| purchase.date <= to)
+      && self
+        .category
+        .as_ref()
+        .map_or(true, |category| purchase.category == *category)
+  }
+}
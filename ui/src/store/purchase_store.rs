@@ -0,0 +1,182 @@
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use prisma_client_rust::QueryError;
+
+use super::{
+  Category, MonthlySpending, NewReceipt, Purchase, PurchaseFilter, Receipt,
+  Shop
+};
+use crate::item::{Field, FoodItem};
+
+/// Prisma error codes of a database that can't be reached or timed out
+const OFFLINE_CODES: [&str; 5] = ["P1001", "P1002", "P1008", "P1017", "P2024"];
+
+/// Something went wrong while reading or writing the store
+#[derive(Debug)]
+pub enum StoreError {
+  /// The database failed or refused the query
+  Query(QueryError),
+  /// A value the store refuses to keep, e.g. an unknown category
+  Invalid {
+    field: Field,
+    value: String,
+    reason: String
+  },
+  /// Nothing with that `id` is stored
+  NotFound { what: &'static str, id: i32 },
+  /// A single item of a basket was refused, so the whole basket was.
+  /// `index` is its place in the basket, counted from 0
+  Item {
+    index: usize,
+    name: String,
+    error: Box<StoreError>
+  }
+}
+
+impl StoreError {
+  /// Blame the item at `index` of a basket for the error
+  pub fn for_item(self, index: usize, food_item: &FoodItem) -> Self {
+    Self::Item {
+      index,
+      name: food_item.name().to_string(),
+      error: Box::new(self)
+    }
+  }
+
+  /// Whether the database couldn't be reached,
+  /// so the same query might work later on
+  pub fn is_offline(&self) -> bool {
+    match self {
+      Self::Query(QueryError::Execute(error)) => error
+        .as_known()
+        .map_or(false, |known| OFFLINE_CODES.contains(&&*known.error_code)),
+      Self::Item { error, .. } => error.is_offline(),
+      _ => false
+    }
+  }
+}
+
+impl Display for StoreError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Query(error) => write!(f, "{}", error),
+      Self::Invalid {
+        field,
+        value,
+        reason
+      } => write!(f, "{} '{}' {}", field, value, reason),
+      Self::NotFound { what, id } => write!(f, "No {} with id {}", what, id),
+      Self::Item { index, name, error } => {
+        write!(f, "Item {} ({}): {}", index + 1, name, error)
+      }
+    }
+  }
+}
+
+impl std::error::Error for StoreError {}
+
+impl From<QueryError> for StoreError {
+  fn from(error: QueryError) -> Self {
+    Self::Query(error)
+  }
+}
+
+/// Everything the UI reads and writes, so it doesn't have to know
+/// whether the records live in a database or in memory
+#[async_trait]
+pub trait PurchaseStore: Send + Sync {
+  /// Store every item in a single transaction,
+  /// so either all of them are kept or none of them are
+  async fn create(
+    &self,
+    food_items: Vec<FoodItem>
+  ) -> Result<Vec<Purchase>, StoreError>;
+
+  /// Store a shopping trip together with every item bought on it.
+  /// If any of them fails, neither the receipt nor the items are kept
+  async fn create_receipt(
+    &self,
+    receipt: NewReceipt,
+    food_items: Vec<FoodItem>
+  ) -> Result<(Receipt, Vec<Purchase>), StoreError>;
+
+  /// A receipt and every purchase linked to it, in the order they were added
+  async fn receipt(
+    &self,
+    id: i32
+  ) -> Result<(Receipt, Vec<Purchase>), StoreError>;
+
+  /// Every purchase matching `filter`, oldest first
+  async fn list(
+    &self,
+    filter: &PurchaseFilter
+  ) -> Result<Vec<Purchase>, StoreError>;
+
+  /// A single page of purchases, newest first.
+  /// Purchases made on the same day are ordered by their `id`
+  async fn list_page(
+    &self,
+    page: i64,
+    page_size: i64
+  ) -> Result<Vec<Purchase>, StoreError>;
+
+  /// How many purchases there are in total
+  async fn count(&self) -> Result<i64, StoreError>;
+
+  /// Overwrite every field of a purchase with `food_item`
+  async fn update(
+    &self,
+    id: i32,
+    food_item: FoodItem
+  ) -> Result<Purchase, StoreError>;
+
+  /// Remove a purchase for good
+  async fn delete(&self, id: i32) -> Result<Purchase, StoreError>;
+
+  /// Sum of `total` per month and category for the given year,
+  /// ordered by month. Months without purchases are left out
+  async fn monthly_spending(
+    &self,
+    year: i32
+  ) -> Result<Vec<MonthlySpending>, StoreError>;
+
+  /// The categories in menu order, archived ones only when asked for
+  async fn list_categories(
+    &self,
+    include_archived: bool
+  ) -> Result<Vec<Category>, StoreError>;
+
+  /// Add a category at `position` of the menu
+  async fn create_category(
+    &self,
+    name: String,
+    position: i32
+  ) -> Result<Category, StoreError>;
+
+  /// Rename a category, its purchases follow along
+  async fn rename_category(
+    &self,
+    id: i32,
+    name: String
+  ) -> Result<Category, StoreError>;
+
+  /// Hide a category from the menu or bring it back
+  async fn set_category_archived(
+    &self,
+    id: i32,
+    archived: bool
+  ) -> Result<Category, StoreError>;
+
+  /// Store the menu order, `ids` are the categories from top to bottom
+  async fn reorder_categories(
+    &self,
+    ids: Vec<i32>
+  ) -> Result<Vec<Category>, StoreError>;
+
+  /// Every store, sorted by name
+  async fn list_shops(&self) -> Result<Vec<Shop>, StoreError>;
+
+  /// Add a store, names have to be unique
+  async fn create_shop(&self, name: String) -> Result<Shop, StoreError>;
+}
@@ -0,0 +1,416 @@
+use prisma_client_rust::{raw, raw::Raw, QueryError};
+use serde::Deserialize;
+
+use crate::prisma::PrismaClient;
+
+/// Everything in `prisma/sqlite/migrations`, oldest first
+const MIGRATIONS: [&str; 5] = [
+  include_str!(
+    "../../../prisma/sqlite/migrations/20221105101500_init/migration.sql"
+  ),
+  include_str!(
+    "../../../prisma/sqlite/migrations/20221112093041_money_in_cents/migration.sql"
+  ),
+  include_str!(
+    "../../../prisma/sqlite/migrations/20221119154210_unit/migration.sql"
+  ),
+  include_str!(
+    "../../../prisma/sqlite/migrations/20221126110452_store/migration.sql"
+  ),
+  include_str!(
+    "../../../prisma/sqlite/migrations/20221203141026_receipt/migration.sql"
+  )
+];
+
+#[derive(Debug, Deserialize)]
+struct Table {
+  name: String
+}
+
+#[derive(Debug, Deserialize)]
+struct Column {
+  name: String,
+  kind: String
+}
+
+#[derive(Debug, Deserialize)]
+struct UserVersion {
+  user_version: i64
+}
+
+/// How many of the `MIGRATIONS` ran on the file, kept in `user_version`
+async fn user_version(prisma: &PrismaClient) -> Result<usize, QueryError> {
+  let rows = prisma
+    ._query_raw::<UserVersion>(raw!(
+      "SELECT user_version FROM pragma_user_version"
+    ))
+    .exec()
+    .await?;
+
+  Ok(rows.first().map_or(0, |row| row.user_version as usize))
+}
+
+async fn set_user_version(
+  prisma: &PrismaClient,
+  version: usize
+) -> Result<(), QueryError> {
+  // pragmas don't take parameters
+  let statement = format!("PRAGMA user_version = {}", version);
+
+  prisma
+    ._execute_raw(Raw::new(&statement, vec![]))
+    .exec()
+    .await?;
+
+  Ok(())
+}
+
+/// Files made before the migrations were counted have a `user_version`
+/// of 0. What each migration added tells how far they got
+async fn applied_before_counting(
+  prisma: &PrismaClient
+) -> Result<usize, QueryError> {
+  let tables = prisma
+    ._query_raw::<Table>(raw!(
+      "SELECT name FROM sqlite_master WHERE type = 'table'"
+    ))
+    .exec()
+    .await?;
+
+  let columns = prisma
+    ._query_raw::<Column>(raw!(
+      "SELECT name, type AS kind FROM pragma_table_info('Food')"
+    ))
+    .exec()
+    .await?;
+
+  let table = |name: &str| tables.iter().any(|table| table.name == name);
+  let column = |name: &str, kind: &str| {
+    columns
+      .iter()
+      .any(|column| column.name == name && column.kind == kind)
+  };
+
+  // in the same order as `MIGRATIONS`
+  let applied = [
+    table("Food"),
+    column("price", "INTEGER"),
+    column("unit", "TEXT"),
+    table("Store"),
+    table("Receipt")
+  ];
+
+  Ok(applied.iter().take_while(|applied| **applied).count())
+}
+
+/// Count the `word` that just ended, if it opens or closes a block
+fn end_word(word: &mut String, statement: &str, depth: &mut i32) {
+  match word.to_uppercase().as_str() {
+    "BEGIN" if statement.to_uppercase().contains("TRIGGER") => *depth += 1,
+    "CASE" => *depth += 1,
+    "END" => *depth -= 1,
+    _ => {}
+  }
+
+  word.clear();
+}
+
+/// Split a migration file into its statements. Semicolons only end a
+/// statement outside of quotes, comments and the body of a trigger
+fn statements(migration: &str) -> Vec<String> {
+  let mut statements = vec![];
+  let mut statement = String::new();
+  let mut word = String::new();
+  let mut chars = migration.chars().peekable();
+  let mut quote = None;
+  // `BEGIN` and `CASE` that haven't reached their `END` yet
+  let mut depth = 0;
+
+  while let Some(c) = chars.next() {
+    match quote {
+      Some(open) => {
+        statement.push(c);
+
+        // a doubled quote is escaped, the next one closes it again
+        if c == open {
+          quote = None;
+        }
+
+        continue;
+      }
+      None if c.is_alphanumeric() || c == '_' => {
+        word.push(c);
+        statement.push(c);
+
+        continue;
+      }
+      None => end_word(&mut word, &statement, &mut depth)
+    }
+
+    if c == '-' && chars.peek() == Some(&'-') {
+      while chars.next_if(|c| *c != '\n').is_some() {}
+
+      continue;
+    }
+
+    match c {
+      '\'' | '"' | '`' => {
+        quote = Some(c);
+        statement.push(c);
+      }
+      ';' if depth <= 0 => {
+        let finished = statement.trim();
+
+        if !finished.is_empty() {
+          statements.push(finished.to_string());
+        }
+
+        statement.clear();
+        depth = 0;
+      }
+      c => statement.push(c)
+    }
+  }
+
+  end_word(&mut word, &statement, &mut depth);
+
+  if !statement.trim().is_empty() {
+    statements.push(statement.trim().to_string());
+  }
+
+  statements
+}
+
+/// Run a statement that doesn't return anything
+async fn execute(prisma: &PrismaClient, sql: &str) -> Result<(), QueryError> {
+  prisma._execute_raw(Raw::new(sql, vec![])).exec().await?;
+
+  Ok(())
+}
+
+/// Run every statement of a migration file and count it as `version`,
+/// all in one transaction, so a failed migration leaves nothing behind.
+/// Nothing else uses the client yet, so every statement goes through the
+/// single connection its pool has opened
+async fn run_migration(
+  prisma: &PrismaClient,
+  migration: &str,
+  version: usize
+) -> Result<(), QueryError> {
+  // redefining a table drops the old one, which the foreign keys refuse.
+  // This only works outside of a transaction
+  execute(prisma, "PRAGMA foreign_keys = OFF").await?;
+  execute(prisma, "BEGIN").await?;
+
+  let mut result = Ok(());
+
+  for statement in statements(migration) {
+    result = execute(prisma, &statement).await;
+
+    if result.is_err() {
+      break;
+    }
+  }
+
+  if result.is_ok() {
+    result = set_user_version(prisma, version).await;
+  }
+
+  match result {
+    Ok(()) => execute(prisma, "COMMIT").await?,
+    // the migration error is what went wrong, not the rollback
+    Err(_) => {
+      let _ = execute(prisma, "ROLLBACK").await;
+    }
+  }
+
+  execute(prisma, "PRAGMA foreign_keys = ON").await?;
+
+  result
+}
+
+/// Bring the tables of a database file up to date, a new file gets all
+/// of them. This way a file (e.g. a temp file in a test) works without
+/// the Prisma CLI. Only the migrations the file doesn't have yet are run
+pub async fn create_tables(prisma: &PrismaClient) -> Result<(), QueryError> {
+  let counted = user_version(prisma).await?;
+
+  let applied = match counted {
+    0 => applied_before_counting(prisma).await?,
+    counted => counted
+  };
+
+  if applied != counted {
+    set_user_version(prisma, applied).await?;
+  }
+
+  // counted one by one, so a failed migration is tried again next time
+  for (version, migration) in MIGRATIONS.iter().enumerate().skip(applied) {
+    run_migration(prisma, migration, version + 1).await?;
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{
+    fs,
+    path::{Path, PathBuf}
+  };
+
+  use prisma_client_rust::chrono::NaiveDate;
+
+  use super::*;
+  use crate::{
+    item::{FoodItem, Unit},
+    money::Money,
+    prisma::new_client_with_url,
+    store::{NewReceipt, PrismaStore, PurchaseFilter, PurchaseStore}
+  };
+
+  /// An empty database file for a single test.
+  /// SQLite takes an empty file as an empty database
+  fn temp_database(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+      "food-{}-{}.db",
+      name,
+      std::process::id()
+    ));
+
+    fs::write(&path, b"").unwrap();
+    path
+  }
+
+  async fn connect(path: &Path) -> PrismaClient {
+    new_client_with_url(&format!("file:{}", path.display()))
+      .await
+      .unwrap()
+  }
+
+  #[test]
+  fn splits_statements_only_where_they_end() {
+    let migration = "-- CreateTable; with a comment\n\
+                     INSERT INTO \"Category\" (\"name\") VALUES ('a;b');\n\
+                     CREATE TRIGGER \"t\" AFTER INSERT ON \"Food\" BEGIN\n\
+                     UPDATE \"Food\" SET \"unit\" = 'pcs';\n\
+                     END;\n\
+                     PRAGMA foreign_keys=ON;";
+
+    assert_eq!(
+      statements(migration),
+      [
+        "INSERT INTO \"Category\" (\"name\") VALUES ('a;b')",
+        "CREATE TRIGGER \"t\" AFTER INSERT ON \"Food\" BEGIN\n\
+         UPDATE \"Food\" SET \"unit\" = 'pcs';\n\
+         END",
+        "PRAGMA foreign_keys=ON"
+      ]
+    );
+  }
+
+  #[tokio::test]
+  async fn rolls_back_a_migration_that_fails() {
+    let path = temp_database("failed");
+    let prisma = connect(&path).await;
+
+    let broken = "CREATE TABLE \"Half\" (\"id\" INTEGER); NOT SQL;";
+
+    assert!(run_migration(&prisma, broken, 1).await.is_err());
+    assert_eq!(user_version(&prisma).await.unwrap(), 0);
+
+    // the table of the first statement went away with the rest
+    execute(&prisma, "CREATE TABLE \"Half\" (\"id\" INTEGER)")
+      .await
+      .unwrap();
+
+    fs::remove_file(path).unwrap();
+  }
+
+  #[tokio::test]
+  async fn creates_every_table_in_a_new_file() {
+    let path = temp_database("new");
+    let prisma = connect(&path).await;
+
+    create_tables(&prisma).await.unwrap();
+    assert_eq!(user_version(&prisma).await.unwrap(), MIGRATIONS.len());
+
+    // nothing is left to do the second time
+    create_tables(&prisma).await.unwrap();
+    assert_eq!(prisma.receipt().count(vec![]).exec().await.unwrap(), 0);
+
+    fs::remove_file(path).unwrap();
+  }
+
+  #[tokio::test]
+  async fn runs_the_missing_migrations_of_an_old_file() {
+    let path = temp_database("old");
+    let prisma = connect(&path).await;
+
+    // a file made before the migrations were counted
+    for migration in &MIGRATIONS[..2] {
+      for statement in statements(migration) {
+        execute(&prisma, &statement).await.unwrap();
+      }
+    }
+
+    assert_eq!(user_version(&prisma).await.unwrap(), 0);
+    assert_eq!(applied_before_counting(&prisma).await.unwrap(), 2);
+
+    create_tables(&prisma).await.unwrap();
+    assert_eq!(user_version(&prisma).await.unwrap(), MIGRATIONS.len());
+    assert_eq!(
+      applied_before_counting(&prisma).await.unwrap(),
+      MIGRATIONS.len()
+    );
+
+    fs::remove_file(path).unwrap();
+  }
+
+  #[tokio::test]
+  async fn keeps_a_receipt_in_the_file() {
+    let path = temp_database("receipt");
+    let prisma = connect(&path).await;
+
+    create_tables(&prisma).await.unwrap();
+
+    let store = PrismaStore::new(prisma);
+    let date = NaiveDate::from_ymd(2022, 12, 3);
+
+    let mut food_item = FoodItem::new();
+
+    // `Meat` is one of the categories the first migration adds
+    food_item
+      .set_table_name("Meat")
+      .set_name("Steak")
+      .set_price(Money::from_cents(1299))
+      .set_amount(0.5)
+      .set_unit(Unit::Kilograms)
+      .set_purchase_date(date)
+      .calc_total();
+
+    let receipt = NewReceipt {
+      date,
+      shop_id: None,
+      total: Some(Money::from_cents(650)),
+      notes: String::new()
+    };
+
+    let (receipt, purchases) = store
+      .create_receipt(receipt, vec![food_item])
+      .await
+      .unwrap();
+
+    assert_eq!(purchases.len(), 1);
+    assert_eq!(purchases[0].total, Money::from_cents(650));
+    assert_eq!(purchases[0].receipt_id, Some(receipt.id));
+    assert_eq!(store.count().await.unwrap(), 1);
+
+    let listed = store.list(&PurchaseFilter::default()).await.unwrap();
+
+    assert_eq!(listed[0].name, "Steak");
+    assert_eq!(listed[0].date, date);
+
+    fs::remove_file(path).unwrap();
+  }
+}
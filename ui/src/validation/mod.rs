@@ -0,0 +1,5 @@
+mod rules;
+mod violations;
+
+pub use rules::Rules;
+pub use violations::Violations;
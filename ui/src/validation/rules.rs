@@ -0,0 +1,277 @@
+use prisma_client_rust::chrono::Local;
+
+use super::Violations;
+use crate::{
+  date::DateParser,
+  item::{Field, Item, ItemError, MAX_TEXT_LENGTH},
+  money::Money
+};
+
+/// What an `Item` has to look like before it can be added to the list.
+/// The defaults refuse anything that is most likely a typo
+#[derive(Debug, Clone)]
+pub struct Rules {
+  allow_negative_price: bool,
+  allow_zero_amount: bool,
+  allow_future_dates: bool,
+  max_name_length: usize,
+  receipt_tolerance: Money,
+  date_parser: DateParser
+}
+
+impl Default for Rules {
+  fn default() -> Self {
+    Self {
+      allow_negative_price: false,
+      allow_zero_amount: false,
+      allow_future_dates: false,
+      max_name_length: MAX_TEXT_LENGTH,
+      // weighed items are rounded one by one, so a few cents may be lost
+      receipt_tolerance: Money::from_cents(5),
+      date_parser: DateParser::default()
+    }
+  }
+}
+
+/// A rule broken by the `value` of `field`
+fn broken(field: Field, value: &str, reason: &str) -> ItemError {
+  ItemError::Rule {
+    field,
+    value: value.to_string(),
+    reason: reason.to_string()
+  }
+}
+
+/// Parse a number field, `NaN` and `inf` included, which parse just fine
+fn check_number(field: Field, value: &str) -> Result<f64, ItemError> {
+  if value.is_empty() {
+    return Err(broken(field, value, "cannot be empty"));
+  }
+
+  let number = value.parse::<f64>().map_err(|err| ItemError::Parse {
+    field,
+    value: value.to_string(),
+    reason: err.to_string()
+  })?;
+
+  if !number.is_finite() {
+    return Err(broken(field, value, "must be a finite number"));
+  }
+
+  Ok(number)
+}
+
+impl Rules {
+  /// Refunds and discounts can be entered as negative prices
+  pub fn set_allow_negative_price(&mut self, allow: bool) -> &mut Self {
+    self.allow_negative_price = allow;
+    self
+  }
+
+  /// Free extras can be entered with an amount of zero
+  pub fn set_allow_zero_amount(&mut self, allow: bool) -> &mut Self {
+    self.allow_zero_amount = allow;
+    self
+  }
+
+  /// Planned purchases can be entered ahead of time
+  pub fn set_allow_future_dates(&mut self, allow: bool) -> &mut Self {
+    self.allow_future_dates = allow;
+    self
+  }
+
+  /// Never more than what the `Food` table can hold
+  pub fn set_max_name_length(&mut self, length: usize) -> &mut Self {
+    self.max_name_length = length.min(MAX_TEXT_LENGTH);
+    self
+  }
+
+  /// How far the items may be off the printed receipt total
+  /// before the commit warns about it
+  pub fn set_receipt_tolerance(&mut self, tolerance: Money) -> &mut Self {
+    self.receipt_tolerance = tolerance.abs();
+    self
+  }
+
+  pub fn receipt_tolerance(&self) -> Money {
+    self.receipt_tolerance
+  }
+
+  /// Which date formats `Purchase Date` accepts
+  pub fn set_date_parser(&mut self, date_parser: DateParser) -> &mut Self {
+    self.date_parser = date_parser;
+    self
+  }
+
+  pub fn date_parser(&self) -> &DateParser {
+    &self.date_parser
+  }
+
+  /// Check every field of `item`.
+  /// Doesn't stop at the first problem, so all of them can be fixed at once
+  pub fn check(&self, item: &Item) -> Result<(), Violations> {
+    let mut violations = Violations::default();
+    let (name, price, amount, unit, purchase_date) = item.to_owned().to_parts();
+
+    if name.is_empty() {
+      violations.push(broken(Field::Name, &name, "cannot be empty"));
+    } else if name.chars().count() > self.max_name_length {
+      violations.push(broken(
+        Field::Name,
+        &name,
+        &format!("is longer than {} characters", self.max_name_length)
+      ));
+    }
+
+    // parsed as `Money`, so `NaN` and `inf` never get through
+    match item.validate_price() {
+      _ if price.is_empty() => {
+        violations.push(broken(Field::Price, &price, "cannot be empty"))
+      }
+      Ok(money) if money < Money::default() && !self.allow_negative_price => {
+        violations.push(broken(Field::Price, &price, "cannot be negative"))
+      }
+      Ok(_) => {}
+      Err(reason) => violations.push(ItemError::Parse {
+        field: Field::Price,
+        value: price.clone(),
+        reason
+      })
+    }
+
+    match check_number(Field::Amount, &amount) {
+      Ok(number) if number < 0.0 => {
+        violations.push(broken(Field::Amount, &amount, "cannot be negative"))
+      }
+      Ok(number) if number == 0.0 && !self.allow_zero_amount => {
+        violations.push(broken(Field::Amount, &amount, "cannot be zero"))
+      }
+      Ok(_) => {}
+      Err(error) => violations.push(error)
+    }
+
+    if let Err(reason) = item.validate_unit() {
+      violations.push(ItemError::Parse {
+        field: Field::Unit,
+        value: unit,
+        reason
+      });
+    }
+
+    if purchase_date.is_empty() {
+      violations.push(broken(
+        Field::PurchaseDate,
+        &purchase_date,
+        "cannot be empty"
+      ));
+    } else {
+      match item.validate_purchase_date(&self.date_parser) {
+        Ok(date)
+          if date > Local::today().naive_local()
+            && !self.allow_future_dates =>
+        {
+          violations.push(broken(
+            Field::PurchaseDate,
+            &purchase_date,
+            "is in the future"
+          ))
+        }
+        Ok(_) => {}
+        Err(reason) => violations.push(ItemError::Parse {
+          field: Field::PurchaseDate,
+          value: purchase_date.clone(),
+          reason
+        })
+      }
+    }
+
+    if violations.is_empty() {
+      Ok(())
+    } else {
+      Err(violations)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Rules;
+  use crate::item::{Field, Item};
+
+  fn item(name: &str, price: &str, amount: &str, date: &str) -> Item {
+    Item::new(name, price, amount, "pcs", date)
+  }
+
+  #[test]
+  fn accepts_a_plain_item() {
+    let milk = item("Milk", "1.29", "2", "2022-10-23");
+
+    assert_eq!(Rules::default().check(&milk), Ok(()));
+  }
+
+  #[test]
+  fn reports_every_failing_field_at_once() {
+    let violations = Rules::default()
+      .check(&item("", "-1", "0", "someday"))
+      .unwrap_err();
+
+    assert_eq!(
+      violations.fields(),
+      vec![
+        Field::Name,
+        Field::Price,
+        Field::Amount,
+        Field::PurchaseDate
+      ]
+    );
+  }
+
+  #[test]
+  fn refuses_numbers_that_arent_finite_and_future_dates() {
+    let violations = Rules::default()
+      .check(&item("Milk", "NaN", "inf", "2999-01-01"))
+      .unwrap_err();
+
+    assert_eq!(
+      violations.fields(),
+      vec![Field::Price, Field::Amount, Field::PurchaseDate]
+    );
+  }
+
+  #[test]
+  fn allows_what_the_rules_were_loosened_for() {
+    let mut rules = Rules::default();
+
+    rules
+      .set_allow_negative_price(true)
+      .set_allow_zero_amount(true)
+      .set_allow_future_dates(true);
+
+    assert_eq!(
+      rules.check(&item("Coupon", "-2", "0", "2999-01-01")),
+      Ok(())
+    );
+  }
+
+  #[test]
+  fn keeps_names_short_enough_for_the_column() {
+    let mut rules = Rules::default();
+    let long_name = "a".repeat(101);
+
+    rules.set_max_name_length(500);
+
+    assert!(rules
+      .check(&item(&long_name, "1", "1", "2022-10-23"))
+      .is_err());
+
+    rules.set_max_name_length(3);
+
+    assert_eq!(
+      rules
+        .check(&item("Milk", "1", "1", "2022-10-23"))
+        .unwrap_err()
+        .fields(),
+      vec![Field::Name]
+    );
+  }
+}
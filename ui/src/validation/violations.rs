@@ -0,0 +1,43 @@
+use std::fmt::Display;
+
+use crate::item::{Field, ItemError};
+
+/// Every problem found with an item, at most one per field
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Violations(Vec<ItemError>);
+
+impl Display for Violations {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let lines = self
+      .0
+      .iter()
+      .map(|error| format!("- {}", error))
+      .collect::<Vec<_>>();
+
+    write!(f, "{}", lines.join("\n"))
+  }
+}
+
+impl std::error::Error for Violations {}
+
+impl Violations {
+  /// Record a problem, unless its field already has one
+  pub fn push(&mut self, error: ItemError) {
+    if !self.fields().contains(&error.field()) {
+      self.0.push(error);
+    }
+  }
+
+  /// The fields that failed, in the order they were checked
+  pub fn fields(&self) -> Vec<Field> {
+    self.0.iter().map(ItemError::field).collect()
+  }
+
+  pub fn errors(&self) -> &[ItemError] {
+    &self.0
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}